@@ -101,6 +101,37 @@ pub enum ClockError {
         /// 无效的时钟源索引
         src: u32,
     },
+
+    /// 父时钟选择错误
+    ///
+    /// 当指定的父时钟不在该时钟的可选父时钟列表中时返回
+    #[error("invalid parent for clock {clk_id}: {parent} is not a selectable parent")]
+    InvalidParent {
+        /// 目标时钟 ID
+        clk_id: ClkId,
+        /// 请求的父时钟 ID
+        parent: ClkId,
+    },
+
+    /// 频率变化被否决
+    ///
+    /// 当频率变化通知的回调否决变化时返回
+    #[error("rate change of clock {clk_id} vetoed by notifier on {notified}")]
+    RateChangeVetoed {
+        /// 目标时钟 ID
+        clk_id: ClkId,
+        /// 否决变化的回调所注册的时钟 ID
+        notified: ClkId,
+    },
+
+    /// 关键时钟不允许关闭
+    ///
+    /// 当 `clk_disable` 会关闭关键时钟时返回
+    #[error("refusing to disable critical clock {clk_id}")]
+    CriticalClock {
+        /// 目标时钟 ID
+        clk_id: ClkId,
+    },
 }
 
 // =============================================================================
@@ -155,6 +186,24 @@ impl ClockError {
     pub const fn invalid_clock_source(clk_id: ClkId, src: u32) -> Self {
         Self::InvalidClockSource { clk_id, src }
     }
+
+    /// 创建无效父时钟错误
+    #[must_use]
+    pub const fn invalid_parent(clk_id: ClkId, parent: ClkId) -> Self {
+        Self::InvalidParent { clk_id, parent }
+    }
+
+    /// 创建频率变化被否决错误
+    #[must_use]
+    pub const fn rate_change_vetoed(clk_id: ClkId, notified: ClkId) -> Self {
+        Self::RateChangeVetoed { clk_id, notified }
+    }
+
+    /// 创建关键时钟错误
+    #[must_use]
+    pub const fn critical_clock(clk_id: ClkId) -> Self {
+        Self::CriticalClock { clk_id }
+    }
 }
 
 // =============================================================================
@@ -179,21 +228,14 @@ mod tests {
         use crate::rk3588::cru::clock::CLK_I2C0;
 
         let err = ClockError::unsupported(CLK_I2C0);
-        assert_eq!(
-            format!("{}", err),
-            format!(
-                "unsupported clock ID: {} (value: {})",
-                CLK_I2C0.value(),
-                146
-            )
-        );
+        assert_eq!(format!("{}", err), format!("unsupported: {}", CLK_I2C0));
 
         let err = ClockError::invalid_rate(CLK_I2C0, 100_000_000);
         assert_eq!(
             format!("{}", err),
             format!(
                 "failed to set clock {} to 100000000 Hz: unsupported rate",
-                CLK_I2C0.value()
+                CLK_I2C0
             )
         );
 
@@ -202,14 +244,14 @@ mod tests {
             format!("{}", err),
             format!(
                 "failed to get clock {} rate: register read timeout",
-                CLK_I2C0.value()
+                CLK_I2C0
             )
         );
     }
 
     #[test]
     fn test_error_constructors() {
-        use crate::rk3588::cru::clock::CLK_SPI0;
+        use crate::rk3588::cru::clock::{CLK_I2C0, CLK_SPI0};
 
         let err = ClockError::unsupported(CLK_SPI0);
         match err {
@@ -227,5 +269,14 @@ mod tests {
             }
             _ => panic!("Unexpected error type"),
         }
+
+        let err = ClockError::invalid_parent(CLK_SPI0, CLK_I2C0);
+        match err {
+            ClockError::InvalidParent { clk_id, parent } => {
+                assert_eq!(clk_id, CLK_SPI0);
+                assert_eq!(parent, CLK_I2C0);
+            }
+            _ => panic!("Unexpected error type"),
+        }
     }
 }
@@ -1,7 +1,11 @@
-use crate::{Mmio, RstId, SocType};
+use alloc::sync::Arc;
+
+use crate::{Mmio, RstId, SocType, delay::Delay, regmap::RegAccess};
 
 mod error;
+pub mod notify;
 pub mod pll;
+pub mod tree;
 
 pub use error::*;
 
@@ -19,9 +23,25 @@ pub trait CruOp {
 
     fn reset_deassert(&mut self, id: RstId);
 
+    /// 产生复位脉冲
+    ///
+    /// 置位复位，按构造时传入的 [`Delay`] 保持至少 `width_us` 微秒后释放
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 复位 ID
+    /// * `width_us` - 复位保持的最短时间 (us)
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// cru.reset_pulse(SRST_A_RKNN0, 10);
+    /// ```
+    fn reset_pulse(&mut self, id: RstId, width_us: u64);
+
     /// 使能时钟
     ///
-    /// 清除时钟门控 bit，使时钟输出到外设
+    /// 使能计数加 1，首次使能时自动使能父时钟，并清除时钟门控 bit
     ///
     /// # 参数
     ///
@@ -40,7 +60,7 @@ pub trait CruOp {
 
     /// 禁止时钟
     ///
-    /// 设置时钟门控 bit，停止时钟输出
+    /// 使能计数减 1，计数归零时设置时钟门控 bit 并释放父时钟
     ///
     /// # 参数
     ///
@@ -68,6 +88,48 @@ pub trait CruOp {
     /// 返回 true 表示时钟已使能，false 表示已禁止，None 表示不支持
     fn clk_is_enabled(&self, id: ClkId) -> ClockResult<bool>;
 
+    /// 获取时钟的使能计数
+    ///
+    /// 与 `clk_is_enabled` 读取门控寄存器不同，返回软件记录的使用者数量
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回使能计数，0 表示没有使用者
+    fn clk_enable_count(&self, id: ClkId) -> ClockResult<u32>;
+
+    /// 检查时钟是否为关键时钟
+    ///
+    /// 关键时钟不会被 `clk_disable` 关闭，对未使能的关键时钟调用 `clk_disable`
+    /// 返回 `ClockError::CriticalClock`
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回 true 表示关键时钟
+    fn clk_is_critical(&self, id: ClkId) -> ClockResult<bool>;
+
+    /// 在运行时将时钟标记为关键时钟
+    ///
+    /// 用于保护板级关键时钟 (如调试串口)，标记后无法取消
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// cru.clk_mark_critical(SCLK_UART2)?;
+    /// ```
+    fn clk_mark_critical(&mut self, id: ClkId) -> ClockResult<()>;
+
     /// 获取时钟频率
     ///
     /// # 参数
@@ -90,6 +152,186 @@ pub trait CruOp {
     ///
     /// 返回实际设置的频率 (Hz)，如果不支持该时钟则返回错误
     fn clk_set_rate(&mut self, id: crate::clock::ClkId, rate_hz: u64) -> ClockResult<u64>;
+
+    /// 获取时钟当前的父时钟
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回当前选中的父时钟 ID，如果不支持该时钟则返回错误
+    fn clk_get_parent(&self, id: ClkId) -> ClockResult<ClkId>;
+
+    /// 切换时钟的父时钟
+    ///
+    /// 只修改 mux 选择位，分频系数保持不变，时钟频率会随父时钟变化。
+    /// 父时钟随之固定，之后的 `clk_set_rate` 只调整分频，不再切换父时钟
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    /// * `parent` - 目标父时钟 ID，必须在该时钟的可选父时钟列表中
+    ///
+    /// # 返回
+    ///
+    /// 成功返回 Ok(())，父时钟不可选时返回 `ClockError::InvalidParent`
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// // 将 UART2 时钟源固定到 CPLL
+    /// cru.clk_set_parent(CLK_UART2_SRC, PLL_CPLL)?;
+    /// ```
+    fn clk_set_parent(&mut self, id: ClkId, parent: ClkId) -> ClockResult<()>;
+
+    /// 获取 SD/SDIO 采样或驱动时钟的相位
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 相位时钟 ID (如 SCLK_SDMMC_SAMPLE)
+    ///
+    /// # 返回
+    ///
+    /// 返回相位角度 (0-359)，时钟频率为 0 时返回 0
+    fn mmc_get_phase(&self, id: ClkId) -> ClockResult<u32>;
+
+    /// 设置 SD/SDIO 采样或驱动时钟的相位
+    ///
+    /// 90 度以内的部分由延迟线实现，按当前时钟频率换算，修改频率后需要重新设置
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 相位时钟 ID (如 SCLK_SDMMC_SAMPLE)
+    /// * `degrees` - 目标相位角度
+    ///
+    /// # 返回
+    ///
+    /// 返回设置后实际的相位角度
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// // 调谐时逐步扫描采样相位
+    /// for degrees in (0..360).step_by(9) {
+    ///     cru.mmc_set_phase(SCLK_SDMMC_SAMPLE, degrees)?;
+    /// }
+    /// ```
+    fn mmc_set_phase(&mut self, id: ClkId, degrees: u32) -> ClockResult<u32>;
+
+    /// 获取相位时钟当前使用的延迟线单元数
+    ///
+    /// # 返回
+    ///
+    /// 返回延迟线单元数，延迟线未使能时返回 0
+    fn mmc_get_delay_num(&self, id: ClkId) -> ClockResult<u8>;
+
+    /// 设置相位时钟的延迟线单元数，保持 90 度粗调相位不变
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 相位时钟 ID
+    /// * `delay_num` - 延迟线单元数，0 表示关闭延迟线
+    fn mmc_set_delay_num(&mut self, id: ClkId, delay_num: u8) -> ClockResult<()>;
+
+    /// 获取 PLL 的扩频 (SSC) 配置
+    ///
+    /// # 参数
+    ///
+    /// * `id` - PLL 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回按寄存器值换算的实际扩频配置，未使能扩频时返回 `None`
+    fn pll_get_ssc(&self, id: ClkId) -> ClockResult<Option<pll::PllSsc>>;
+
+    /// 设置 PLL 的扩频 (SSC) 配置
+    ///
+    /// 扩频参数按 PLL 当前的分频系数换算，修改 PLL 频率后需要重新设置
+    ///
+    /// # 参数
+    ///
+    /// * `id` - PLL 时钟 ID
+    /// * `ssc` - 扩频配置，`None` 表示关闭扩频
+    ///
+    /// # 返回
+    ///
+    /// 返回设置后实际的扩频配置
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// cru.pll_set_ssc(PLL_V0PLL, Some(PllSsc {
+    ///     spread: SscSpread::Down,
+    ///     depth_ppm: 5000,
+    ///     mod_freq_hz: 31_250,
+    /// }))?;
+    /// ```
+    fn pll_set_ssc(
+        &mut self,
+        id: ClkId,
+        ssc: Option<pll::PllSsc>,
+    ) -> ClockResult<Option<pll::PllSsc>>;
+    /// 注册时钟频率变化通知
+    ///
+    /// 参考 Linux: drivers/clk/clk.c:clk_notifier_register()
+    ///
+    /// `clk_set_rate` 改变该时钟或其祖先时钟的频率时调用回调：变化前发送
+    /// `Pre` 事件 (可以否决)，变化后发送 `Post` 事件，取消时发送 `Abort` 事件
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    /// * `notifier` - 回调
+    ///
+    /// # 返回
+    ///
+    /// 返回用于注销的通知 ID，时钟不支持读取频率时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```rust,ignore
+    /// // UART 驱动在频率变化后重新计算分频
+    /// cru.clk_notifier_register(SCLK_UART2, Arc::new(move |change| {
+    ///     if change.event == RateChangeEvent::Post {
+    ///         uart.update_divisor(change.new_rate);
+    ///     }
+    ///     NotifyAction::Ok
+    /// }))?;
+    /// ```
+    fn clk_notifier_register(
+        &mut self,
+        id: ClkId,
+        notifier: notify::ClkNotifier,
+    ) -> ClockResult<notify::NotifierId>;
+
+    /// 注销时钟频率变化通知
+    ///
+    /// # 参数
+    ///
+    /// * `notifier_id` - 注册时返回的通知 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回 true 表示已注销，false 表示通知 ID 不存在
+    fn clk_notifier_unregister(&mut self, notifier_id: notify::NotifierId) -> bool;
+}
+
+/// CRU 初始化策略
+///
+/// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_clk_init()
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InitPolicy {
+    /// 读取并验证 bootloader 的配置，与 u-boot 不一致时输出警告，不修改寄存器
+    #[default]
+    Verify,
+    /// 按 u-boot 的默认值配置 PLL 和总线根时钟，再进行验证。
+    /// 用于 bootloader (如精简的 SPL) 没有初始化时钟的场景
+    Enforce,
+    /// 不验证也不修改寄存器，只读取 PLL 频率。
+    /// 用于不能修改共享时钟的场景 (如虚拟机中的 guest)
+    NoTouch,
 }
 
 #[enum_dispatch::enum_dispatch(CruOp)]
@@ -100,9 +342,84 @@ pub enum Cru {
 impl Cru {
     /// `base`: reg property
     /// `sys_grf`: "rockchip,grf"
+    ///
+    /// 使用 [`InitPolicy::Verify`] 初始化
     pub fn new(ty: SocType, base: Mmio, sys_grf: Mmio) -> Self {
+        Self::new_with_policy(ty, base, sys_grf, InitPolicy::default())
+    }
+
+    /// 按指定的初始化策略创建 CRU
+    pub fn new_with_policy(ty: SocType, base: Mmio, sys_grf: Mmio, policy: InitPolicy) -> Self {
         match ty {
-            SocType::Rk3588 => Cru::Rk3588(crate::variants::rk3588::cru::Cru::new(base, sys_grf)),
+            SocType::Rk3588 => Cru::Rk3588(crate::variants::rk3588::cru::Cru::new_with_policy(
+                base, sys_grf, policy,
+            )),
+        }
+    }
+
+    /// 使用自定义寄存器访问后端创建 CRU
+    ///
+    /// `base` 仅用于日志输出，寄存器读写全部经过 `regs` / `sys_grf`
+    pub fn new_with_access(
+        ty: SocType,
+        base: usize,
+        regs: Arc<dyn RegAccess>,
+        sys_grf: Arc<dyn RegAccess>,
+    ) -> Self {
+        Self::new_with_access_and_policy(ty, base, regs, sys_grf, InitPolicy::default())
+    }
+
+    /// 使用自定义寄存器访问后端，按指定的初始化策略创建 CRU
+    pub fn new_with_access_and_policy(
+        ty: SocType,
+        base: usize,
+        regs: Arc<dyn RegAccess>,
+        sys_grf: Arc<dyn RegAccess>,
+        policy: InitPolicy,
+    ) -> Self {
+        match ty {
+            SocType::Rk3588 => Cru::Rk3588(
+                crate::variants::rk3588::cru::Cru::new_with_access_and_policy(
+                    base, regs, sys_grf, policy,
+                ),
+            ),
+        }
+    }
+
+    /// 使用自定义寄存器访问后端和延时后端创建 CRU
+    ///
+    /// `delay` 用于 PLL 锁定超时和复位脉冲计时，其他构造函数使用当前平台的默认延时
+    /// (aarch64 为 ARM 通用定时器)
+    pub fn new_with_delay(
+        ty: SocType,
+        base: usize,
+        regs: Arc<dyn RegAccess>,
+        sys_grf: Arc<dyn RegAccess>,
+        policy: InitPolicy,
+        delay: Arc<dyn Delay>,
+    ) -> Self {
+        match ty {
+            SocType::Rk3588 => Cru::Rk3588(crate::variants::rk3588::cru::Cru::new_with_delay(
+                base, regs, sys_grf, policy, delay,
+            )),
+        }
+    }
+
+    /// 按预期配置检查 PLL 和总线根时钟频率，不修改寄存器
+    ///
+    /// 见 [`crate::rk3588::InitProfile`]
+    pub fn verify(&self, profile: &crate::rk3588::InitProfile) -> crate::rk3588::VerifyReport {
+        match self {
+            Cru::Rk3588(cru) => cru.verify(profile),
+        }
+    }
+
+    /// 构造时按初始化策略得到的验证报告
+    ///
+    /// 使用 [`InitPolicy::NoTouch`] 时没有报告，返回 `None`
+    pub fn init_report(&self) -> Option<&crate::rk3588::VerifyReport> {
+        match self {
+            Cru::Rk3588(cru) => cru.init_report(),
         }
     }
 }
@@ -0,0 +1,53 @@
+//! 时钟频率变化通知
+//!
+//! 参考 Linux: include/linux/clk.h (clk_notifier_register)
+//!
+//! 使用者在时钟上注册回调，频率变化前后收到通知：
+//!
+//! - [`RateChangeEvent::Pre`] - 变化前发送，回调可以返回 [`NotifyAction::Veto`] 阻止变化
+//! - [`RateChangeEvent::Post`] - 频率实际发生变化后发送
+//! - [`RateChangeEvent::Abort`] - 收到 `Pre` 但频率最终没有变化 (被否决或设置失败) 时发送
+
+use alloc::sync::Arc;
+
+use super::ClkId;
+
+def_id!(NotifierId, u32);
+
+/// 频率变化事件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateChangeEvent {
+    /// 即将变化 (Linux: PRE_RATE_CHANGE)
+    Pre,
+    /// 已经变化 (Linux: POST_RATE_CHANGE)
+    Post,
+    /// 变化被取消 (Linux: ABORT_RATE_CHANGE)
+    Abort,
+}
+
+/// 频率变化通知
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateChange {
+    /// 注册回调的时钟 ID
+    pub clk_id: ClkId,
+    /// 事件类型
+    pub event: RateChangeEvent,
+    /// 变化前的频率 (Hz)
+    pub old_rate: u64,
+    /// 变化后的频率 (Hz)
+    ///
+    /// `Pre` 和 `Abort` 事件中为按请求频率和当前分频估算的值，实际频率以 `Post` 事件为准
+    pub new_rate: u64,
+}
+
+/// 回调的处理结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyAction {
+    /// 允许变化 (Linux: NOTIFY_OK)
+    Ok,
+    /// 否决变化，只对 `Pre` 事件有效 (Linux: NOTIFY_BAD)
+    Veto,
+}
+
+/// 频率变化回调
+pub type ClkNotifier = Arc<dyn Fn(&RateChange) -> NotifyAction + Send + Sync>;
@@ -1,3 +1,5 @@
+use crate::regmap::RegAccess;
+
 /// PLL 类型枚举
 ///
 /// 参考 rockchip_pll_type 定义
@@ -74,6 +76,28 @@ pub enum PllRateParams {
     },
 }
 
+/// PLL 扩频方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SscSpread {
+    /// 向下扩频，频率在 [f * (1 - 深度), f] 之间变化
+    Down,
+    /// 向上扩频，频率在 [f, f * (1 + 深度)] 之间变化
+    Up,
+    /// 中心扩频，频率在 [f * (1 - 深度 / 2), f * (1 + 深度 / 2)] 之间变化
+    Center,
+}
+
+/// PLL 扩频 (Spread Spectrum Clocking) 配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PllSsc {
+    /// 扩频方式
+    pub spread: SscSpread,
+    /// 扩频深度 (ppm)，如 5000 表示 0.5%
+    pub depth_ppm: u32,
+    /// 调制频率 (Hz)，通常为 30kHz ~ 33kHz
+    pub mod_freq_hz: u32,
+}
+
 /// Rockchip PLL 时钟结构
 #[derive(Debug, Default)]
 #[repr(C)]
@@ -109,57 +133,46 @@ pub struct PllClock {
 impl PllClock {
     /// 检查 PLL 是否已锁定
     ///
+    /// 锁定状态位于 PLLCON6 (con_offset + 0x18)
+    ///
     /// # 参数
     ///
-    /// * `base` - CRU 基地址
+    /// * `regs` - CRU 寄存器访问
     ///
     /// # 返回
     ///
     /// 如果 PLL 已锁定返回 `true`,否则返回 `false`
     #[must_use]
-    pub fn is_locked(&self, base: usize) -> bool {
-        let reg_addr = base + self.con_offset as usize;
-        unsafe {
-            let reg = reg_addr as *const u32;
-            let val = core::ptr::read_volatile(reg);
-            (val & (1 << self.lock_shift)) != 0
-        }
+    pub fn is_locked(&self, regs: &dyn RegAccess) -> bool {
+        let val = regs.read(self.con_offset as usize + 0x18);
+        (val & (1 << self.lock_shift)) != 0
     }
 
     /// 获取 PLL 当前模式
     ///
     /// # 参数
     ///
-    /// * `base` - CRU 基地址
+    /// * `regs` - CRU 寄存器访问
     ///
     /// # 返回
     ///
     /// 当前模式值
     #[must_use]
-    pub fn get_mode(&self, base: usize) -> u32 {
-        let reg_addr = base + self.mode_offset as usize;
-        unsafe {
-            let reg = reg_addr as *const u32;
-            let val = core::ptr::read_volatile(reg);
-            (val & self.mode_mask) >> self.mode_shift
-        }
+    pub fn get_mode(&self, regs: &dyn RegAccess) -> u32 {
+        let val = regs.read(self.mode_offset as usize);
+        (val & self.mode_mask) >> self.mode_shift
     }
 
     /// 设置 PLL 模式
     ///
     /// # 参数
     ///
-    /// * `base` - CRU 基地址
+    /// * `regs` - CRU 寄存器访问
     /// * `mode` - 要设置的模式值
-    pub fn set_mode(&self, base: usize, mode: u32) {
-        let reg_addr = base + self.mode_offset as usize;
-        unsafe {
-            let reg = reg_addr as *mut u32;
-            let current = core::ptr::read_volatile(reg);
-            let new_val =
-                (current & !self.mode_mask) | ((mode << self.mode_shift) & self.mode_mask);
-            core::ptr::write_volatile(reg, new_val);
-        }
+    pub fn set_mode(&self, regs: &dyn RegAccess, mode: u32) {
+        let current = regs.read(self.mode_offset as usize);
+        let new_val = (current & !self.mode_mask) | ((mode << self.mode_shift) & self.mode_mask);
+        regs.write(self.mode_offset as usize, new_val);
     }
 }
 
@@ -0,0 +1,525 @@
+//! 时钟树模型
+//!
+//! 参考 Linux: drivers/clk/rockchip/clk.h (struct rockchip_clk_branch)
+//!
+//! 每个时钟以 [`ClkNode`] 描述：父时钟列表、mux/div 寄存器位域以及标志位。
+//! 门控信息由各 SoC 的门控表提供，不在节点中重复描述。
+//! 时钟频率通过沿当前选中的父时钟链向上遍历计算得到。
+
+use bitflags::bitflags;
+
+use super::ClkId;
+
+bitflags! {
+    /// 时钟节点标志位
+    ///
+    /// 参考 Linux: include/linux/clk-provider.h
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ClkFlags: u32 {
+        /// 关键时钟，不允许关闭 (CLK_IS_CRITICAL)
+        const IS_CRITICAL = 1 << 0;
+        /// 设置频率时允许向上调整父时钟 (CLK_SET_RATE_PARENT)
+        const SET_RATE_PARENT = 1 << 1;
+        /// 设置频率时不切换父时钟 (CLK_SET_RATE_NO_REPARENT)
+        const SET_RATE_NO_REPARENT = 1 << 2;
+        /// 未被使用时也不自动关闭 (CLK_IGNORE_UNUSED)
+        const IGNORE_UNUSED = 1 << 3;
+    }
+}
+
+/// 寄存器位域
+///
+/// 用于描述 mux 选择位和分频系数位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegField {
+    /// 寄存器偏移 (相对 CRU 基地址)
+    pub reg: u32,
+    /// 位偏移
+    pub shift: u32,
+    /// 移位后的掩码
+    pub mask: u32,
+}
+
+impl RegField {
+    /// 创建寄存器位域
+    ///
+    /// # 参数
+    ///
+    /// * `reg` - 寄存器偏移
+    /// * `shift` - 位偏移
+    /// * `width` - 位宽
+    #[must_use]
+    pub const fn new(reg: u32, shift: u32, width: u32) -> Self {
+        Self {
+            reg,
+            shift,
+            mask: ((1 << width) - 1) << shift,
+        }
+    }
+
+    /// 从寄存器值中提取位域值
+    #[must_use]
+    pub const fn get(&self, value: u32) -> u32 {
+        (value & self.mask) >> self.shift
+    }
+
+    /// 位域可表示的最大值
+    #[must_use]
+    pub const fn max(&self) -> u32 {
+        self.mask >> self.shift
+    }
+}
+
+/// 分频器类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivKind {
+    /// 整数分频: rate = parent / (div + 1)
+    Linear,
+    /// 半分频: rate = parent * 2 / (div * 2 + 3)
+    ///
+    /// 参考 Linux: drivers/clk/rockchip/clk-half-divider.c
+    Half,
+}
+
+/// 分频器描述
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClkDivider {
+    /// 分频系数位域
+    pub field: RegField,
+    /// 分频器类型
+    pub kind: DivKind,
+}
+
+impl ClkDivider {
+    /// 根据寄存器中的分频值计算输出频率
+    #[must_use]
+    pub const fn rate(&self, parent_rate: u64, div: u32) -> u64 {
+        match self.kind {
+            DivKind::Linear => parent_rate / (div as u64 + 1),
+            DivKind::Half => parent_rate * 2 / (div as u64 * 2 + 3),
+        }
+    }
+
+    /// 计算输出不超过目标频率的最小分频值
+    ///
+    /// 参考 Linux: drivers/clk/clk-divider.c:divider_get_val()
+    ///
+    /// 结果限制在位域可表示的范围内，目标频率低于最大分频时返回最大分频值
+    #[must_use]
+    pub const fn div_for_rate(&self, parent_rate: u64, rate: u64) -> u32 {
+        if rate == 0 {
+            return self.field.max();
+        }
+        let div = match self.kind {
+            DivKind::Linear => parent_rate.div_ceil(rate).saturating_sub(1),
+            DivKind::Half => (parent_rate * 2)
+                .div_ceil(rate)
+                .saturating_sub(3)
+                .div_ceil(2),
+        };
+        if div > self.field.max() as u64 {
+            self.field.max()
+        } else {
+            div as u32
+        }
+    }
+}
+
+/// 时钟节点类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClkKind {
+    /// 固定频率时钟源 (如外部晶振 xin24m)
+    Fixed(u64),
+    /// PLL 输出，频率由 PLL 寄存器计算
+    Pll,
+    /// mux/div 组合时钟，mux 与 div 均可缺省
+    ///
+    /// 只有门控的时钟也属于此类 (mux 与 div 都为 None，频率等于父时钟)
+    Composite {
+        /// 父时钟选择位域
+        mux: Option<RegField>,
+        /// 分频器
+        div: Option<ClkDivider>,
+    },
+    /// 小数分频器，寄存器 [31:16] 为分子，[15:0] 为分母
+    Frac {
+        /// 小数分频寄存器偏移
+        reg: u32,
+    },
+    /// 固定倍频/分频，频率为父时钟 * mult / div
+    Factor {
+        /// 倍频系数
+        mult: u32,
+        /// 分频系数
+        div: u32,
+    },
+}
+
+/// 时钟树节点
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClkNode {
+    /// 时钟 ID
+    pub id: ClkId,
+    /// 时钟名称 (与 Linux 驱动中的名称一致)
+    pub name: &'static str,
+    /// 父时钟列表，顺序与 mux 选择值一致
+    ///
+    /// 空列表表示父时钟尚未建模 (孤儿时钟)
+    pub parents: &'static [ClkId],
+    /// 节点类型
+    pub kind: ClkKind,
+    /// 标志位
+    pub flags: ClkFlags,
+}
+
+impl ClkNode {
+    /// 固定频率时钟源
+    #[must_use]
+    pub const fn fixed(id: ClkId, name: &'static str, rate: u64) -> Self {
+        Self {
+            id,
+            name,
+            parents: &[],
+            kind: ClkKind::Fixed(rate),
+            flags: ClkFlags::empty(),
+        }
+    }
+
+    /// PLL 输出
+    #[must_use]
+    pub const fn pll(id: ClkId, name: &'static str, parent: &'static [ClkId]) -> Self {
+        Self {
+            id,
+            name,
+            parents: parent,
+            kind: ClkKind::Pll,
+            flags: ClkFlags::empty(),
+        }
+    }
+
+    /// 带 mux 和整数分频的组合时钟 (Linux: COMPOSITE)
+    #[must_use]
+    pub const fn composite(
+        id: ClkId,
+        name: &'static str,
+        parents: &'static [ClkId],
+        mux: RegField,
+        div: RegField,
+        flags: ClkFlags,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            parents,
+            kind: ClkKind::Composite {
+                mux: Some(mux),
+                div: Some(ClkDivider {
+                    field: div,
+                    kind: DivKind::Linear,
+                }),
+            },
+            flags,
+        }
+    }
+
+    /// 带 mux 和半分频的组合时钟 (Linux: COMPOSITE_HALFDIV)
+    #[must_use]
+    pub const fn composite_halfdiv(
+        id: ClkId,
+        name: &'static str,
+        parents: &'static [ClkId],
+        mux: RegField,
+        div: RegField,
+        flags: ClkFlags,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            parents,
+            kind: ClkKind::Composite {
+                mux: Some(mux),
+                div: Some(ClkDivider {
+                    field: div,
+                    kind: DivKind::Half,
+                }),
+            },
+            flags,
+        }
+    }
+
+    /// 只有 mux 的时钟 (Linux: MUX / COMPOSITE_NODIV)
+    #[must_use]
+    pub const fn mux(
+        id: ClkId,
+        name: &'static str,
+        parents: &'static [ClkId],
+        mux: RegField,
+        flags: ClkFlags,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            parents,
+            kind: ClkKind::Composite {
+                mux: Some(mux),
+                div: None,
+            },
+            flags,
+        }
+    }
+
+    /// 只有分频器的时钟 (Linux: DIV / COMPOSITE_NOMUX)
+    #[must_use]
+    pub const fn div(
+        id: ClkId,
+        name: &'static str,
+        parent: &'static [ClkId],
+        div: RegField,
+        flags: ClkFlags,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            parents: parent,
+            kind: ClkKind::Composite {
+                mux: None,
+                div: Some(ClkDivider {
+                    field: div,
+                    kind: DivKind::Linear,
+                }),
+            },
+            flags,
+        }
+    }
+
+    /// 只有门控的时钟 (Linux: GATE)，频率与父时钟相同
+    #[must_use]
+    pub const fn gate(
+        id: ClkId,
+        name: &'static str,
+        parent: &'static [ClkId],
+        flags: ClkFlags,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            parents: parent,
+            kind: ClkKind::Composite {
+                mux: None,
+                div: None,
+            },
+            flags,
+        }
+    }
+
+    /// 小数分频时钟 (Linux: COMPOSITE_FRACMUX 中的 frac 部分)
+    #[must_use]
+    pub const fn frac(
+        id: ClkId,
+        name: &'static str,
+        parent: &'static [ClkId],
+        reg: u32,
+        flags: ClkFlags,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            parents: parent,
+            kind: ClkKind::Frac { reg },
+            flags,
+        }
+    }
+
+    /// 固定倍频/分频时钟 (Linux: FACTOR)
+    #[must_use]
+    pub const fn factor(
+        id: ClkId,
+        name: &'static str,
+        parent: &'static [ClkId],
+        mult: u32,
+        div: u32,
+        flags: ClkFlags,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            parents: parent,
+            kind: ClkKind::Factor { mult, div },
+            flags,
+        }
+    }
+
+    /// 父时钟选择位域
+    #[must_use]
+    pub const fn mux_field(&self) -> Option<RegField> {
+        match self.kind {
+            ClkKind::Composite { mux, .. } => mux,
+            _ => None,
+        }
+    }
+
+    /// 分频器描述
+    #[must_use]
+    pub const fn divider(&self) -> Option<ClkDivider> {
+        match self.kind {
+            ClkKind::Composite { div, .. } => div,
+            _ => None,
+        }
+    }
+
+    /// 是否为关键时钟
+    #[must_use]
+    pub const fn is_critical(&self) -> bool {
+        self.flags.contains(ClkFlags::IS_CRITICAL)
+    }
+}
+
+/// 求不超过给定最大值的最佳有理数近似 `n / d ≈ given_numerator / given_denominator`
+///
+/// 参考 Linux: lib/math/rational.c:rational_best_approximation()
+///
+/// 使用连分数展开，超出范围时在上一个收敛分数和最大的半收敛分数中选择更接近的一个，
+/// 用于计算小数分频器的分子和分母
+///
+/// # 返回
+///
+/// 返回 `(分子, 分母)`
+#[must_use]
+pub fn rational_best_approximation(
+    given_numerator: u64,
+    given_denominator: u64,
+    max_numerator: u64,
+    max_denominator: u64,
+) -> (u64, u64) {
+    let (mut n, mut d) = (given_numerator, given_denominator);
+    let (mut n0, mut d0) = (0u64, 1u64);
+    let (mut n1, mut d1) = (1u64, 0u64);
+
+    while d != 0 {
+        // 欧几里得算法求连分数的下一项
+        let dp = d;
+        let a = n / d;
+        d = n % d;
+        n = dp;
+
+        // 当前收敛分数
+        let n2 = n0 + a * n1;
+        let d2 = d0 + a * d1;
+
+        if n2 > max_numerator || d2 > max_denominator {
+            // 半收敛分数的最后一项
+            let t = (max_denominator - d0)
+                .checked_div(d1)
+                .unwrap_or(u64::MAX)
+                .min((max_numerator - n0).checked_div(n1).unwrap_or(u64::MAX));
+
+            // 半收敛分数比上一个收敛分数更接近时选择半收敛分数
+            if d1 == 0 || 2 * t > a || (2 * t == a && d0 * dp > d1 * d) {
+                n1 = n0 + t * n1;
+                d1 = d0 + t * d1;
+            }
+            break;
+        }
+        n0 = n1;
+        n1 = n2;
+        d0 = d1;
+        d1 = d2;
+    }
+
+    (n1, d1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reg_field() {
+        let field = RegField::new(0x398, 5, 2);
+        assert_eq!(field.mask, 0x3 << 5);
+        assert_eq!(field.max(), 3);
+        assert_eq!(field.get(0b0100_0011), 2);
+    }
+
+    #[test]
+    fn test_divider_rate() {
+        let field = RegField::new(0x300, 0, 5);
+        let linear = ClkDivider {
+            field,
+            kind: DivKind::Linear,
+        };
+        assert_eq!(linear.rate(1_188_000_000, 5), 198_000_000);
+
+        // 半分频: 1188MHz * 2 / (0 * 2 + 3) = 792MHz
+        let half = ClkDivider {
+            field,
+            kind: DivKind::Half,
+        };
+        assert_eq!(half.rate(1_188_000_000, 0), 792_000_000);
+    }
+
+    #[test]
+    fn test_div_for_rate() {
+        let linear = ClkDivider {
+            field: RegField::new(0x300, 0, 5),
+            kind: DivKind::Linear,
+        };
+        // 1500MHz / 12 = 125MHz
+        assert_eq!(linear.div_for_rate(1_500_000_000, 125_000_000), 11);
+        // 1188MHz / 50MHz 不能整除，向上取整保证不超过目标频率
+        assert_eq!(linear.div_for_rate(1_188_000_000, 50_000_000), 23);
+        // 超出位域范围时取最大值
+        assert_eq!(linear.div_for_rate(1_188_000_000, 1_000_000), 31);
+        assert_eq!(linear.div_for_rate(1_188_000_000, 0), 31);
+
+        // 半分频: 1188MHz * 2 / 7 = 339.4MHz <= 450MHz
+        let half = ClkDivider {
+            field: RegField::new(0x300, 0, 5),
+            kind: DivKind::Half,
+        };
+        assert_eq!(half.div_for_rate(1_188_000_000, 450_000_000), 2);
+        assert_eq!(half.div_for_rate(1_188_000_000, 792_000_000), 0);
+    }
+
+    #[test]
+    fn test_node_accessors() {
+        const PARENTS: &[ClkId] = &[ClkId::new(7), ClkId::new(6)];
+        let node = ClkNode::composite(
+            ClkId::new(123),
+            "aclk_bus_root",
+            PARENTS,
+            RegField::new(0x398, 5, 1),
+            RegField::new(0x398, 0, 5),
+            ClkFlags::IS_CRITICAL,
+        );
+        assert!(node.is_critical());
+        assert_eq!(node.mux_field(), Some(RegField::new(0x398, 5, 1)));
+        assert_eq!(node.divider().map(|d| d.kind), Some(DivKind::Linear));
+
+        let gate = ClkNode::gate(ClkId::new(133), "pclk_i2c1", PARENTS, ClkFlags::empty());
+        assert!(!gate.is_critical());
+        assert_eq!(gate.mux_field(), None);
+        assert_eq!(gate.divider(), None);
+    }
+
+    #[test]
+    fn test_rational_best_approximation() {
+        // 12.288MHz / 1188MHz = 128 / 12375
+        assert_eq!(
+            rational_best_approximation(12_288_000, 1_188_000_000, 0xffff, 0xffff),
+            (128, 12375)
+        );
+        // 11.2896MHz / 1188MHz = 196 / 20625
+        assert_eq!(
+            rational_best_approximation(11_289_600, 1_188_000_000, 0xffff, 0xffff),
+            (196, 20625)
+        );
+        // pi 近似: 分母不超过 100 时为 311 / 99
+        assert_eq!(
+            rational_best_approximation(31_415_926, 10_000_000, 1000, 100),
+            (311, 99)
+        );
+        // 分母超出范围时取最接近的半收敛分数
+        assert_eq!(rational_best_approximation(1, 300, 0xff, 0xff), (1, 255));
+    }
+}
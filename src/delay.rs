@@ -0,0 +1,168 @@
+//! 延时抽象
+//!
+//! 参考 Linux: include/linux/delay.h
+//!
+//! PLL 锁定超时、复位脉冲宽度等需要按实际时间等待，驱动通过 [`Delay`]
+//! 延时，不依赖 CPU 频率：
+//!
+//! - [`GenericTimer`] - ARM 通用定时器 (仅 aarch64)
+//! - [`SpinDelay`] - 按循环次数估算的忙等，没有定时器时使用
+//! - [`FakeDelay`] - 不实际等待，只累计延时时长，用于主机端单元测试
+
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// 延时接口
+pub trait Delay: Send + Sync {
+    /// 延时至少 `us` 微秒
+    fn delay_us(&self, us: u64);
+}
+
+impl<T: Delay + ?Sized> Delay for Arc<T> {
+    fn delay_us(&self, us: u64) {
+        (**self).delay_us(us)
+    }
+}
+
+/// 当前平台的默认延时后端
+///
+/// aarch64 使用 [`GenericTimer`]，其他平台使用 [`SpinDelay`]
+pub(crate) fn default_delay() -> Arc<dyn Delay> {
+    #[cfg(target_arch = "aarch64")]
+    {
+        Arc::new(GenericTimer)
+    }
+    #[cfg(not(target_arch = "aarch64"))]
+    {
+        Arc::new(SpinDelay::default())
+    }
+}
+
+// =============================================================================
+// ARM 通用定时器
+// =============================================================================
+
+/// ARM 通用定时器延时
+///
+/// 读取 CNTVCT_EL0 计数，频率取自 CNTFRQ_EL0 (RK3588 为 24MHz)，
+/// 需要固件已正确设置 CNTFRQ_EL0
+#[cfg(target_arch = "aarch64")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GenericTimer;
+
+#[cfg(target_arch = "aarch64")]
+impl GenericTimer {
+    /// 当前计数值
+    fn counter() -> u64 {
+        let cnt: u64;
+        unsafe {
+            core::arch::asm!("isb", "mrs {}, cntvct_el0", out(reg) cnt, options(nomem, nostack));
+        }
+        cnt
+    }
+
+    /// 计数频率 (Hz)
+    fn frequency() -> u64 {
+        let freq: u64;
+        unsafe {
+            core::arch::asm!("mrs {}, cntfrq_el0", out(reg) freq, options(nomem, nostack));
+        }
+        freq
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+impl Delay for GenericTimer {
+    fn delay_us(&self, us: u64) {
+        let ticks = (us as u128 * Self::frequency() as u128).div_ceil(1_000_000) as u64;
+        let start = Self::counter();
+        while Self::counter().wrapping_sub(start) < ticks {
+            core::hint::spin_loop();
+        }
+    }
+}
+
+// =============================================================================
+// 忙等循环
+// =============================================================================
+
+/// 按循环次数估算的忙等延时
+///
+/// 实际时长取决于 CPU 频率，只在没有可用定时器时使用
+#[derive(Debug, Clone, Copy)]
+pub struct SpinDelay {
+    loops_per_us: u32,
+}
+
+impl SpinDelay {
+    /// 创建忙等延时
+    ///
+    /// # 参数
+    ///
+    /// * `loops_per_us` - 每微秒的 `spin_loop` 次数
+    pub const fn new(loops_per_us: u32) -> Self {
+        Self { loops_per_us }
+    }
+}
+
+impl Default for SpinDelay {
+    /// 每微秒 100 次循环
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+impl Delay for SpinDelay {
+    fn delay_us(&self, us: u64) {
+        for _ in 0..us * self.loops_per_us as u64 {
+            core::hint::spin_loop();
+        }
+    }
+}
+
+// =============================================================================
+// 测试用延时
+// =============================================================================
+
+/// 不实际等待的延时
+///
+/// 每次延时只累加时长，测试中可以检查驱动等待了多久
+#[derive(Debug, Default)]
+pub struct FakeDelay {
+    elapsed_us: AtomicU64,
+}
+
+impl FakeDelay {
+    /// 创建测试用延时，累计时长为 0
+    pub const fn new() -> Self {
+        Self {
+            elapsed_us: AtomicU64::new(0),
+        }
+    }
+
+    /// 累计的延时时长 (us)
+    pub fn elapsed_us(&self) -> u64 {
+        self.elapsed_us.load(Ordering::Relaxed)
+    }
+}
+
+impl Delay for FakeDelay {
+    fn delay_us(&self, us: u64) {
+        self.elapsed_us.fetch_add(us, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fake_delay() {
+        let delay = Arc::new(FakeDelay::new());
+        let dyn_delay: Arc<dyn Delay> = delay.clone();
+
+        dyn_delay.delay_us(10);
+        dyn_delay.delay_us(5);
+        assert_eq!(delay.elapsed_us(), 15);
+    }
+}
@@ -13,16 +13,27 @@ mod _macros;
 mod grf;
 
 mod clock;
+mod delay;
 
 pub(crate) mod pinctrl;
+mod regmap;
 mod rst;
 mod syscon;
 pub(crate) mod variants;
 
 use core::ptr::NonNull;
 
-pub use clock::{ClkId, ClockError, ClockResult, Cru, CruOp};
+pub use clock::{
+    ClkId, ClockError, ClockResult, Cru, CruOp, InitPolicy,
+    notify::{ClkNotifier, NotifierId, NotifyAction, RateChange, RateChangeEvent},
+    pll::{PllSsc, SscSpread},
+    tree::{ClkDivider, ClkFlags, ClkKind, ClkNode, DivKind, RegField},
+};
+#[cfg(target_arch = "aarch64")]
+pub use delay::GenericTimer;
+pub use delay::{Delay, FakeDelay, SpinDelay};
 pub use pinctrl::{GpioDirection, PinConfig, PinCtrl, PinCtrlOp, PinctrlResult, Pull, id::*};
+pub use regmap::{MmioAccess, RegAccess, RegFile, RegOp, TraceAccess};
 pub use rst::{ResetRockchip, RstId};
 pub use variants::*;
 
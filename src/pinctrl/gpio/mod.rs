@@ -1,9 +1,18 @@
-use crate::{GpioDirection, Mmio, PinId, PinctrlResult, pinctrl::Iomux, pinctrl::PinctrlError};
+use alloc::sync::Arc;
+use core::mem::offset_of;
+
+use crate::{
+    GpioDirection, PinId, PinctrlResult, pinctrl::Iomux, pinctrl::PinctrlError, regmap::RegAccess,
+};
 
 mod reg;
 
 use reg::*;
-use tock_registers::interfaces::{Readable, Writeable};
+
+/// 数据寄存器 (低 16 位引脚，高 16 位引脚位于 +0x4)
+const SWPORT_DR: usize = offset_of!(Registers, swport_dr_l);
+/// 方向寄存器 (低 16 位引脚，高 16 位引脚位于 +0x4)
+const SWPORT_DDR: usize = offset_of!(Registers, swport_ddr_l);
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct IomuxReg {
@@ -12,12 +21,12 @@ pub(crate) struct IomuxReg {
 }
 
 pub struct GpioBank {
-    base: usize,
+    regs: Arc<dyn RegAccess>,
     pub(crate) iomux: [IomuxReg; 4],
 }
 
 impl GpioBank {
-    pub fn new(base: Mmio, iomux: [Iomux; 4]) -> Self {
+    pub fn new(regs: Arc<dyn RegAccess>, iomux: [Iomux; 4]) -> Self {
         let iomux_regs: [IomuxReg; 4] = core::array::from_fn(|i| {
             let ty = iomux[i];
             let offset = i * if ty.contains(Iomux::WIDTH_4BIT)
@@ -32,15 +41,11 @@ impl GpioBank {
         });
 
         GpioBank {
-            base: base.as_ptr() as usize,
+            regs,
             iomux: iomux_regs,
         }
     }
 
-    fn reg(&self) -> &Registers {
-        unsafe { &*(self.base as *const Registers) }
-    }
-
     pub fn verify_mux(&self, pin: PinId, mux: Iomux) -> PinctrlResult<()> {
         let pin_in_bank = pin.pin_in_bank();
         if pin_in_bank >= 32 {
@@ -97,12 +102,7 @@ impl GpioBank {
         if pin_in_bank >= 32 {
             return Err(PinctrlError::InvalidPinId(pin));
         }
-        set_bit(
-            &self.reg().swport_ddr_l,
-            &self.reg().swport_ddr_h,
-            pin_in_bank,
-            false,
-        );
+        self.set_bit(SWPORT_DDR, pin_in_bank, false);
 
         Ok(())
     }
@@ -120,19 +120,9 @@ impl GpioBank {
             return Err(PinctrlError::InvalidPinId(pin));
         }
 
-        set_bit(
-            &self.reg().swport_dr_l,
-            &self.reg().swport_dr_h,
-            pin_in_bank,
-            value,
-        );
+        self.set_bit(SWPORT_DR, pin_in_bank, value);
 
-        set_bit(
-            &self.reg().swport_ddr_l,
-            &self.reg().swport_ddr_h,
-            pin_in_bank,
-            true,
-        );
+        self.set_bit(SWPORT_DDR, pin_in_bank, true);
 
         Ok(())
     }
@@ -147,11 +137,7 @@ impl GpioBank {
         if pin_in_bank >= 32 {
             return Err(PinctrlError::InvalidPinId(pin));
         }
-        let value = read_bit(
-            &self.reg().swport_dr_l,
-            &self.reg().swport_dr_h,
-            pin_in_bank,
-        );
+        let value = self.read_bit(SWPORT_DR, pin_in_bank);
         Ok(value)
     }
 
@@ -169,12 +155,7 @@ impl GpioBank {
             return Err(PinctrlError::InvalidPinId(pin));
         }
 
-        set_bit(
-            &self.reg().swport_dr_l,
-            &self.reg().swport_dr_h,
-            pin_in_bank,
-            value,
-        );
+        self.set_bit(SWPORT_DR, pin_in_bank, value);
 
         Ok(())
     }
@@ -198,65 +179,54 @@ impl GpioBank {
             return Err(PinctrlError::InvalidPinId(pin));
         }
 
-        if read_bit(
-            &self.reg().swport_ddr_l,
-            &self.reg().swport_ddr_h,
-            pin_in_bank,
-        ) {
+        if self.read_bit(SWPORT_DDR, pin_in_bank) {
             // 输出方向：同时读取输出值
-            let dr_value = read_bit(
-                &self.reg().swport_dr_l,
-                &self.reg().swport_dr_h,
-                pin_in_bank,
-            );
+            let dr_value = self.read_bit(SWPORT_DR, pin_in_bank);
             Ok(GpioDirection::Output(dr_value))
         } else {
             // 输入方向
             Ok(GpioDirection::Input)
         }
     }
-}
 
-fn read_value(reg_l: &impl Readable<T = u32>, reg_h: &impl Readable<T = u32>) -> u32 {
-    reg_l.get() & 0xffff | (reg_h.get() & 0xffff) << 16
-}
+    /// 读取由 `_l`/`_h` 两个寄存器拼成的 32 位值
+    fn read_value(&self, reg_l: usize) -> u32 {
+        self.regs.read(reg_l) & 0xffff | (self.regs.read(reg_l + 4) & 0xffff) << 16
+    }
 
-fn write_bit(reg_l: &impl Writeable<T = u32>, reg_h: &impl Writeable<T = u32>, value: u32) {
-    reg_l.set(((value) & 0xFFFF) | 0xFFFF0000);
-    reg_h.set((((value) & 0xFFFF0000) >> 16) | 0xFFFF0000);
-}
+    /// 以写掩码方式写入 `_l`/`_h` 两个寄存器
+    fn write_value(&self, reg_l: usize, value: u32) {
+        self.regs.write(reg_l, (value & 0xFFFF) | 0xFFFF0000);
+        self.regs
+            .write(reg_l + 4, ((value & 0xFFFF0000) >> 16) | 0xFFFF0000);
+    }
 
-fn read_bit(
-    reg_l: &impl Readable<T = u32>,
-    reg_h: &impl Readable<T = u32>,
-    pin_in_bank: u32,
-) -> bool {
-    read_value(reg_l, reg_h) & (1 << pin_in_bank) != 0
-}
+    fn read_bit(&self, reg_l: usize, pin_in_bank: u32) -> bool {
+        self.read_value(reg_l) & (1 << pin_in_bank) != 0
+    }
 
-fn set_bit<V>(reg_l: &V, reg_h: &V, pin_in_bank: u32, value: bool)
-where
-    V: Readable<T = u32> + Writeable<T = u32>,
-{
-    let mut current = read_value(reg_l, reg_h);
-    if value {
-        current |= 1 << pin_in_bank;
-    } else {
-        current &= !(1 << pin_in_bank);
+    fn set_bit(&self, reg_l: usize, pin_in_bank: u32, value: bool) {
+        let mut current = self.read_value(reg_l);
+        if value {
+            current |= 1 << pin_in_bank;
+        } else {
+            current &= !(1 << pin_in_bank);
+        }
+        self.write_value(reg_l, current);
     }
-    write_bit(reg_l, reg_h, current);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::regmap::RegFile;
 
     #[test]
     fn test_iomux_offset_gpio0() {
         // GPIO0 的 iomux 只存储组内偏移，由 Pinctrl::set_mux() 加上 PMU1_IOC 基地址
-        let base = unsafe { Mmio::new_unchecked(0xfd8a0000 as *mut u8) };
+        let regs = Arc::new(RegFile::new(0x110));
         let iomux = [Iomux::WIDTH_4BIT; 4];
-        let bank = GpioBank::new(base, iomux);
+        let bank = GpioBank::new(regs, iomux);
 
         // 验证组内偏移（不包含基地址）
         assert_eq!(bank.iomux[0].offset, 0x00);
@@ -268,9 +238,9 @@ mod tests {
     #[test]
     fn test_iomux_offset_gpio1() {
         // GPIO1-4 的 iomux 只存储组内偏移，由 Pinctrl::set_mux() 加上 BUS_IOC 基地址 (0x8000)
-        let base = unsafe { Mmio::new_unchecked(0xfec20000 as *mut u8) };
+        let regs = Arc::new(RegFile::new(0x110));
         let iomux = [Iomux::WIDTH_4BIT; 4];
-        let bank = GpioBank::new(base, iomux);
+        let bank = GpioBank::new(regs, iomux);
 
         // 验证组内偏移（不包含基地址）
         assert_eq!(bank.iomux[0].offset, 0x00);
@@ -282,12 +252,34 @@ mod tests {
     #[test]
     fn test_offset_increment() {
         // 验证每个 iomux 组占用 8 字节
-        let base = unsafe { Mmio::new_unchecked(0xfec20000 as *mut u8) };
+        let regs = Arc::new(RegFile::new(0x110));
         let iomux = [Iomux::WIDTH_4BIT; 4];
-        let bank = GpioBank::new(base, iomux);
+        let bank = GpioBank::new(regs, iomux);
 
         assert_eq!(bank.iomux[1].offset - bank.iomux[0].offset, 0x8);
         assert_eq!(bank.iomux[2].offset - bank.iomux[1].offset, 0x8);
         assert_eq!(bank.iomux[3].offset - bank.iomux[2].offset, 0x8);
     }
+
+    #[test]
+    fn test_direction_and_value() {
+        let regs = Arc::new(RegFile::new(0x110));
+        let bank = GpioBank::new(regs.clone(), [Iomux::WIDTH_4BIT; 4]);
+        let pin = PinId::new(32 + 20).unwrap(); // GPIO1_C4
+
+        bank.set_direction(pin, GpioDirection::Output(true))
+            .unwrap();
+        assert_eq!(regs.read(SWPORT_DR + 4) & 0xffff, 1 << 4);
+        assert_eq!(regs.read(SWPORT_DDR + 4) & 0xffff, 1 << 4);
+        assert_eq!(
+            bank.get_direction(pin).unwrap(),
+            GpioDirection::Output(true)
+        );
+
+        bank.write(pin, false).unwrap();
+        assert!(!bank.read(pin).unwrap());
+
+        bank.set_direction(pin, GpioDirection::Input).unwrap();
+        assert_eq!(bank.get_direction(pin).unwrap(), GpioDirection::Input);
+    }
 }
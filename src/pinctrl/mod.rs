@@ -2,6 +2,7 @@
 //!
 //! 提供跨芯片的引脚控制抽象，包括引脚标识、配置类型和错误处理。
 
+use alloc::sync::Arc;
 use core::fmt;
 
 pub mod id;
@@ -10,7 +11,7 @@ mod pinconf;
 pub use id::PinId;
 pub use pinconf::{Iomux, PinConfig, Pull};
 
-use crate::{Mmio, SocType};
+use crate::{Mmio, SocType, regmap::RegAccess};
 pub(crate) mod gpio;
 
 /// GPIO 方向配置（用于设置方向）
@@ -83,4 +84,23 @@ impl PinCtrl {
             SocType::Rk3588 => PinCtrl::Rk3588(crate::variants::rk3588::PinCtrl::new(ioc, gpio)),
         }
     }
+
+    /// 使用自定义寄存器访问后端创建 PinCtrl
+    ///
+    /// `gpio` 按 bank 顺序排列，数量必须与 SoC 的 GPIO bank 数一致
+    pub fn new_with_access(
+        ty: SocType,
+        ioc: Arc<dyn RegAccess>,
+        gpio: &[Arc<dyn RegAccess>],
+    ) -> Self {
+        match ty {
+            SocType::Rk3588 => {
+                let gpio: [Arc<dyn RegAccess>; 5] = gpio
+                    .to_vec()
+                    .try_into()
+                    .unwrap_or_else(|_| panic!("RK3588 PinCtrl requires 5 GPIO banks"));
+                PinCtrl::Rk3588(crate::variants::rk3588::PinCtrl::new_with_access(ioc, gpio))
+            }
+        }
+    }
 }
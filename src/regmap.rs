@@ -0,0 +1,218 @@
+//! 寄存器访问抽象
+//!
+//! 参考 Linux: drivers/base/regmap
+//!
+//! 驱动通过 [`RegAccess`] 读写寄存器，不直接操作裸指针，
+//! 从而可以在不同后端上运行同一份驱动逻辑：
+//!
+//! - [`MmioAccess`] - 真实硬件 MMIO (volatile 读写)
+//! - [`RegFile`] - 内存中的寄存器文件，用于主机端单元测试
+//! - [`TraceAccess`] - 包装任意后端，记录每一次寄存器访问
+
+use alloc::{sync::Arc, vec::Vec};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::Mmio;
+
+/// 寄存器访问接口
+///
+/// 偏移量以字节为单位，相对于设备寄存器基地址，必须 4 字节对齐
+pub trait RegAccess: Send + Sync {
+    /// 读取 32 位寄存器
+    fn read(&self, offset: usize) -> u32;
+
+    /// 写入 32 位寄存器
+    fn write(&self, offset: usize, value: u32);
+}
+
+impl<T: RegAccess + ?Sized> RegAccess for Arc<T> {
+    fn read(&self, offset: usize) -> u32 {
+        (**self).read(offset)
+    }
+
+    fn write(&self, offset: usize, value: u32) {
+        (**self).write(offset, value)
+    }
+}
+
+// =============================================================================
+// MMIO 后端
+// =============================================================================
+
+/// MMIO 寄存器访问
+#[derive(Debug, Clone, Copy)]
+pub struct MmioAccess {
+    base: usize,
+}
+
+impl MmioAccess {
+    /// 创建 MMIO 寄存器访问
+    ///
+    /// # 参数
+    ///
+    /// * `base` - 设备寄存器基地址
+    ///
+    /// # Safety
+    ///
+    /// `base` 必须是已映射的有效设备寄存器基地址，并且在整个生命周期内保持有效。
+    /// 之后通过 [`RegAccess`] 的所有读写都会直接访问 `base` 加偏移量处的内存
+    pub unsafe fn new(base: Mmio) -> Self {
+        Self {
+            base: base.as_ptr() as usize,
+        }
+    }
+
+    /// 寄存器基地址
+    pub fn base(&self) -> usize {
+        self.base
+    }
+}
+
+impl RegAccess for MmioAccess {
+    fn read(&self, offset: usize) -> u32 {
+        unsafe { core::ptr::read_volatile((self.base + offset) as *const u32) }
+    }
+
+    fn write(&self, offset: usize, value: u32) {
+        unsafe { core::ptr::write_volatile((self.base + offset) as *mut u32, value) }
+    }
+}
+
+// =============================================================================
+// 内存寄存器文件
+// =============================================================================
+
+/// 内存中的寄存器文件
+///
+/// 按原样存储写入的值，不模拟 Rockchip 写掩码等硬件行为。
+/// 越界访问会 panic
+#[derive(Debug)]
+pub struct RegFile {
+    regs: Vec<AtomicU32>,
+}
+
+impl RegFile {
+    /// 创建寄存器文件，所有寄存器初始化为 0
+    ///
+    /// # 参数
+    ///
+    /// * `size` - 寄存器空间大小 (字节)
+    pub fn new(size: usize) -> Self {
+        Self {
+            regs: (0..size.div_ceil(4)).map(|_| AtomicU32::new(0)).collect(),
+        }
+    }
+
+    /// 寄存器空间大小 (字节)
+    pub fn size(&self) -> usize {
+        self.regs.len() * 4
+    }
+}
+
+impl RegAccess for RegFile {
+    fn read(&self, offset: usize) -> u32 {
+        self.regs[offset / 4].load(Ordering::Relaxed)
+    }
+
+    fn write(&self, offset: usize, value: u32) {
+        self.regs[offset / 4].store(value, Ordering::Relaxed);
+    }
+}
+
+// =============================================================================
+// 访问记录
+// =============================================================================
+
+/// 一次寄存器访问
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegOp {
+    /// 读取，value 为读到的值
+    Read { offset: usize, value: u32 },
+    /// 写入，value 为写入的值
+    Write { offset: usize, value: u32 },
+}
+
+/// 记录寄存器访问的包装后端
+///
+/// 每次访问都会调用 `hook`，可用于打印日志或在测试中检查写入序列
+pub struct TraceAccess<A, F> {
+    inner: A,
+    hook: F,
+}
+
+impl<A: RegAccess, F: Fn(RegOp) + Send + Sync> TraceAccess<A, F> {
+    /// 创建访问记录包装
+    ///
+    /// # 参数
+    ///
+    /// * `inner` - 实际执行访问的后端
+    /// * `hook` - 每次访问后调用的回调
+    pub fn new(inner: A, hook: F) -> Self {
+        Self { inner, hook }
+    }
+
+    /// 被包装的后端
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+}
+
+impl<A: RegAccess> TraceAccess<A, fn(RegOp)> {
+    /// 创建以 trace 级别日志输出访问记录的包装
+    pub fn with_log(inner: A) -> Self {
+        Self::new(inner, |op| trace!("{:x?}", op))
+    }
+}
+
+impl<A: RegAccess, F: Fn(RegOp) + Send + Sync> RegAccess for TraceAccess<A, F> {
+    fn read(&self, offset: usize) -> u32 {
+        let value = self.inner.read(offset);
+        (self.hook)(RegOp::Read { offset, value });
+        value
+    }
+
+    fn write(&self, offset: usize, value: u32) {
+        self.inner.write(offset, value);
+        (self.hook)(RegOp::Write { offset, value });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn test_reg_file() {
+        let regs = RegFile::new(0x10);
+        assert_eq!(regs.size(), 0x10);
+        assert_eq!(regs.read(0x4), 0);
+
+        regs.write(0x4, 0xdead_beef);
+        assert_eq!(regs.read(0x4), 0xdead_beef);
+        assert_eq!(regs.read(0x8), 0);
+    }
+
+    #[test]
+    fn test_trace_access() {
+        let log = Mutex::new(Vec::new());
+        let regs = TraceAccess::new(RegFile::new(0x10), |op| log.lock().unwrap().push(op));
+
+        regs.write(0x8, 0x1234);
+        assert_eq!(regs.read(0x8), 0x1234);
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                RegOp::Write {
+                    offset: 0x8,
+                    value: 0x1234
+                },
+                RegOp::Read {
+                    offset: 0x8,
+                    value: 0x1234
+                },
+            ]
+        );
+    }
+}
@@ -1,5 +1,8 @@
+use alloc::sync::Arc;
 use core::ops::RangeBounds;
 
+use crate::{delay::Delay, regmap::RegAccess};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RstId(u64);
 
@@ -56,39 +59,58 @@ impl RangeBounds<RstId> for RstId {
 
 #[derive(Clone)]
 pub struct ResetRockchip {
-    base: usize,
+    regs: Arc<dyn RegAccess>,
+    /// 复位寄存器起始偏移
+    offset: usize,
     _reset_num: usize,
+    /// 复位脉冲计时
+    delay: Arc<dyn Delay>,
 }
 
 impl ResetRockchip {
-    pub(crate) fn new(base: usize, reset_num: usize) -> Self {
+    pub(crate) fn new(
+        regs: Arc<dyn RegAccess>,
+        offset: usize,
+        reset_num: usize,
+        delay: Arc<dyn Delay>,
+    ) -> Self {
         ResetRockchip {
-            base,
+            regs,
+            offset,
             _reset_num: reset_num,
+            delay,
         }
     }
 
     pub fn reset_assert(&self, id: RstId) {
         let bank = id.value() / 16;
         let offset = id.value() % 16;
-        let addr = self.base + (bank as usize * 4);
-        debug!("reset (id={id}) (reg_addr={addr:#x})",);
+        let reg = self.offset + (bank as usize * 4);
+        debug!("reset (id={id}) (reg_offset={reg:#x})",);
 
-        unsafe {
-            let reg = addr as *mut u32;
-            core::ptr::write_volatile(reg, 1 << offset | (1 << offset) << 16);
-        }
+        self.regs.write(reg, 1 << offset | (1 << offset) << 16);
     }
 
     pub fn reset_deassert(&self, id: RstId) {
         let bank = id.value() / 16;
         let offset = id.value() % 16;
-        let addr = self.base + (bank as usize * 4);
-        debug!("deassert reset (id={id}) (reg_addr={addr:#x})",);
+        let reg = self.offset + (bank as usize * 4);
+        debug!("deassert reset (id={id}) (reg_offset={reg:#x})",);
 
-        unsafe {
-            let reg = addr as *mut u32;
-            core::ptr::write_volatile(reg, (1 << offset) << 16);
-        }
+        self.regs.write(reg, (1 << offset) << 16);
+    }
+
+    /// 产生复位脉冲：置位复位，保持至少 `width_us` 微秒后释放
+    ///
+    /// 参考 Linux: reset_control_reset()
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 复位 ID
+    /// * `width_us` - 复位保持的最短时间 (us)
+    pub fn reset_pulse(&self, id: RstId, width_us: u64) {
+        self.reset_assert(id);
+        self.delay.delay_us(width_us);
+        self.reset_deassert(id);
     }
 }
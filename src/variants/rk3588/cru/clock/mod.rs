@@ -45,6 +45,12 @@ clk_id_group!(
     PLL_PPLL = 9,
 );
 
+// =============================================================================
+// CPU 集群时钟 ID
+// =============================================================================
+
+clk_id_group!(ARMCLK_L = 10, ARMCLK_B01 = 11, ARMCLK_B23 = 12,);
+
 // =============================================================================
 // I2C 时钟 ID
 // =============================================================================
@@ -189,6 +195,30 @@ clk_id_group!(PCLK_SARADC = 156, CLK_SARADC = 157,);
 
 clk_id_group!(PCLK_TSADC = 169, CLK_TSADC = 170,);
 
+// =============================================================================
+// 公共时钟源 ID
+// =============================================================================
+
+// 由 GPLL/CPLL 等分频得到的公共时钟源 (CLKSEL_CON(0) ~ CLKSEL_CON(7))
+clk_id_group!(
+    CLK_50M_SRC = 236,
+    CLK_100M_SRC = 237,
+    CLK_150M_SRC = 238,
+    CLK_200M_SRC = 239,
+    CLK_250M_SRC = 240,
+    CLK_300M_SRC = 241,
+    CLK_350M_SRC = 242,
+    CLK_400M_SRC = 243,
+    CLK_450M_SRC = 244,
+    CLK_500M_SRC = 245,
+    CLK_600M_SRC = 246,
+    CLK_650M_SRC = 247,
+    CLK_700M_SRC = 248,
+    CLK_800M_SRC = 249,
+    CLK_1000M_SRC = 250,
+    CLK_1200M_SRC = 251,
+);
+
 // =============================================================================
 // 根时钟 ID
 // =============================================================================
@@ -198,6 +228,8 @@ clk_id_group!(
     ACLK_TOP_ROOT = 270,
     PCLK_TOP_ROOT = 271,
     ACLK_LOW_TOP_ROOT = 272,
+    ACLK_TOP_S400 = 273,
+    ACLK_TOP_S200 = 274,
     ACLK_CENTER_ROOT = 216,
     ACLK_CENTER_LOW_ROOT = 217,
     HCLK_CENTER_ROOT = 218,
@@ -209,8 +241,15 @@ clk_id_group!(
 // =============================================================================
 
 clk_id_group!(
+    SCLK_SDIO_DRV = 304,
+    SCLK_SDIO_SAMPLE = 305,
+    SCLK_SDMMC_DRV = 306,
+    SCLK_SDMMC_SAMPLE = 307,
+    HCLK_SDIO_ROOT = 309,
     HCLK_SDIO = 409,
     CCLK_SRC_SDIO = 410,
+    HCLK_NVM_ROOT = 310,
+    ACLK_NVM_ROOT = 311,
     HCLK_EMMC = 312,
     ACLK_EMMC = 313,
     CCLK_EMMC = 314,
@@ -221,6 +260,49 @@ clk_id_group!(
     HCLK_SFC_XIP = 319,
 );
 
+// =============================================================================
+// GPU 时钟 ID
+// =============================================================================
+
+clk_id_group!(
+    CLK_GPU_SRC = 275,
+    CLK_GPU = 276,
+    CLK_GPU_COREGROUP = 277,
+    CLK_GPU_STACKS = 278,
+    ACLK_S_GPU_BIU = 279,
+    ACLK_M0_GPU_BIU = 280,
+    ACLK_M1_GPU_BIU = 281,
+    ACLK_M2_GPU_BIU = 282,
+    ACLK_M3_GPU_BIU = 283,
+    PCLK_GPU_ROOT = 284,
+    PCLK_GPU_GRF = 285,
+    CLK_GPU_PVTM = 286,
+    CLK_CORE_GPU_PVTM = 287,
+);
+
+// =============================================================================
+// NPU 时钟 ID
+// =============================================================================
+
+clk_id_group!(
+    ACLK_NPU1 = 288,
+    HCLK_NPU1 = 289,
+    ACLK_NPU2 = 290,
+    HCLK_NPU2 = 291,
+    HCLK_NPU_CM0_ROOT = 292,
+    FCLK_NPU_CM0_CORE = 293,
+    CLK_NPU_CM0_RTC = 294,
+    PCLK_NPU_PVTM = 295,
+    PCLK_NPU_GRF = 296,
+    CLK_NPU_PVTM = 297,
+    CLK_CORE_NPU_PVTM = 298,
+    ACLK_NPU0 = 299,
+    HCLK_NPU0 = 300,
+    HCLK_NPU_ROOT = 301,
+    CLK_NPU_DSU0 = 302,
+    PCLK_NPU_ROOT = 303,
+);
+
 // =============================================================================
 // GMAC 时钟 ID
 // =============================================================================
@@ -232,6 +314,82 @@ clk_id_group!(
     CLK_GMAC_50M = 325,
 );
 
+clk_id_group!(
+    PCLK_GMAC0 = 359,
+    PCLK_GMAC1 = 360,
+    ACLK_GMAC0 = 364,
+    ACLK_GMAC1 = 365,
+);
+
+clk_id_group!(REFCLKO25M_ETH0_OUT = 604, REFCLKO25M_ETH1_OUT = 605,);
+
+// =============================================================================
+// PHP (PCIe/SATA/GMAC/USB3) 时钟 ID
+// =============================================================================
+
+// PHP 根时钟和总线
+clk_id_group!(
+    PCLK_PHP_ROOT = 358,
+    ACLK_PCIE_ROOT = 361,
+    ACLK_PHP_ROOT = 362,
+    ACLK_PCIE_BRIDGE = 363,
+    ACLK_PHP_GIC_ITS = 366,
+    ACLK_MMU_PCIE = 367,
+    ACLK_MMU_PHP = 368,
+);
+
+// SATA
+clk_id_group!(
+    ACLK_SATA0 = 369,
+    ACLK_SATA1 = 370,
+    ACLK_SATA2 = 371,
+    CLK_RXOOB0 = 372,
+    CLK_RXOOB1 = 373,
+    CLK_RXOOB2 = 374,
+);
+
+// PCIe 控制器: 4L = PCIe3.0 x4, 2L = PCIe3.0 x2, 1L0~1L2 = PCIe2.0 x1
+clk_id_group!(
+    ACLK_PCIE_4L_DBI = 431,
+    ACLK_PCIE_2L_DBI = 432,
+    ACLK_PCIE_1L0_DBI = 433,
+    ACLK_PCIE_1L1_DBI = 434,
+    ACLK_PCIE_1L2_DBI = 435,
+    ACLK_PCIE_4L_MSTR = 436,
+    ACLK_PCIE_2L_MSTR = 437,
+    ACLK_PCIE_1L0_MSTR = 438,
+    ACLK_PCIE_1L1_MSTR = 439,
+    ACLK_PCIE_1L2_MSTR = 440,
+    ACLK_PCIE_4L_SLV = 441,
+    ACLK_PCIE_2L_SLV = 442,
+    ACLK_PCIE_1L0_SLV = 443,
+    ACLK_PCIE_1L1_SLV = 444,
+    ACLK_PCIE_1L2_SLV = 445,
+    PCLK_PCIE_4L = 446,
+    PCLK_PCIE_2L = 447,
+    PCLK_PCIE_1L0 = 448,
+    PCLK_PCIE_1L1 = 449,
+    PCLK_PCIE_1L2 = 450,
+    CLK_PCIE_AUX0 = 451,
+    CLK_PCIE_AUX1 = 452,
+    CLK_PCIE_AUX2 = 453,
+    CLK_PCIE_AUX3 = 454,
+    CLK_PCIE_AUX4 = 455,
+    CLK_PCIE4L_PIPE = 456,
+    CLK_PCIE2L_PIPE = 457,
+    CLK_PCIE1L0_PIPE = 458,
+    CLK_PCIE1L1_PIPE = 459,
+    CLK_PCIE1L2_PIPE = 460,
+);
+
+// PHP CRU: PCIe3 PHY 参考时钟
+clk_id_group!(
+    CLK_PHY0_REF_ALT_P = 461,
+    CLK_PHY0_REF_ALT_M = 462,
+    CLK_PHY1_REF_ALT_P = 463,
+    CLK_PHY1_REF_ALT_M = 464,
+);
+
 // =============================================================================
 // USB 时钟 ID
 // =============================================================================
@@ -269,6 +427,262 @@ clk_id_group!(
 
 clk_id_group!(USBDPPHY_MIPIDCPPHY_REF = 694,);
 
+// =============================================================================
+// 视频编解码时钟 ID
+// =============================================================================
+
+// VDPU (VPU121/JPEG/IEP/RGA 所在的视频域)
+clk_id_group!(
+    ACLK_VDPU_ROOT = 252,
+    ACLK_VDPU_LOW_ROOT = 253,
+    HCLK_VDPU_ROOT = 254,
+    ACLK_JPEG_DECODER_ROOT = 255,
+    ACLK_VPU = 256,
+    HCLK_VPU = 257,
+    ACLK_JPEG_ENCODER0 = 258,
+    HCLK_JPEG_ENCODER0 = 259,
+    ACLK_JPEG_ENCODER1 = 260,
+    HCLK_JPEG_ENCODER1 = 261,
+    ACLK_JPEG_ENCODER2 = 262,
+    HCLK_JPEG_ENCODER2 = 263,
+    ACLK_JPEG_ENCODER3 = 264,
+    HCLK_JPEG_ENCODER3 = 265,
+    ACLK_JPEG_DECODER = 266,
+    HCLK_JPEG_DECODER = 267,
+);
+
+// RKVDEC
+clk_id_group!(
+    HCLK_RKVDEC0_ROOT = 326,
+    ACLK_RKVDEC0_ROOT = 327,
+    ACLK_RKVDEC_CCU = 328,
+    HCLK_RKVDEC0 = 329,
+    ACLK_RKVDEC0 = 330,
+    CLK_RKVDEC0_CA = 331,
+    CLK_RKVDEC0_HEVC_CA = 332,
+    CLK_RKVDEC0_CORE = 333,
+    HCLK_RKVDEC1_ROOT = 334,
+    ACLK_RKVDEC1_ROOT = 335,
+    HCLK_RKVDEC1 = 336,
+    ACLK_RKVDEC1 = 337,
+    CLK_RKVDEC1_CA = 338,
+    CLK_RKVDEC1_HEVC_CA = 339,
+    CLK_RKVDEC1_CORE = 340,
+);
+
+// RKVENC
+clk_id_group!(
+    HCLK_RKVENC0_ROOT = 341,
+    ACLK_RKVENC0_ROOT = 342,
+    HCLK_RKVENC0 = 343,
+    ACLK_RKVENC0 = 344,
+    CLK_RKVENC0_CORE = 345,
+    HCLK_RKVENC1_ROOT = 346,
+    ACLK_RKVENC1_ROOT = 347,
+    HCLK_RKVENC1 = 348,
+    ACLK_RKVENC1 = 349,
+    CLK_RKVENC1_CORE = 350,
+);
+
+// AV1
+clk_id_group!(
+    ACLK_AV1_ROOT = 351,
+    ACLK_AV1 = 352,
+    PCLK_AV1_ROOT = 353,
+    PCLK_AV1 = 354,
+);
+
+// =============================================================================
+// 摄像头 (VI/ISP) 时钟 ID
+// =============================================================================
+
+// VI 域: VICAP, ISP0, FISHEYE, MIPI CSI host
+clk_id_group!(
+    ACLK_VI_ROOT = 379,
+    HCLK_VI_ROOT = 380,
+    PCLK_VI_ROOT = 381,
+    DCLK_VICAP = 382,
+    ACLK_VICAP = 383,
+    HCLK_VICAP = 384,
+    CLK_ISP0_CORE = 385,
+    CLK_ISP0_CORE_MARVIN = 386,
+    CLK_ISP0_CORE_VICAP = 387,
+    ACLK_ISP0 = 388,
+    HCLK_ISP0 = 389,
+    ACLK_FISHEYE0 = 390,
+    HCLK_FISHEYE0 = 391,
+    CLK_FISHEYE0_CORE = 392,
+    ACLK_FISHEYE1 = 393,
+    HCLK_FISHEYE1 = 394,
+    CLK_FISHEYE1_CORE = 395,
+    PCLK_CSI_HOST_0 = 396,
+    PCLK_CSI_HOST_1 = 397,
+    PCLK_CSI_HOST_2 = 398,
+    PCLK_CSI_HOST_3 = 399,
+    PCLK_CSI_HOST_4 = 400,
+    PCLK_CSI_HOST_5 = 401,
+);
+
+// ISP1 域
+clk_id_group!(
+    ACLK_ISP1_ROOT = 402,
+    HCLK_ISP1_ROOT = 403,
+    CLK_ISP1_CORE = 404,
+    CLK_ISP1_CORE_MARVIN = 405,
+    CLK_ISP1_CORE_VICAP = 406,
+    ACLK_ISP1 = 407,
+    HCLK_ISP1 = 408,
+);
+
+// 摄像头主时钟 (MCLK) 输出
+clk_id_group!(
+    CLK_CIFOUT_OUT = 425,
+    CLK_MIPI_CAMARAOUT_M0 = 426,
+    CLK_MIPI_CAMARAOUT_M1 = 427,
+    CLK_MIPI_CAMARAOUT_M2 = 428,
+    CLK_MIPI_CAMARAOUT_M3 = 429,
+    CLK_MIPI_CAMARAOUT_M4 = 430,
+);
+
+// =============================================================================
+// VOP 时钟 ID
+// =============================================================================
+
+clk_id_group!(
+    ACLK_VOP_ROOT = 619,
+    ACLK_VOP_LOW_ROOT = 620,
+    HCLK_VOP_ROOT = 621,
+    PCLK_VOP_ROOT = 622,
+    HCLK_VOP = 623,
+    ACLK_VOP = 624,
+    DCLK_VOP0_SRC = 625,
+    DCLK_VOP1_SRC = 626,
+    DCLK_VOP2_SRC = 627,
+    DCLK_VOP0 = 628,
+    DCLK_VOP1 = 629,
+    DCLK_VOP2 = 630,
+    DCLK_VOP3 = 631,
+    ACLK_VOP_SUB_SRC = 638,
+);
+
+// =============================================================================
+// 音频时钟 ID (I2S/SPDIF/PDM)
+// =============================================================================
+
+clk_id_group!(
+    CLK_I2S0_8CH_TX_SRC = 465,
+    CLK_I2S0_8CH_TX_FRAC = 466,
+    CLK_I2S0_8CH_TX = 467,
+    MCLK_I2S0_8CH_TX = 468,
+    CLK_I2S0_8CH_RX_SRC = 469,
+    CLK_I2S0_8CH_RX_FRAC = 470,
+    CLK_I2S0_8CH_RX = 471,
+    MCLK_I2S0_8CH_RX = 472,
+    I2S0_8CH_MCLKOUT = 473,
+    CLK_I2S2_2CH_SRC = 474,
+    CLK_I2S2_2CH_FRAC = 475,
+    CLK_I2S2_2CH = 476,
+    MCLK_I2S2_2CH = 477,
+    I2S2_2CH_MCLKOUT = 478,
+    CLK_I2S3_2CH_SRC = 479,
+    CLK_I2S3_2CH_FRAC = 480,
+    CLK_I2S3_2CH = 481,
+    MCLK_I2S3_2CH = 482,
+    I2S3_2CH_MCLKOUT = 483,
+    CLK_I2S4_8CH_TX_SRC = 484,
+    CLK_I2S4_8CH_TX_FRAC = 485,
+    CLK_I2S4_8CH_TX = 486,
+    MCLK_I2S4_8CH_TX = 487,
+    CLK_I2S5_8CH_TX_SRC = 488,
+    CLK_I2S5_8CH_TX_FRAC = 489,
+    CLK_I2S5_8CH_TX = 490,
+    MCLK_I2S5_8CH_TX = 491,
+    CLK_I2S6_8CH_TX_SRC = 492,
+    CLK_I2S6_8CH_TX_FRAC = 493,
+    CLK_I2S6_8CH_TX = 494,
+    MCLK_I2S6_8CH_TX = 495,
+    CLK_I2S6_8CH_RX_SRC = 496,
+    CLK_I2S6_8CH_RX_FRAC = 497,
+    CLK_I2S6_8CH_RX = 498,
+    MCLK_I2S6_8CH_RX = 499,
+    CLK_I2S7_8CH_RX_SRC = 500,
+    CLK_I2S7_8CH_RX_FRAC = 501,
+    CLK_I2S7_8CH_RX = 502,
+    MCLK_I2S7_8CH_RX = 503,
+    CLK_I2S8_8CH_TX_SRC = 504,
+    CLK_I2S8_8CH_TX_FRAC = 505,
+    CLK_I2S8_8CH_TX = 506,
+    MCLK_I2S8_8CH_TX = 507,
+    CLK_I2S9_8CH_RX_SRC = 508,
+    CLK_I2S9_8CH_RX_FRAC = 509,
+    CLK_I2S9_8CH_RX = 510,
+    MCLK_I2S9_8CH_RX = 511,
+    CLK_SPDIF0_SRC = 512,
+    CLK_SPDIF0_FRAC = 513,
+    CLK_SPDIF0 = 514,
+    MCLK_SPDIF0 = 515,
+    CLK_SPDIF1_SRC = 516,
+    CLK_SPDIF1_FRAC = 517,
+    CLK_SPDIF1 = 518,
+    MCLK_SPDIF1 = 519,
+    MCLK_SPDIFRX0 = 520,
+    MCLK_SPDIFRX1 = 521,
+    MCLK_SPDIFRX2 = 522,
+    MCLK_PDM1 = 523,
+    I2S0_8CH_MCLKOUT_TO_IO = 524,
+    I2S1_8CH_MCLKOUT_TO_IO = 525,
+    I2S2_2CH_MCLKOUT_TO_IO = 526,
+    I2S3_2CH_MCLKOUT_TO_IO = 527,
+);
+
+// PMU CRU 总线根时钟
+clk_id_group!(
+    CLK_PMU1_50M_SRC = 641,
+    CLK_PMU1_100M_SRC = 642,
+    HCLK_PMU1_ROOT = 643,
+    CLK_PMU1_300M_SRC = 644,
+    CLK_PMU1_400M_SRC = 645,
+    PCLK_PMU1_ROOT = 658,
+    PCLK_PMU0_ROOT = 659,
+    CLK_PMU1_200M_SRC = 660,
+);
+
+// PMU CRU 中的 I2S1 和 PDM0
+clk_id_group!(
+    CLK_I2S1_8CH_TX_SRC = 648,
+    CLK_I2S1_8CH_TX_FRAC = 649,
+    CLK_I2S1_8CH_TX = 650,
+    MCLK_I2S1_8CH_TX = 651,
+    CLK_I2S1_8CH_RX_SRC = 652,
+    CLK_I2S1_8CH_RX_FRAC = 653,
+    CLK_I2S1_8CH_RX = 654,
+    MCLK_I2S1_8CH_RX = 655,
+    I2S1_8CH_MCLKOUT = 656,
+    MCLK_PDM0 = 657,
+);
+
+// =============================================================================
+// 内部时钟 ID
+// =============================================================================
+
+// 以下时钟在 rk3588-cru.h 中没有定义 (由设备树 fixed-clock、SCMI 或 PHY 驱动提供，
+// 或在 Linux 中以 ID 0 注册)，仅用于描述时钟树拓扑，取值避开 dt-bindings 的范围
+clk_id_group!(
+    XIN24M = 0x1000,
+    SPLL = 0x1001,
+    CLK_HDMIPHY_PIXEL0 = 0x1002,
+    CLK_HDMIPHY_PIXEL1 = 0x1003,
+    ACLK_VOP_DIV2_SRC = 0x1004,
+    SCLK_DSU = 0x1005,
+    CLK_NPU_PVTPLL = 0x1006,
+    CLK_NPU_MUX = 0x1007,
+    CLK_GPU_PVTPLL = 0x1008,
+    XIN12M = 0x1009,
+    I2S_MCLKIN = 0x100a,
+    CCLK_SRC_SDMMC = 0x100b,
+    HCLK_SDMMC = 0x100c,
+);
+
 // =============================================================================
 // 辅助函数：时钟类型判断和外设编号提取
 // =============================================================================
@@ -278,6 +692,31 @@ pub fn is_pll_clk(clk_id: ClkId) -> bool {
     (PLL_B0PLL..=PLL_PPLL).contains(&clk_id)
 }
 
+/// 判断时钟 ID 是否为 CPU 集群 (包括 DSU)
+pub fn is_cpu_clk(clk_id: ClkId) -> bool {
+    (ARMCLK_L..=ARMCLK_B23).contains(&clk_id) || clk_id == SCLK_DSU
+}
+
+/// 判断时钟 ID 是否为总线根时钟 (包括 PMU 根时钟)
+pub fn is_root_clk(clk_id: ClkId) -> bool {
+    matches!(
+        clk_id,
+        ACLK_BUS_ROOT
+            | ACLK_TOP_ROOT
+            | PCLK_TOP_ROOT
+            | ACLK_LOW_TOP_ROOT
+            | ACLK_TOP_S400
+            | ACLK_TOP_S200
+            | ACLK_CENTER_ROOT
+            | ACLK_CENTER_LOW_ROOT
+            | HCLK_CENTER_ROOT
+            | PCLK_CENTER_ROOT
+            | HCLK_PMU1_ROOT
+            | PCLK_PMU1_ROOT
+            | PCLK_PMU0_ROOT
+    )
+}
+
 /// 判断时钟 ID 是否为 I2C
 pub fn is_i2c_clk(clk_id: ClkId) -> bool {
     // PMU I2C0: PCLK_I2C0, CLK_I2C0
@@ -309,10 +748,85 @@ pub fn is_adc_clk(clk_id: ClkId) -> bool {
     (PCLK_SARADC..=CLK_SARADC).contains(&clk_id) || (PCLK_TSADC..=CLK_TSADC).contains(&clk_id)
 }
 
-/// 判断时钟 ID 是否为 MMC/EMMC/SDIO/SFC
+/// 判断时钟 ID 是否为 MMC/EMMC/SDIO/SDMMC/SFC
 pub fn is_mmc_clk(clk_id: ClkId) -> bool {
-    // CCLK_EMMC, BCLK_EMMC, CCLK_SRC_SDIO, SCLK_SFC
-    matches!(clk_id, CCLK_EMMC | BCLK_EMMC | CCLK_SRC_SDIO | SCLK_SFC)
+    // SCLK_SDIO_DRV ~ HCLK_SFC_XIP: 304-319 (相位时钟和 NVM 域)
+    (SCLK_SDIO_DRV..=HCLK_SFC_XIP).contains(&clk_id)
+        || matches!(
+            clk_id,
+            HCLK_SDIO | CCLK_SRC_SDIO | CCLK_SRC_SDMMC | HCLK_SDMMC
+        )
+}
+
+/// 判断时钟 ID 是否为 GPU
+pub fn is_gpu_clk(clk_id: ClkId) -> bool {
+    // CLK_GPU_SRC ~ CLK_CORE_GPU_PVTM: 275-287
+    (CLK_GPU_SRC..=CLK_CORE_GPU_PVTM).contains(&clk_id)
+}
+
+/// 判断时钟 ID 是否为 NPU
+pub fn is_npu_clk(clk_id: ClkId) -> bool {
+    // ACLK_NPU1 ~ PCLK_NPU_ROOT: 288-303
+    (ACLK_NPU1..=PCLK_NPU_ROOT).contains(&clk_id) || clk_id == CLK_NPU_MUX
+}
+
+/// 判断时钟 ID 是否为 PHP 域 (PHP 根时钟、PCIe、SATA)
+pub fn is_php_clk(clk_id: ClkId) -> bool {
+    // GMAC 和 USB3OTG2 的时钟虽然在 PHP 域，但由各自的函数处理
+    matches!(
+        clk_id,
+        PCLK_PHP_ROOT
+            | ACLK_PCIE_ROOT
+            | ACLK_PHP_ROOT
+            | ACLK_PCIE_BRIDGE
+            | ACLK_PHP_GIC_ITS
+            | ACLK_MMU_PCIE
+            | ACLK_MMU_PHP
+    ) || (ACLK_SATA0..=CLK_RXOOB2).contains(&clk_id)
+        || (ACLK_PCIE_4L_DBI..=CLK_PHY1_REF_ALT_M).contains(&clk_id)
+}
+
+/// 判断时钟 ID 是否为 GMAC (包括以太网 PHY 参考时钟输出)
+pub fn is_gmac_clk(clk_id: ClkId) -> bool {
+    matches!(
+        clk_id,
+        CLK_GMAC0_PTP_REF
+            | CLK_GMAC1_PTP_REF
+            | CLK_GMAC_125M
+            | CLK_GMAC_50M
+            | REFCLKO25M_ETH0_OUT
+            | REFCLKO25M_ETH1_OUT
+    )
+}
+
+/// 判断时钟 ID 是否为视频编解码 (RKVDEC/RKVENC/VDPU/JPEG/AV1)
+pub fn is_video_clk(clk_id: ClkId) -> bool {
+    // ACLK_VDPU_ROOT ~ HCLK_JPEG_DECODER: 252-267
+    // HCLK_RKVDEC0_ROOT ~ PCLK_AV1: 326-354
+    (ACLK_VDPU_ROOT..=HCLK_JPEG_DECODER).contains(&clk_id)
+        || (HCLK_RKVDEC0_ROOT..=PCLK_AV1).contains(&clk_id)
+}
+
+/// 判断时钟 ID 是否为摄像头 (VICAP/ISP/FISHEYE/CSI host/MCLK 输出)
+pub fn is_camera_clk(clk_id: ClkId) -> bool {
+    // ACLK_VI_ROOT ~ HCLK_ISP1: 379-408
+    // CLK_CIFOUT_OUT ~ CLK_MIPI_CAMARAOUT_M4: 425-430
+    (ACLK_VI_ROOT..=HCLK_ISP1).contains(&clk_id)
+        || (CLK_CIFOUT_OUT..=CLK_MIPI_CAMARAOUT_M4).contains(&clk_id)
+}
+
+/// 判断时钟 ID 是否为音频 (I2S/SPDIF/PDM)
+pub fn is_audio_clk(clk_id: ClkId) -> bool {
+    // CLK_I2S0_8CH_TX_SRC ~ I2S3_2CH_MCLKOUT_TO_IO: 465-527
+    // CLK_I2S1_8CH_TX_SRC ~ MCLK_PDM0: 648-657 (PMU)
+    (CLK_I2S0_8CH_TX_SRC..=I2S3_2CH_MCLKOUT_TO_IO).contains(&clk_id)
+        || (CLK_I2S1_8CH_TX_SRC..=MCLK_PDM0).contains(&clk_id)
+}
+
+/// 判断时钟 ID 是否为 VOP (显示控制器)
+pub fn is_vop_clk(clk_id: ClkId) -> bool {
+    // ACLK_VOP_ROOT ~ DCLK_VOP3: 619-631
+    (ACLK_VOP_ROOT..=DCLK_VOP3).contains(&clk_id)
 }
 
 /// 判断时钟 ID 是否为 USB
@@ -20,11 +20,15 @@ pub const CPLL_HZ: u64 = 1500 * MHZ;
 pub const NPLL_HZ: u64 = 850 * MHZ;
 pub const PPLL_HZ: u64 = 1100 * MHZ;
 
+/// VOP 像素时钟使用 V0PLL 时的最低 PLL 频率 (u-boot: RK3588_VOP_PLL_LIMIT_FREQ)
+pub const VOP_PLL_LIMIT_HZ: u64 = 600 * MHZ;
+
 // ============================================================================
 // CRU 基地址偏移
 // ============================================================================
 
 pub const RK3588_PHP_CRU_BASE: u32 = 0x8000;
+pub const RK3588_SECURE_CRU_BASE: u32 = 0x10000;
 pub const RK3588_PMU_CRU_BASE: u32 = 0x30000;
 pub const RK3588_BIGCORE0_CRU_BASE: u32 = 0x50000;
 pub const RK3588_BIGCORE1_CRU_BASE: u32 = 0x52000;
@@ -99,6 +103,29 @@ pub const fn php_softrst_con(x: u32) -> u32 {
     x * 0x4 + RK3588_PHP_CRU_BASE + 0xa00
 }
 
+// ============================================================================
+// SECURE CRU 寄存器偏移
+// ============================================================================
+
+/// SECURE CRU 时钟选择寄存器
+pub const fn secure_clksel_con(x: u32) -> u32 {
+    x * 0x4 + RK3588_SECURE_CRU_BASE + 0x300
+}
+
+/// SECURE CRU 时钟门控寄存器
+pub const fn secure_clkgate_con(x: u32) -> u32 {
+    x * 0x4 + RK3588_SECURE_CRU_BASE + 0x800
+}
+
+// ============================================================================
+// SYS_GRF 寄存器偏移
+// ============================================================================
+
+/// SYS_GRF SOC 控制寄存器 (相对 SYS_GRF 基地址)
+pub const fn sys_grf_soc_con(x: u32) -> u32 {
+    x * 0x4 + 0x300
+}
+
 // ============================================================================
 // PMU CRU 寄存器偏移
 // ============================================================================
@@ -737,6 +764,30 @@ pub mod pllcon2 {
     pub const K_MASK: u32 = 0xffff << K_SHIFT; // 16 bits
 }
 
+/// RK3588 PLL 配置寄存器 3 (PLLCON3) - 扩频调制参数
+pub mod pllcon3 {
+    /// MFR 调制频率系数: Fmod = Fref / (P * MFR * 32)
+    pub const MFR_SHIFT: u32 = 0;
+    pub const MFR_MASK: u32 = 0xff << MFR_SHIFT; // 8 bits
+
+    /// MRR 调制深度系数: 深度 = MFR * MRR / (M * 64)
+    pub const MRR_SHIFT: u32 = 8;
+    pub const MRR_MASK: u32 = 0x3f << MRR_SHIFT; // 6 bits
+
+    /// 扩频方式
+    pub const SEL_PF_SHIFT: u32 = 14;
+    pub const SEL_PF_MASK: u32 = 0x3 << SEL_PF_SHIFT;
+    pub const SEL_PF_DOWN: u32 = 0;
+    pub const SEL_PF_UP: u32 = 1;
+    pub const SEL_PF_CENTER: u32 = 2;
+}
+
+/// RK3588 PLL 配置寄存器 4 (PLLCON4)
+pub mod pllcon4 {
+    /// 扩频调制使能
+    pub const SSCG_EN: u32 = 1 << 0;
+}
+
 /// RK3588 PLL 配置寄存器 6 (PLLCON6)
 pub mod pllcon6 {
     /// PLL 锁定状态
@@ -0,0 +1,224 @@
+//! RK3588 CPU 集群时钟
+//!
+//! 参考 Linux: drivers/clk/rockchip/clk-cpu.c, drivers/clk/rockchip/clk-rk3588.c
+//!
+//! RK3588 有三个 CPU 集群，每个集群的时钟都在独立的 CRU 中：
+//!
+//! - ARMCLK_L: 4 个 A55 小核，LPLL (DSU CRU)
+//! - ARMCLK_B01: A76 大核 0/1，B0PLL (BIGCORE0 CRU)
+//! - ARMCLK_B23: A76 大核 2/3，B1PLL (BIGCORE1 CRU)
+//!
+//! 集群 mux 可选 xin24m、GPLL (备用) 和集群 PLL (主时钟)，每个核还有独立的分频器。
+//! 这里只负责时钟配置，提高频率前需要由调用者先提高 CPU 电压
+
+use super::clock::*;
+use super::consts::*;
+use super::*;
+use crate::clock::tree::RegField;
+use crate::clock::{ClockError, ClockResult};
+
+/// CPU 集群时钟描述
+///
+/// 参考 Linux: struct rockchip_cpuclk_reg_data
+struct CpuCluster {
+    /// 集群时钟 ID
+    id: ClkId,
+    /// 主时钟 PLL
+    pll: PllId,
+    /// 每个核的分频器，第一个同时作为集群时钟的分频器
+    core_divs: &'static [RegField],
+}
+
+const CPU_CLUSTERS: [CpuCluster; 3] = [
+    CpuCluster {
+        id: ARMCLK_L,
+        pll: PllId::LPLL,
+        core_divs: &[
+            RegField::new(dsu_clksel_con(6), 0, 5),
+            RegField::new(dsu_clksel_con(6), 7, 5),
+            RegField::new(dsu_clksel_con(7), 0, 5),
+            RegField::new(dsu_clksel_con(7), 7, 5),
+        ],
+    },
+    CpuCluster {
+        id: ARMCLK_B01,
+        pll: PllId::B0PLL,
+        core_divs: &[
+            RegField::new(bigcore0_clksel_con(0), 8, 5),
+            RegField::new(bigcore0_clksel_con(1), 0, 5),
+        ],
+    },
+    CpuCluster {
+        id: ARMCLK_B23,
+        pll: PllId::B1PLL,
+        core_divs: &[
+            RegField::new(bigcore1_clksel_con(0), 8, 5),
+            RegField::new(bigcore1_clksel_con(1), 0, 5),
+        ],
+    },
+];
+
+/// 集群 mux 的备用父时钟 (Linux: mux_core_alt)
+const CPU_ALT_PARENT: ClkId = PLL_GPLL;
+
+impl Cru {
+    /// 设置 CPU 集群频率
+    ///
+    /// 参考 Linux: drivers/clk/rockchip/clk-cpu.c:rockchip_cpuclk_pre_rate_change()
+    ///
+    /// 1. 设置核分频，使切换到 GPLL 后的频率不超过当前频率
+    /// 2. 集群切换到 GPLL
+    /// 3. 重新配置集群 PLL
+    /// 4. 切回集群 PLL，核分频清零
+    ///
+    /// SCLK_DSU 按时钟树在现有父时钟中选择最接近的频率，不会修改 PLL
+    ///
+    /// # 参数
+    ///
+    /// * `id` - ARMCLK_L / ARMCLK_B01 / ARMCLK_B23 或 SCLK_DSU
+    /// * `rate_hz` - 目标频率 (Hz)
+    ///
+    /// # 返回
+    ///
+    /// 返回设置后实际的时钟频率
+    ///
+    /// # Errors
+    ///
+    /// 时钟 ID 不支持返回 `ClockError::UnsupportedClock`，
+    /// PLL 无法配置到目标频率时返回 `ClockError::PllConfigError`
+    pub(crate) fn cpu_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        if id == SCLK_DSU {
+            return self.clk_tree_set_rate(id, rate_hz);
+        }
+
+        let cluster = CPU_CLUSTERS
+            .iter()
+            .find(|cluster| cluster.id == id)
+            .ok_or(ClockError::unsupported(id))?;
+        let pll = ClkId::from(cluster.pll as u32);
+
+        let old_rate = self.clk_tree_get_rate(id)?;
+        let alt_rate = self.clk_tree_get_rate(CPU_ALT_PARENT)?;
+
+        // 共用集群 PLL 的时钟在切换前收到通知，可以否决
+        let pending = self.clk_notify_pre_nested(id, pll, rate_hz)?;
+
+        // 1. 备用时钟分频不低于当前频率对应的分频
+        let alt_div = if old_rate == 0 || alt_rate <= old_rate {
+            0
+        } else {
+            (alt_rate.div_ceil(old_rate) - 1) as u32
+        };
+        for div in cluster.core_divs {
+            let alt_div = alt_div.min(div.max());
+            self.clrsetreg(div.reg, div.mask, alt_div << div.shift);
+        }
+
+        // 2. 切换到 GPLL
+        if let Err(e) = self.clk_core_set_parent(id, CPU_ALT_PARENT) {
+            self.clk_notify_abort_nested(pending);
+            return Err(e);
+        }
+
+        // 3. 配置集群 PLL
+        debug!(
+            "{}: {}MHz -> {}MHz (alt div {})",
+            cluster.pll.name(),
+            old_rate / MHZ,
+            rate_hz / MHZ,
+            alt_div
+        );
+        if let Err(e) = self.pll_set_rate(cluster.pll, rate_hz) {
+            warn!("{}: staying on GPLL, {:?}", cluster.pll.name(), e);
+            self.clk_notify_abort_nested(pending);
+            return Err(e);
+        }
+
+        // 4. 切回集群 PLL
+        self.clk_core_set_parent(id, pll)?;
+        for div in cluster.core_divs {
+            self.clrreg(div.reg, div.mask);
+        }
+
+        self.clk_tree_get_rate(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{delay::FakeDelay, regmap::RegFile, variants::rk3588::cru::sim::sim_cru};
+
+    #[test]
+    fn test_cpu_cluster_div_matches_tree() {
+        for cluster in &CPU_CLUSTERS {
+            let node = tree::clk_nodes()
+                .find(|node| node.id == cluster.id)
+                .unwrap();
+            assert_eq!(node.divider().unwrap().field, cluster.core_divs[0]);
+            assert!(node.parents.contains(&ClkId::from(cluster.pll as u32)));
+            assert!(node.parents.contains(&CPU_ALT_PARENT));
+        }
+    }
+
+    #[test]
+    fn test_cpu_cluster_set_rate() {
+        let (sim, mut cru) = sim_cru();
+
+        for (id, pll, rate) in [
+            (ARMCLK_L, PLL_LPLL, 1_416 * MHZ),
+            (ARMCLK_B01, PLL_B0PLL, 1_800 * MHZ),
+            (ARMCLK_B23, PLL_B1PLL, 2_208 * MHZ),
+        ] {
+            assert_eq!(cru.clk_set_rate(id, rate).unwrap(), rate);
+            assert_eq!(cru.clk_get_parent(id).unwrap(), pll);
+            assert_eq!(cru.clk_get_rate(pll).unwrap(), rate);
+        }
+
+        // 所有核分频都已清零
+        assert_eq!(sim.read(dsu_clksel_con(7) as usize) & 0x0f9f, 0);
+        assert_eq!(sim.read(bigcore0_clksel_con(1) as usize) & 0x1f, 0);
+    }
+
+    #[test]
+    fn test_cpu_cluster_alt_div() {
+        let (sim, mut cru) = sim_cru();
+
+        cru.clk_set_rate(ARMCLK_B01, 408 * MHZ).unwrap();
+
+        // 切到 GPLL 的过程中频率不能超过当前的 408MHz: 1188 / 3 = 396MHz
+        let log = alloc::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let trace = {
+            let log = log.clone();
+            crate::regmap::TraceAccess::new(sim.clone(), move |op| log.lock().unwrap().push(op))
+        };
+        let mut cru = Cru::from_access(
+            0,
+            Arc::new(trace),
+            Arc::new(RegFile::new(0x1000)),
+            Arc::new(FakeDelay::new()),
+        );
+        cru.clk_set_rate(ARMCLK_B01, 1_800 * MHZ).unwrap();
+
+        let sel = bigcore0_clksel_con(0) as usize;
+        let first_write = log
+            .lock()
+            .unwrap()
+            .iter()
+            .find_map(|op| match *op {
+                crate::regmap::RegOp::Write { offset, value } if offset == sel => Some(value),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(first_write, (0x1f << 24) | (2 << 8));
+    }
+
+    #[test]
+    fn test_dsu_set_rate() {
+        let (_, mut cru) = sim_cru();
+
+        cru.clk_set_rate(SCLK_DSU, 594 * MHZ).unwrap();
+        assert_eq!(cru.clk_get_parent(SCLK_DSU).unwrap(), PLL_GPLL);
+        assert_eq!(cru.clk_get_rate(SCLK_DSU).unwrap(), 594 * MHZ);
+    }
+}
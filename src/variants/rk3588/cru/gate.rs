@@ -14,6 +14,23 @@ use super::consts::*;
 use super::*;
 use crate::clock::ClkId;
 
+/// PMU CRU 门控寄存器的索引起点
+///
+/// 主 CRU 的 clkgate_con 编号到 78，PMU CRU 的 pmu_clkgate_con(x)
+/// 在表中记为 `PMU_GATE_BASE + x`
+pub const PMU_GATE_BASE: u32 = 0x100;
+
+/// PHP CRU 门控寄存器的索引起点，php_clkgate_con(x) 在表中记为 `PHP_GATE_BASE + x`
+pub const PHP_GATE_BASE: u32 = 0x200;
+
+/// SECURE CRU 门控寄存器的索引起点，secure_clkgate_con(x) 在表中记为 `SECURE_GATE_BASE + x`
+pub const SECURE_GATE_BASE: u32 = 0x300;
+
+/// SYS_GRF 门控寄存器的索引起点，sys_grf_soc_con(x) 在表中记为 `SYS_GRF_GATE_BASE + x`
+///
+/// 参考 Linux: GATE_GRF (grf_type_sys)，写掩码和 bit 含义与 CRU 门控相同
+pub const SYS_GRF_GATE_BASE: u32 = 0x400;
+
 #[derive(Debug, Clone, Copy)]
 pub enum ClkType {
     Gate,
@@ -26,7 +43,10 @@ pub struct ClkGate {
     /// 时钟 ID
     pub clk_id: ClkId,
     pub kind: ClkType,
-    /// 寄存器索引 (0-31 用于 clkgate_con, 32+ 用于 pmu_clkgate_con)
+    /// 寄存器索引 (小于 [`PMU_GATE_BASE`] 用于 clkgate_con，
+    /// [`PMU_GATE_BASE`] 起用于 pmu_clkgate_con，[`PHP_GATE_BASE`] 起用于 php_clkgate_con，
+    /// [`SECURE_GATE_BASE`] 起用于 secure_clkgate_con，
+    /// [`SYS_GRF_GATE_BASE`] 起用于 SYS_GRF 的 sys_grf_soc_con)
     pub reg_idx: u32,
     /// 位偏移 (0-15)
     pub bit: u32,
@@ -82,6 +102,38 @@ macro_rules! clk_composite_table {
 // =============================================================================
 
 clk_gate_table!(
+    // ========================================================================
+    // 公共时钟源门控
+    // ========================================================================
+    CLK_50M_SRC => (0, 0),
+    CLK_100M_SRC => (0, 1),
+    CLK_150M_SRC => (0, 2),
+    CLK_200M_SRC => (0, 3),
+    CLK_250M_SRC => (0, 4),
+    CLK_300M_SRC => (0, 5),
+    CLK_350M_SRC => (0, 6),
+    CLK_400M_SRC => (0, 7),
+    CLK_450M_SRC => (0, 8),
+    CLK_500M_SRC => (0, 9),
+    CLK_600M_SRC => (0, 10),
+    CLK_650M_SRC => (0, 11),
+    CLK_700M_SRC => (0, 12),
+    CLK_800M_SRC => (0, 13),
+    CLK_1000M_SRC => (0, 14),
+    CLK_1200M_SRC => (0, 15),
+    // ========================================================================
+    // 根时钟门控
+    // ========================================================================
+    ACLK_TOP_ROOT => (1, 0),
+    PCLK_TOP_ROOT => (1, 1),
+    ACLK_LOW_TOP_ROOT => (1, 2),
+    ACLK_BUS_ROOT => (10, 0),
+    // PMU 根时钟
+    CLK_PMU1_300M_SRC => (PMU_GATE_BASE, 3),
+    CLK_PMU1_400M_SRC => (PMU_GATE_BASE, 4),
+    HCLK_PMU1_ROOT => (PMU_GATE_BASE, 5),
+    PCLK_PMU1_ROOT => (PMU_GATE_BASE, 7),
+    PCLK_PMU0_ROOT => (PMU_GATE_BASE + 5, 0),
     // ========================================================================
     // I2C 时钟门控
     // ========================================================================
@@ -102,8 +154,8 @@ clk_gate_table!(
     PCLK_I2C8 => (10, 15),
     CLK_I2C8 => (11, 7),
     // I2C0 (PMU)
-    PCLK_I2C0 => (0x32 + 2, 1),
-    CLK_I2C0 => (0x32 + 2, 2),
+    PCLK_I2C0 => (PMU_GATE_BASE + 2, 1),
+    CLK_I2C0 => (PMU_GATE_BASE + 2, 2),
     // ========================================================================
     // SPI 时钟门控
     // ========================================================================
@@ -121,26 +173,46 @@ clk_gate_table!(
     // UART 时钟门控
     // ========================================================================
     PCLK_UART1 => (12, 2),
+    CLK_UART1_SRC => (12, 11),
+    CLK_UART1_FRAC => (12, 12),
     SCLK_UART1 => (12, 13),
     PCLK_UART2 => (12, 3),
+    CLK_UART2_SRC => (12, 14),
+    CLK_UART2_FRAC => (12, 15),
     SCLK_UART2 => (13, 0),
     PCLK_UART3 => (12, 4),
+    CLK_UART3_SRC => (13, 1),
+    CLK_UART3_FRAC => (13, 2),
     SCLK_UART3 => (13, 3),
     PCLK_UART4 => (12, 5),
+    CLK_UART4_SRC => (13, 4),
+    CLK_UART4_FRAC => (13, 5),
     SCLK_UART4 => (13, 6),
     PCLK_UART5 => (12, 6),
+    CLK_UART5_SRC => (13, 7),
+    CLK_UART5_FRAC => (13, 8),
     SCLK_UART5 => (13, 9),
     PCLK_UART6 => (12, 7),
+    CLK_UART6_SRC => (13, 10),
+    CLK_UART6_FRAC => (13, 11),
     SCLK_UART6 => (13, 12),
     PCLK_UART7 => (12, 8),
+    CLK_UART7_SRC => (13, 13),
+    CLK_UART7_FRAC => (13, 14),
     SCLK_UART7 => (13, 15),
     PCLK_UART8 => (12, 9),
+    CLK_UART8_SRC => (14, 0),
+    CLK_UART8_FRAC => (14, 1),
     SCLK_UART8 => (14, 2),
     PCLK_UART9 => (12, 10),
+    CLK_UART9_SRC => (14, 3),
+    CLK_UART9_FRAC => (14, 4),
     SCLK_UART9 => (14, 5),
     // UART0 (PMU)
-    PCLK_UART0 => (0x32 + 2, 6),
-    SCLK_UART0 => (0x32 + 2, 5),
+    PCLK_UART0 => (PMU_GATE_BASE + 2, 6),
+    CLK_UART0_SRC => (PMU_GATE_BASE + 2, 3),
+    CLK_UART0_FRAC => (PMU_GATE_BASE + 2, 4),
+    SCLK_UART0 => (PMU_GATE_BASE + 2, 5),
     // ========================================================================
     // PWM 时钟门控
     // ========================================================================
@@ -154,9 +226,9 @@ clk_gate_table!(
     CLK_PWM3 => (15, 4),
     CLK_PWM3_CAPTURE => (15, 9),
     // PMU PWM
-    PCLK_PMU1PWM => (0x32 + 2, 8),
-    CLK_PMU1PWM => (0x32 + 2, 11),
-    CLK_PMU1PWM_CAPTURE => (0x32 + 2, 12),
+    PCLK_PMU1PWM => (PMU_GATE_BASE + 2, 8),
+    CLK_PMU1PWM => (PMU_GATE_BASE + 2, 11),
+    CLK_PMU1PWM_CAPTURE => (PMU_GATE_BASE + 2, 12),
     // ========================================================================
     // ADC 时钟门控
     // ========================================================================
@@ -165,6 +237,25 @@ clk_gate_table!(
     PCLK_TSADC => (16, 6),
     CLK_TSADC => (16, 7),
     // ========================================================================
+    // MMC 时钟门控 (eMMC/SDIO/SDMMC/SFC)
+    // ========================================================================
+    HCLK_NVM_ROOT => (31, 0),
+    ACLK_NVM_ROOT => (31, 1),
+    HCLK_EMMC => (31, 4),
+    ACLK_EMMC => (31, 5),
+    CCLK_EMMC => (31, 6),
+    BCLK_EMMC => (31, 7),
+    TMCLK_EMMC => (31, 8),
+    SCLK_SFC => (31, 9),
+    HCLK_SFC => (31, 10),
+    HCLK_SFC_XIP => (31, 11),
+    HCLK_SDIO_ROOT => (75, 0),
+    HCLK_SDIO => (75, 2),
+    CCLK_SRC_SDIO => (75, 3),
+    // SDMMC (SECURE CRU)
+    HCLK_SDMMC => (SECURE_GATE_BASE + 1, 9),
+    CCLK_SRC_SDMMC => (SECURE_GATE_BASE + 1, 10),
+    // ========================================================================
     // USB 时钟门控
     // ========================================================================
     // USB3 OTG2
@@ -191,6 +282,267 @@ clk_gate_table!(
 
     PCLK_USBDPPHY0 => (72, 2),
     PCLK_USBDPPHY1 => (72, 4),
+    // ========================================================================
+    // 视频编解码时钟门控
+    // ========================================================================
+    HCLK_RKVDEC0_ROOT => (40, 0),
+    ACLK_RKVDEC0_ROOT => (40, 1),
+    ACLK_RKVDEC_CCU => (40, 2),
+    HCLK_RKVDEC0 => (40, 3),
+    ACLK_RKVDEC0 => (40, 4),
+    CLK_RKVDEC0_CA => (40, 7),
+    CLK_RKVDEC0_HEVC_CA => (40, 8),
+    CLK_RKVDEC0_CORE => (40, 9),
+    HCLK_RKVDEC1_ROOT => (41, 0),
+    ACLK_RKVDEC1_ROOT => (41, 1),
+    HCLK_RKVDEC1 => (41, 2),
+    ACLK_RKVDEC1 => (41, 3),
+    CLK_RKVDEC1_CA => (41, 6),
+    CLK_RKVDEC1_HEVC_CA => (41, 7),
+    CLK_RKVDEC1_CORE => (41, 8),
+    ACLK_VDPU_ROOT => (44, 0),
+    ACLK_VDPU_LOW_ROOT => (44, 1),
+    HCLK_VDPU_ROOT => (44, 2),
+    ACLK_JPEG_DECODER_ROOT => (44, 3),
+    ACLK_VPU => (44, 8),
+    HCLK_VPU => (44, 9),
+    ACLK_JPEG_ENCODER0 => (44, 10),
+    HCLK_JPEG_ENCODER0 => (44, 11),
+    ACLK_JPEG_ENCODER1 => (44, 12),
+    HCLK_JPEG_ENCODER1 => (44, 13),
+    ACLK_JPEG_ENCODER2 => (44, 14),
+    HCLK_JPEG_ENCODER2 => (44, 15),
+    ACLK_JPEG_ENCODER3 => (45, 0),
+    HCLK_JPEG_ENCODER3 => (45, 1),
+    ACLK_JPEG_DECODER => (45, 2),
+    HCLK_JPEG_DECODER => (45, 3),
+    HCLK_RKVENC0_ROOT => (47, 0),
+    ACLK_RKVENC0_ROOT => (47, 1),
+    HCLK_RKVENC0 => (47, 4),
+    ACLK_RKVENC0 => (47, 5),
+    CLK_RKVENC0_CORE => (47, 6),
+    HCLK_RKVENC1_ROOT => (48, 0),
+    ACLK_RKVENC1_ROOT => (48, 1),
+    HCLK_RKVENC1 => (48, 4),
+    ACLK_RKVENC1 => (48, 5),
+    CLK_RKVENC1_CORE => (48, 6),
+    ACLK_AV1_ROOT => (68, 0),
+    ACLK_AV1 => (68, 2),
+    PCLK_AV1_ROOT => (68, 3),
+    PCLK_AV1 => (68, 5),
+    // ========================================================================
+    // 摄像头 (VI/ISP) 时钟门控
+    // ========================================================================
+    CLK_CIFOUT_OUT => (5, 8),
+    CLK_MIPI_CAMARAOUT_M0 => (5, 9),
+    CLK_MIPI_CAMARAOUT_M1 => (5, 10),
+    CLK_MIPI_CAMARAOUT_M2 => (5, 11),
+    CLK_MIPI_CAMARAOUT_M3 => (5, 12),
+    CLK_MIPI_CAMARAOUT_M4 => (5, 13),
+    ACLK_ISP1_ROOT => (26, 0),
+    HCLK_ISP1_ROOT => (26, 1),
+    CLK_ISP1_CORE => (26, 2),
+    CLK_ISP1_CORE_MARVIN => (26, 3),
+    CLK_ISP1_CORE_VICAP => (26, 4),
+    ACLK_ISP1 => (26, 6),
+    HCLK_ISP1 => (26, 7),
+    ACLK_VI_ROOT => (49, 0),
+    HCLK_VI_ROOT => (49, 1),
+    PCLK_VI_ROOT => (49, 2),
+    DCLK_VICAP => (49, 6),
+    ACLK_VICAP => (49, 7),
+    HCLK_VICAP => (49, 8),
+    CLK_ISP0_CORE => (49, 9),
+    CLK_ISP0_CORE_MARVIN => (49, 10),
+    CLK_ISP0_CORE_VICAP => (49, 11),
+    ACLK_ISP0 => (49, 12),
+    HCLK_ISP0 => (49, 13),
+    ACLK_FISHEYE0 => (49, 14),
+    HCLK_FISHEYE0 => (49, 15),
+    CLK_FISHEYE0_CORE => (50, 0),
+    ACLK_FISHEYE1 => (50, 1),
+    HCLK_FISHEYE1 => (50, 2),
+    CLK_FISHEYE1_CORE => (50, 3),
+    PCLK_CSI_HOST_0 => (50, 4),
+    PCLK_CSI_HOST_1 => (50, 5),
+    PCLK_CSI_HOST_2 => (50, 6),
+    PCLK_CSI_HOST_3 => (50, 7),
+    PCLK_CSI_HOST_4 => (50, 8),
+    PCLK_CSI_HOST_5 => (50, 9),
+    // ========================================================================
+    // GPU 时钟门控
+    // ========================================================================
+    CLK_GPU_SRC => (66, 1),
+    CLK_GPU => (66, 4),
+    CLK_GPU_COREGROUP => (66, 6),
+    CLK_GPU_STACKS => (66, 7),
+    ACLK_S_GPU_BIU => (66, 8),
+    ACLK_M0_GPU_BIU => (66, 9),
+    ACLK_M1_GPU_BIU => (66, 10),
+    ACLK_M2_GPU_BIU => (66, 11),
+    ACLK_M3_GPU_BIU => (66, 12),
+    PCLK_GPU_ROOT => (66, 14),
+    CLK_GPU_PVTM => (67, 0),
+    CLK_CORE_GPU_PVTM => (67, 1),
+    PCLK_GPU_GRF => (67, 2),
+    // ========================================================================
+    // NPU 时钟门控
+    // ========================================================================
+    ACLK_NPU1 => (27, 0),
+    HCLK_NPU1 => (27, 2),
+    ACLK_NPU2 => (28, 0),
+    HCLK_NPU2 => (28, 2),
+    HCLK_NPU_ROOT => (29, 0),
+    CLK_NPU_DSU0 => (29, 1),
+    PCLK_NPU_ROOT => (29, 4),
+    PCLK_NPU_PVTM => (29, 12),
+    PCLK_NPU_GRF => (29, 13),
+    CLK_NPU_PVTM => (29, 14),
+    CLK_CORE_NPU_PVTM => (29, 15),
+    ACLK_NPU0 => (30, 6),
+    HCLK_NPU0 => (30, 8),
+    // ========================================================================
+    // PHP 时钟门控 (PCIe/SATA)
+    // ========================================================================
+    PCLK_PHP_ROOT => (32, 0),
+    ACLK_PCIE_ROOT => (32, 6),
+    ACLK_PHP_ROOT => (32, 7),
+    ACLK_PCIE_BRIDGE => (32, 8),
+    ACLK_PCIE_4L_DBI => (32, 13),
+    ACLK_PCIE_2L_DBI => (32, 14),
+    ACLK_PCIE_1L0_DBI => (32, 15),
+    ACLK_PCIE_1L1_DBI => (33, 0),
+    ACLK_PCIE_1L2_DBI => (33, 1),
+    ACLK_PCIE_4L_MSTR => (33, 2),
+    ACLK_PCIE_2L_MSTR => (33, 3),
+    ACLK_PCIE_1L0_MSTR => (33, 4),
+    ACLK_PCIE_1L1_MSTR => (33, 5),
+    ACLK_PCIE_1L2_MSTR => (33, 6),
+    ACLK_PCIE_4L_SLV => (33, 7),
+    ACLK_PCIE_2L_SLV => (33, 8),
+    ACLK_PCIE_1L0_SLV => (33, 9),
+    ACLK_PCIE_1L1_SLV => (33, 10),
+    ACLK_PCIE_1L2_SLV => (33, 11),
+    PCLK_PCIE_4L => (33, 12),
+    PCLK_PCIE_2L => (33, 13),
+    PCLK_PCIE_1L0 => (33, 14),
+    PCLK_PCIE_1L1 => (33, 15),
+    PCLK_PCIE_1L2 => (34, 0),
+    CLK_PCIE_AUX0 => (34, 1),
+    CLK_PCIE_AUX1 => (34, 2),
+    CLK_PCIE_AUX2 => (34, 3),
+    CLK_PCIE_AUX3 => (34, 4),
+    CLK_PCIE_AUX4 => (34, 5),
+    ACLK_PHP_GIC_ITS => (34, 6),
+    ACLK_MMU_PCIE => (34, 7),
+    ACLK_MMU_PHP => (34, 8),
+    ACLK_SATA0 => (37, 7),
+    ACLK_SATA1 => (37, 8),
+    ACLK_SATA2 => (37, 9),
+    CLK_RXOOB0 => (37, 10),
+    CLK_RXOOB1 => (37, 11),
+    CLK_RXOOB2 => (37, 12),
+    CLK_PCIE1L2_PIPE => (38, 13),
+    CLK_PCIE1L0_PIPE => (38, 14),
+    CLK_PCIE1L1_PIPE => (38, 15),
+    CLK_PCIE4L_PIPE => (39, 0),
+    CLK_PCIE2L_PIPE => (39, 1),
+    CLK_PHY0_REF_ALT_P => (PHP_GATE_BASE, 5),
+    CLK_PHY0_REF_ALT_M => (PHP_GATE_BASE, 6),
+    CLK_PHY1_REF_ALT_P => (PHP_GATE_BASE, 7),
+    CLK_PHY1_REF_ALT_M => (PHP_GATE_BASE, 8),
+    // ========================================================================
+    // GMAC 时钟门控
+    // ========================================================================
+    PCLK_GMAC0 => (32, 3),
+    PCLK_GMAC1 => (32, 4),
+    ACLK_GMAC0 => (32, 10),
+    ACLK_GMAC1 => (32, 11),
+    CLK_GMAC0_PTP_REF => (34, 10),
+    CLK_GMAC1_PTP_REF => (34, 11),
+    CLK_GMAC_125M => (35, 5),
+    CLK_GMAC_50M => (35, 6),
+    REFCLKO25M_ETH0_OUT => (5, 3),
+    REFCLKO25M_ETH1_OUT => (5, 4),
+    // ========================================================================
+    // 音频时钟门控 (I2S/SPDIF/PDM)
+    // ========================================================================
+    CLK_I2S0_8CH_TX_SRC => (7, 11),
+    CLK_I2S0_8CH_TX_FRAC => (7, 12),
+    MCLK_I2S0_8CH_TX => (7, 13),
+    CLK_I2S0_8CH_RX_SRC => (7, 14),
+    CLK_I2S0_8CH_RX_FRAC => (7, 15),
+    MCLK_I2S0_8CH_RX => (8, 0),
+    I2S0_8CH_MCLKOUT => (8, 1),
+    CLK_I2S2_2CH_SRC => (8, 5),
+    CLK_I2S2_2CH_FRAC => (8, 6),
+    MCLK_I2S2_2CH => (8, 7),
+    I2S2_2CH_MCLKOUT => (8, 8),
+    CLK_I2S3_2CH_SRC => (8, 10),
+    CLK_I2S3_2CH_FRAC => (8, 11),
+    MCLK_I2S3_2CH => (8, 12),
+    I2S3_2CH_MCLKOUT => (8, 13),
+    CLK_SPDIF0_SRC => (9, 1),
+    CLK_SPDIF0_FRAC => (9, 2),
+    MCLK_SPDIF0 => (9, 3),
+    CLK_SPDIF1_SRC => (9, 5),
+    CLK_SPDIF1_FRAC => (9, 6),
+    MCLK_SPDIF1 => (9, 7),
+    MCLK_PDM1 => (9, 9),
+    CLK_I2S4_8CH_TX_SRC => (56, 11),
+    CLK_I2S4_8CH_TX_FRAC => (56, 12),
+    MCLK_I2S4_8CH_TX => (56, 13),
+    CLK_I2S8_8CH_TX_SRC => (57, 1),
+    CLK_I2S8_8CH_TX_FRAC => (57, 2),
+    MCLK_I2S8_8CH_TX => (57, 3),
+    CLK_I2S7_8CH_RX_SRC => (60, 1),
+    CLK_I2S7_8CH_RX_FRAC => (60, 2),
+    MCLK_I2S7_8CH_RX => (60, 3),
+    CLK_I2S5_8CH_TX_SRC => (62, 6),
+    CLK_I2S5_8CH_TX_FRAC => (62, 7),
+    MCLK_I2S5_8CH_TX => (62, 8),
+    CLK_I2S6_8CH_TX_SRC => (62, 13),
+    CLK_I2S6_8CH_TX_FRAC => (62, 14),
+    MCLK_I2S6_8CH_TX => (62, 15),
+    CLK_I2S6_8CH_RX_SRC => (63, 0),
+    CLK_I2S6_8CH_RX_FRAC => (63, 1),
+    MCLK_I2S6_8CH_RX => (63, 2),
+    MCLK_SPDIFRX0 => (63, 4),
+    MCLK_SPDIFRX1 => (63, 6),
+    MCLK_SPDIFRX2 => (63, 8),
+    CLK_I2S9_8CH_RX_SRC => (65, 1),
+    CLK_I2S9_8CH_RX_FRAC => (65, 2),
+    MCLK_I2S9_8CH_RX => (65, 3),
+    // I2S1 和 PDM0 (PMU)
+    CLK_I2S1_8CH_TX_SRC => (PMU_GATE_BASE + 1, 11),
+    CLK_I2S1_8CH_TX_FRAC => (PMU_GATE_BASE + 1, 12),
+    MCLK_I2S1_8CH_TX => (PMU_GATE_BASE + 1, 13),
+    CLK_I2S1_8CH_RX_SRC => (PMU_GATE_BASE + 1, 14),
+    CLK_I2S1_8CH_RX_FRAC => (PMU_GATE_BASE + 1, 15),
+    MCLK_I2S1_8CH_RX => (PMU_GATE_BASE + 2, 0),
+    I2S1_8CH_MCLKOUT => (PMU_GATE_BASE + 2, 1),
+    MCLK_PDM0 => (PMU_GATE_BASE + 2, 15),
+    // MCLK 输出到 IO (SYS_GRF_SOC_CON6)
+    I2S0_8CH_MCLKOUT_TO_IO => (SYS_GRF_GATE_BASE + 6, 0),
+    I2S1_8CH_MCLKOUT_TO_IO => (SYS_GRF_GATE_BASE + 6, 1),
+    I2S2_2CH_MCLKOUT_TO_IO => (SYS_GRF_GATE_BASE + 6, 2),
+    I2S3_2CH_MCLKOUT_TO_IO => (SYS_GRF_GATE_BASE + 6, 7),
+    // ========================================================================
+    // VOP 时钟门控
+    // ========================================================================
+    ACLK_VOP_ROOT => (52, 0),
+    ACLK_VOP_LOW_ROOT => (52, 1),
+    HCLK_VOP_ROOT => (52, 2),
+    PCLK_VOP_ROOT => (52, 3),
+    HCLK_VOP => (52, 8),
+    ACLK_VOP => (52, 9),
+    DCLK_VOP0_SRC => (52, 10),
+    DCLK_VOP1_SRC => (52, 11),
+    DCLK_VOP2_SRC => (52, 12),
+    DCLK_VOP0 => (52, 13),
+    DCLK_VOP1 => (53, 0),
+    DCLK_VOP2 => (53, 1),
+    DCLK_VOP3 => (53, 2),
 
 
 );
@@ -221,16 +573,47 @@ impl Cru {
     }
 
     /// 获取时钟门控寄存器地址
+    ///
+    /// SYS_GRF 中的门控返回相对 SYS_GRF 基地址的偏移，其余返回相对 CRU 基地址的偏移
     pub fn get_gate_reg_offset(&self, gate: ClkGate) -> u32 {
-        if gate.reg_idx >= 0x32 {
+        if gate.reg_idx >= SYS_GRF_GATE_BASE {
+            // SYS_GRF: sys_grf_soc_con
+            sys_grf_soc_con(gate.reg_idx - SYS_GRF_GATE_BASE)
+        } else if gate.reg_idx >= SECURE_GATE_BASE {
+            // SECURE CRU: secure_clkgate_con
+            secure_clkgate_con(gate.reg_idx - SECURE_GATE_BASE)
+        } else if gate.reg_idx >= PHP_GATE_BASE {
+            // PHP CRU: php_clkgate_con
+            php_clkgate_con(gate.reg_idx - PHP_GATE_BASE)
+        } else if gate.reg_idx >= PMU_GATE_BASE {
             // PMU CRU: pmu_clkgate_con
-            let idx = gate.reg_idx - 0x32;
+            let idx = gate.reg_idx - PMU_GATE_BASE;
             pmu_clkgate_con(idx)
         } else {
             // 主 CRU: clkgate_con
             clkgate_con(gate.reg_idx)
         }
     }
+
+    /// 读取门控寄存器
+    pub(crate) fn gate_read(&self, gate: ClkGate) -> u32 {
+        let offset = self.get_gate_reg_offset(gate) as usize;
+        if gate.reg_idx >= SYS_GRF_GATE_BASE {
+            self.grf.read(offset)
+        } else {
+            self.regs.read(offset)
+        }
+    }
+
+    /// 写入门控寄存器
+    pub(crate) fn gate_write(&self, gate: ClkGate, value: u32) {
+        let offset = self.get_gate_reg_offset(gate) as usize;
+        if gate.reg_idx >= SYS_GRF_GATE_BASE {
+            self.grf.write(offset, value)
+        } else {
+            self.regs.write(offset, value)
+        }
+    }
 }
 
 // =============================================================================
@@ -240,6 +623,7 @@ impl Cru {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{delay::FakeDelay, regmap::RegFile};
 
     #[test]
     fn test_clk_gate_table_size() {
@@ -250,14 +634,26 @@ mod tests {
         );
 
         // 验证具体的 gate 数量
+        // 公共时钟源: 16
+        // 根时钟: 4
+        // PMU 根时钟: 5
         // I2C: 18 (I2C1-8: 16, I2C0: 2)
         // SPI: 10
-        // UART: 20 (UART1-9: 18, UART0: 2)
+        // UART: 40 (UART1-9: 36, UART0: 4)
         // PWM: 12 (PWM1-3: 9, PMU1PWM: 3)
         // ADC: 4
-        // USB: 15
-        // 总计: 79
-        assert_eq!(CLK_GATE_TABLE.len(), 79);
+        // MMC: 15
+        // USB: 19
+        // 视频编解码: 45
+        // 摄像头: 36
+        // GPU: 13
+        // NPU: 13
+        // PHP: 47
+        // GMAC: 10
+        // 音频: 58 (CRU: 46, PMU: 8, SYS_GRF: 4)
+        // VOP: 13
+        // 总计: 378
+        assert_eq!(CLK_GATE_TABLE.len(), 378);
     }
 
     #[test]
@@ -278,6 +674,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clk_gate_has_tree_node() {
+        // 每个门控时钟都应在时钟树中有对应节点
+        for gate in CLK_GATE_TABLE.iter().chain(CLK_COMPOSITE_TABLE) {
+            assert!(
+                tree::clk_nodes().any(|node| node.id == gate.clk_id),
+                "gate {} has no clock tree node",
+                gate.clk_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_gate_reg_offset() {
+        let cru = Cru::from_access(
+            0,
+            Arc::new(RegFile::new(0x1000)),
+            Arc::new(RegFile::new(0x1000)),
+            Arc::new(FakeDelay::new()),
+        );
+        let gate = cru.find_clk_gate(CLK_PHY1_REF_ALT_M).unwrap();
+        assert_eq!(cru.get_gate_reg_offset(gate), php_clkgate_con(0));
+        let gate = cru.find_clk_gate(CLK_PCIE4L_PIPE).unwrap();
+        assert_eq!(cru.get_gate_reg_offset(gate), clkgate_con(39));
+        let gate = cru.find_clk_gate(CCLK_SRC_SDMMC).unwrap();
+        assert_eq!(cru.get_gate_reg_offset(gate), secure_clkgate_con(1));
+        let gate = cru.find_clk_gate(I2S3_2CH_MCLKOUT_TO_IO).unwrap();
+        assert_eq!(cru.get_gate_reg_offset(gate), sys_grf_soc_con(6));
+    }
+
     #[test]
     fn test_i2c_gates() {
         // 验证 I2C gate 配置
@@ -300,7 +726,7 @@ mod tests {
             .iter()
             .find(|gate| gate.clk_id == PCLK_I2C0)
             .expect("PCLK_I2C0 not found");
-        assert_eq!(pclk_i2c0.reg_idx, 0x32 + 2);
+        assert_eq!(pclk_i2c0.reg_idx, PMU_GATE_BASE + 2);
         assert_eq!(pclk_i2c0.bit, 1);
     }
 
@@ -344,7 +770,7 @@ mod tests {
             .iter()
             .find(|gate| gate.clk_id == PCLK_UART0)
             .expect("PCLK_UART0 not found");
-        assert_eq!(pclk_uart0.reg_idx, 0x32 + 2);
+        assert_eq!(pclk_uart0.reg_idx, PMU_GATE_BASE + 2);
         assert_eq!(pclk_uart0.bit, 6);
     }
 
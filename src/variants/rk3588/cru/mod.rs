@@ -1,14 +1,36 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 use core::fmt::Debug;
 
 use crate::{
-    Mmio, ResetRockchip, RstId, clock::ClkId, grf::GrfMmio, variants::rk3588::cru::gate::ClkType,
+    Mmio, ResetRockchip, RstId,
+    clock::{
+        ClkId,
+        notify::{ClkNotifier, NotifierId},
+        pll::PllSsc,
+    },
+    delay::{Delay, default_delay},
+    grf::GrfMmio,
+    regmap::{MmioAccess, RegAccess},
+    variants::rk3588::cru::gate::ClkType,
 };
 
 pub mod clock;
 mod consts;
+mod cpu;
 mod gate;
+mod notify;
 mod peripheral;
+mod phase;
 mod pll;
+pub mod reset;
+mod sim;
+mod ssc;
+mod tree;
+mod verify;
 
 // =============================================================================
 // 公开导出
@@ -17,6 +39,8 @@ mod pll;
 pub use clock::*;
 pub use consts::*;
 pub use pll::*;
+pub use sim::SimCru;
+pub use verify::{InitProfile, VerifyItem, VerifyReport};
 
 use crate::clock::*;
 
@@ -39,14 +63,35 @@ const ACLK_TOP_S200_SEL_SHIFT: u32 = 6;
 const ACLK_TOP_S200_SEL_MASK: u32 = 0x3 << ACLK_TOP_S200_SEL_SHIFT;
 const ACLK_TOP_S200_SEL_200M: u32 = 0;
 
-#[derive(Clone)]
+/// RK3588 CRU
+///
+/// 使能计数、关键时钟和通知等软件状态保存在实例中，因此不实现 `Clone`：
+/// 共享同一组寄存器的驱动必须使用同一个实例，否则会互相关闭对方的时钟
 pub struct Cru {
+    /// CRU 基地址，仅用于日志
     base: usize,
-    _grf: usize,
+    /// CRU 寄存器访问
+    regs: Arc<dyn RegAccess>,
+    /// SYS_GRF 寄存器访问 (I2S MCLK 输出到 IO 的门控)
+    grf: Arc<dyn RegAccess>,
     cpll_hz: u64,
     gpll_hz: u64,
     ppll_hz: u64,
     reset: ResetRockchip,
+    /// PLL 锁定超时计时
+    delay: Arc<dyn Delay>,
+    /// 时钟使能计数
+    enable_count: BTreeMap<ClkId, u32>,
+    /// 运行时标记的关键时钟
+    critical: BTreeSet<ClkId>,
+    /// 通过 `clk_set_parent` 固定了父时钟的时钟，设置频率时不再切换父时钟
+    pinned: BTreeSet<ClkId>,
+    /// 频率变化通知 (通知 ID, 时钟 ID, 回调)
+    notifiers: Vec<(NotifierId, ClkId, ClkNotifier)>,
+    /// 下一个通知 ID
+    next_notifier_id: u32,
+    /// 最近一次初始化的验证报告
+    init_report: Option<VerifyReport>,
 }
 
 impl Debug for Cru {
@@ -69,6 +114,10 @@ impl CruOp for Cru {
         self.reset.reset_deassert(id);
     }
 
+    fn reset_pulse(&mut self, id: RstId, width_us: u64) {
+        self.reset.reset_pulse(id, width_us);
+    }
+
     fn clk_enable(&mut self, id: ClkId) -> ClockResult<()> {
         self.clk_enable(id)
     }
@@ -81,6 +130,18 @@ impl CruOp for Cru {
         self.clk_is_enabled(id)
     }
 
+    fn clk_enable_count(&self, id: ClkId) -> ClockResult<u32> {
+        self.clk_enable_count(id)
+    }
+
+    fn clk_is_critical(&self, id: ClkId) -> ClockResult<bool> {
+        self.clk_is_critical(id)
+    }
+
+    fn clk_mark_critical(&mut self, id: ClkId) -> ClockResult<()> {
+        self.clk_mark_critical(id)
+    }
+
     fn clk_get_rate(&self, id: crate::clock::ClkId) -> ClockResult<u64> {
         self.clk_get_rate(id)
     }
@@ -88,163 +149,293 @@ impl CruOp for Cru {
     fn clk_set_rate(&mut self, id: crate::clock::ClkId, rate_hz: u64) -> ClockResult<u64> {
         self.clk_set_rate(id, rate_hz)
     }
+
+    fn clk_get_parent(&self, id: ClkId) -> ClockResult<ClkId> {
+        self.clk_get_parent(id)
+    }
+
+    fn clk_set_parent(&mut self, id: ClkId, parent: ClkId) -> ClockResult<()> {
+        self.clk_set_parent(id, parent)
+    }
+
+    fn mmc_get_phase(&self, id: ClkId) -> ClockResult<u32> {
+        self.mmc_get_phase(id)
+    }
+
+    fn mmc_set_phase(&mut self, id: ClkId, degrees: u32) -> ClockResult<u32> {
+        self.mmc_set_phase(id, degrees)
+    }
+
+    fn mmc_get_delay_num(&self, id: ClkId) -> ClockResult<u8> {
+        self.mmc_get_delay_num(id)
+    }
+
+    fn mmc_set_delay_num(&mut self, id: ClkId, delay_num: u8) -> ClockResult<()> {
+        self.mmc_set_delay_num(id, delay_num)
+    }
+
+    fn pll_get_ssc(&self, id: ClkId) -> ClockResult<Option<PllSsc>> {
+        let pll_id = PllId::try_from(id).map_err(|_| ClockError::unsupported(id))?;
+        self.pll_get_ssc(pll_id)
+    }
+
+    fn pll_set_ssc(&mut self, id: ClkId, ssc: Option<PllSsc>) -> ClockResult<Option<PllSsc>> {
+        let pll_id = PllId::try_from(id).map_err(|_| ClockError::unsupported(id))?;
+        self.pll_set_ssc(pll_id, ssc)
+    }
+
+    fn clk_notifier_register(
+        &mut self,
+        id: ClkId,
+        notifier: ClkNotifier,
+    ) -> ClockResult<NotifierId> {
+        self.clk_notifier_register(id, notifier)
+    }
+
+    fn clk_notifier_unregister(&mut self, notifier_id: NotifierId) -> bool {
+        self.clk_notifier_unregister(notifier_id)
+    }
 }
 
 impl Cru {
     pub fn new(base: Mmio, sys_grf: Mmio) -> Self {
-        let mut c = Cru {
-            base: base.as_ptr() as usize,
-            _grf: sys_grf.as_ptr() as usize,
+        Self::new_with_policy(base, sys_grf, InitPolicy::default())
+    }
+
+    /// 按指定的初始化策略创建 CRU
+    ///
+    /// # 参数
+    ///
+    /// * `base` - CRU 寄存器基地址
+    /// * `sys_grf` - SYS_GRF 寄存器基地址
+    /// * `policy` - 初始化策略，见 [`InitPolicy`]
+    pub fn new_with_policy(base: Mmio, sys_grf: Mmio, policy: InitPolicy) -> Self {
+        Self::new_with_access_and_policy(
+            base.as_ptr() as usize,
+            Arc::new(unsafe { MmioAccess::new(base) }),
+            Arc::new(unsafe { MmioAccess::new(sys_grf) }),
+            policy,
+        )
+    }
+
+    /// 使用自定义寄存器访问后端创建 CRU
+    ///
+    /// # 参数
+    ///
+    /// * `base` - CRU 基地址，仅用于日志输出
+    /// * `regs` - CRU 寄存器访问
+    /// * `sys_grf` - SYS_GRF 寄存器访问
+    pub fn new_with_access(
+        base: usize,
+        regs: Arc<dyn RegAccess>,
+        sys_grf: Arc<dyn RegAccess>,
+    ) -> Self {
+        Self::new_with_access_and_policy(base, regs, sys_grf, InitPolicy::default())
+    }
+
+    /// 使用自定义寄存器访问后端，按指定的初始化策略创建 CRU
+    ///
+    /// # 参数
+    ///
+    /// * `base` - CRU 基地址，仅用于日志输出
+    /// * `regs` - CRU 寄存器访问
+    /// * `sys_grf` - SYS_GRF 寄存器访问
+    /// * `policy` - 初始化策略，见 [`InitPolicy`]
+    pub fn new_with_access_and_policy(
+        base: usize,
+        regs: Arc<dyn RegAccess>,
+        sys_grf: Arc<dyn RegAccess>,
+        policy: InitPolicy,
+    ) -> Self {
+        Self::new_with_delay(base, regs, sys_grf, policy, default_delay())
+    }
+
+    /// 使用自定义寄存器访问后端和延时后端，按指定的初始化策略创建 CRU
+    ///
+    /// # 参数
+    ///
+    /// * `base` - CRU 基地址，仅用于日志输出
+    /// * `regs` - CRU 寄存器访问
+    /// * `sys_grf` - SYS_GRF 寄存器访问
+    /// * `policy` - 初始化策略，见 [`InitPolicy`]
+    /// * `delay` - PLL 锁定超时和复位脉冲的延时后端
+    pub fn new_with_delay(
+        base: usize,
+        regs: Arc<dyn RegAccess>,
+        sys_grf: Arc<dyn RegAccess>,
+        policy: InitPolicy,
+        delay: Arc<dyn Delay>,
+    ) -> Self {
+        let mut c = Self::from_access(base, regs, sys_grf, delay);
+        c.init_with_policy(policy);
+        c
+    }
+
+    /// 创建 CRU 但不执行初始化
+    pub(crate) fn from_access(
+        base: usize,
+        regs: Arc<dyn RegAccess>,
+        sys_grf: Arc<dyn RegAccess>,
+        delay: Arc<dyn Delay>,
+    ) -> Self {
+        Cru {
+            base,
+            reset: ResetRockchip::new(
+                regs.clone(),
+                SOFTRST_CON_OFFSET as usize,
+                49158,
+                delay.clone(),
+            ),
+            delay,
+            regs,
+            grf: sys_grf,
             cpll_hz: 0,
             gpll_hz: 0,
             ppll_hz: 0,
-            reset: ResetRockchip::new(base.as_ptr() as usize + SOFTRST_CON_OFFSET as usize, 49158),
+            enable_count: BTreeMap::new(),
+            critical: BTreeSet::new(),
+            pinned: BTreeSet::new(),
+            notifiers: Vec::new(),
+            next_notifier_id: 0,
+            init_report: None,
+        }
+    }
+
+    /// 按初始化策略初始化 CRU
+    ///
+    /// # 参数
+    ///
+    /// * `policy` - 初始化策略
+    ///   - [`InitPolicy::Verify`]: 见 [`Cru::init`]
+    ///   - [`InitPolicy::Enforce`]: 先按 u-boot 的默认值配置，再执行 [`Cru::init`] 验证
+    ///   - [`InitPolicy::NoTouch`]: 只读取 PLL 频率
+    ///
+    /// # 返回
+    ///
+    /// 返回验证报告，[`InitPolicy::NoTouch`] 不进行验证，返回 `None`。
+    /// 报告同时保存下来，之后可以通过 [`Cru::init_report`] 获取
+    pub fn init_with_policy(&mut self, policy: InitPolicy) -> Option<VerifyReport> {
+        let report = match policy {
+            InitPolicy::Verify => Some(self.init()),
+            InitPolicy::Enforce => {
+                self.init_enforce();
+                Some(self.init())
+            }
+            InitPolicy::NoTouch => {
+                self.read_pll_rates();
+                info!(
+                    "CRU@{:x}: Clock configuration left untouched (CPLL {}MHz, GPLL {}MHz, PPLL {}MHz)",
+                    self.base,
+                    self.cpll_hz / MHZ,
+                    self.gpll_hz / MHZ,
+                    self.ppll_hz / MHZ
+                );
+                None
+            }
         };
-        c.init();
-        c
+        self.init_report = report.clone();
+        report
+    }
+
+    /// 读取 CPLL/GPLL/PPLL 的当前频率
+    fn read_pll_rates(&mut self) {
+        self.cpll_hz = self.pll_get_rate(PllId::CPLL).unwrap();
+        self.gpll_hz = self.pll_get_rate(PllId::GPLL).unwrap();
+        self.ppll_hz = self.pll_get_rate(PllId::PPLL).unwrap();
     }
 
-    /// 初始化并验证 CRU 配置
+    /// 按 u-boot 的默认值配置 PLL 和总线根时钟
     ///
     /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_clk_init()
     ///
-    /// ⚠️ 此方法仅**验证**配置，不修改寄存器
-    /// 假设 bootloader (u-boot/TPL) 已正确配置 PLL 和时钟分频
-    ///
-    /// u-boot rk3588_clk_init 配置：
-    /// 1. ACLK_BUS_ROOT: GPLL/4 ≈ 300MHz (clksel_con[38])
-    /// 2. CPLL: 1500MHz
-    /// 3. GPLL: 1188MHz
-    /// 4. PPLL: 1100MHz (如果启用 PCI)
-    /// 5. ACLK_TOP_S400: 400MHz (clksel_con[9])
-    /// 6. ACLK_TOP_S200: 200MHz (clksel_con[9])
-    pub fn init(&mut self) {
+    /// 配置顺序与 u-boot 一致：先设置 ACLK_BUS_ROOT 的分频，避免 GPLL 升频后总线频率过高，
+    /// 再设置频率不一致的 PLL，最后设置 ACLK_TOP_S400/S200
+    fn init_enforce(&mut self) {
         info!(
-            "CRU@{:x}: Initializing and verifying clock configuration...",
+            "CRU@{:x}: Programming u-boot default clock configuration...",
             self.base
         );
 
-        // ========================================================================
-        // 1. 验证 ACLK_BUS_ROOT 配置
-        // u-boot: div = DIV_ROUND_UP(GPLL_HZ, 300 * MHz); = 1188/300 = 4
-        //        rk_clrsetreg(&priv->cru->clksel_con[38],
-        //                     ACLK_BUS_ROOT_SEL_MASK | ACLK_BUS_ROOT_DIV_MASK,
-        //                     div << ACLK_BUS_ROOT_DIV_SHIFT);
-        // 预期: SEL=0 (GPLL), DIV=4
-        // ========================================================================
-        let clksel_38 = self.read(clksel_con(38) as _);
-        let bus_root_sel = (clksel_38 & ACLK_BUS_ROOT_SEL_MASK) >> ACLK_BUS_ROOT_SEL_SHIFT;
-        let bus_root_div = (clksel_38 & ACLK_BUS_ROOT_DIV_MASK) >> ACLK_BUS_ROOT_DIV_SHIFT;
-
-        debug!(
-            "CRU@{:x}: clksel_con[38] (ACLK_BUS_ROOT): 0x{:08x}",
-            self.base, clksel_38
-        );
-        debug!("  - SEL: {} (0=GPLL, 1=CPLL, 2=NPLL, 3=24M)", bus_root_sel);
-        // u-boot: DIV_TO_RATE(input_rate, div) = ((input_rate) / ((div) + 1))
-        // 所以实际分频系数是 (div + 1)
-        let bus_root_div_factor = bus_root_div + 1;
-        let bus_root_rate = if bus_root_div > 0 {
-            GPLL_HZ / bus_root_div_factor as u64
-        } else {
-            0
-        };
-        debug!(
-            "  - DIV: {} (factor: {}, output: {}MHz)",
-            bus_root_div,
-            bus_root_div_factor,
-            bus_root_rate / MHZ
+        let div = (GPLL_HZ.div_ceil(300 * MHZ) - 1) as u32;
+        self.clrsetreg(
+            clksel_con(38),
+            ACLK_BUS_ROOT_SEL_MASK | ACLK_BUS_ROOT_DIV_MASK,
+            (ACLK_BUS_ROOT_SEL_GPLL << ACLK_BUS_ROOT_SEL_SHIFT) | (div << ACLK_BUS_ROOT_DIV_SHIFT),
         );
 
-        // u-boot 配置验证
-        // u-boot: div = DIV_ROUND_UP(GPLL_HZ, 300 * MHz) - 1;
-        //       = (1188 + 300 - 1) / 300 - 1 = 4 - 1 = 3
-        let expected_div = GPLL_HZ.div_ceil(300 * MHZ) - 1;
-        if bus_root_sel != ACLK_BUS_ROOT_SEL_GPLL {
-            warn!(
-                "⚠ CRU@{:x}: ACLK_BUS_ROOT source mismatch! u-boot: GPLL(0), current: {}",
-                self.base, bus_root_sel
-            );
-        } else {
-            debug!("✓ ACLK_BUS_ROOT source matches u-boot (GPLL)");
+        for (pll_id, rate_hz) in [
+            (PllId::CPLL, CPLL_HZ),
+            (PllId::GPLL, GPLL_HZ),
+            (PllId::PPLL, PPLL_HZ),
+        ] {
+            if self.pll_get_rate(pll_id).ok() == Some(rate_hz) {
+                continue;
+            }
+            if let Err(e) = self.pll_set_rate(pll_id, rate_hz) {
+                warn!(
+                    "⚠ CRU@{:x}: failed to set {}: {}",
+                    self.base,
+                    pll_id.name(),
+                    e
+                );
+            }
         }
 
-        if bus_root_div != expected_div as u32 {
-            warn!(
-                "⚠ CRU@{:x}: ACLK_BUS_ROOT div mismatch! u-boot: {}, current: {}",
-                self.base, expected_div, bus_root_div
-            );
-        } else {
-            debug!("✓ ACLK_BUS_ROOT div matches u-boot ({})", expected_div);
-        }
+        self.clrsetreg(
+            clksel_con(9),
+            ACLK_TOP_S400_SEL_MASK | ACLK_TOP_S200_SEL_MASK,
+            (ACLK_TOP_S400_SEL_400M << ACLK_TOP_S400_SEL_SHIFT)
+                | (ACLK_TOP_S200_SEL_200M << ACLK_TOP_S200_SEL_SHIFT),
+        );
+    }
 
-        // ========================================================================
-        // 2. 验证 ACLK_TOP_S400/S200 配置
-        // u-boot: rk_clrsetreg(&priv->cru->clksel_con[9],
-        //                      ACLK_TOP_S400_SEL_MASK | ACLK_TOP_S200_SEL_MASK,
-        //                      (ACLK_TOP_S400_SEL_400M << ACLK_TOP_S400_SEL_SHIFT) |
-        //                      (ACLK_TOP_S200_SEL_200M << ACLK_TOP_S200_SEL_SHIFT));
-        // 预期: S400_SEL=0 (400MHz), S200_SEL=0 (200MHz)
-        // ========================================================================
-        let clksel_9 = self.read(clksel_con(9) as _);
-        let s400_sel = (clksel_9 & ACLK_TOP_S400_SEL_MASK) >> ACLK_TOP_S400_SEL_SHIFT;
-        let s200_sel = (clksel_9 & ACLK_TOP_S200_SEL_MASK) >> ACLK_TOP_S200_SEL_SHIFT;
+    /// 验证 CRU 配置
+    ///
+    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_clk_init()
+    ///
+    /// ⚠️ 此方法仅**验证**配置，不修改寄存器
+    /// 假设 bootloader (u-boot/TPL) 已正确配置 PLL 和时钟分频，
+    /// 需要由驱动配置时使用 [`InitPolicy::Enforce`]
+    ///
+    /// 检查项和预期值见 [`InitProfile::UBOOT`]，检查其他配置使用 [`Cru::verify`]
+    ///
+    /// # 返回
+    ///
+    /// 返回验证报告，未通过的检查项同时以 warn 级别输出
+    pub fn init(&mut self) -> VerifyReport {
+        info!(
+            "CRU@{:x}: Initializing and verifying clock configuration...",
+            self.base
+        );
 
         debug!(
-            "CRU@{:x}: clksel_con[9] (ACLK_TOP): 0x{:08x}",
-            self.base, clksel_9
+            "CRU@{:x}: clksel_con[38] (ACLK_BUS_ROOT): 0x{:08x}, clksel_con[9] (ACLK_TOP): 0x{:08x}",
+            self.base,
+            self.read(clksel_con(38)),
+            self.read(clksel_con(9))
         );
-        debug!("  - S400_SEL: {} (0=400MHz, 1=200MHz)", s400_sel);
-        debug!("  - S200_SEL: {} (0=200MHz, 1=100MHz)", s200_sel);
 
-        if s400_sel != ACLK_TOP_S400_SEL_400M {
-            warn!(
-                "⚠ CRU@{:x}: ACLK_TOP_S400 mismatch! u-boot: 0 (400MHz), current: {}",
-                self.base, s400_sel
-            );
-        } else {
-            debug!("✓ ACLK_TOP_S400 matches u-boot (400MHz)");
+        // 保存实际读取到的频率
+        self.read_pll_rates();
+
+        let report = self.verify(&InitProfile::UBOOT);
+        for item in &report.items {
+            if item.passed {
+                debug!("{}", item);
+            } else {
+                warn!("CRU@{:x}: {}", self.base, item);
+            }
         }
 
-        if s200_sel != ACLK_TOP_S200_SEL_200M {
+        if report.passed() {
+            info!("✓ CRU@{:x}: Clock configuration verified", self.base);
+        } else {
             warn!(
-                "⚠ CRU@{:x}: ACLK_TOP_S200 mismatch! u-boot: 0 (200MHz), current: {}",
-                self.base, s200_sel
+                "⚠ CRU@{:x}: Clock configuration differs from u-boot",
+                self.base
             );
-        } else {
-            debug!("✓ ACLK_TOP_S200 matches u-boot (200MHz)");
-        }
-
-        // ========================================================================
-        // 3. 读取并验证 PLL 频率
-        // u-boot 通过 rockchip_pll_set_rate() 配置:
-        // - CPLL: CPLL_HZ (1500MHz)
-        // - GPLL: GPLL_HZ (1188MHz)
-        // ========================================================================
-        let cpll_actual = self.pll_get_rate(PllId::CPLL).unwrap();
-        let gpll_actual = self.pll_get_rate(PllId::GPLL).unwrap();
-
-        // 保存实际读取到的频率
-        self.cpll_hz = cpll_actual;
-        self.gpll_hz = gpll_actual;
-        self.ppll_hz = self.pll_get_rate(PllId::PPLL).unwrap();
-
-        debug!("PLL actual rates (read from registers):");
-        debug!("  - CPLL: {}MHz", cpll_actual / MHZ);
-        debug!("  - GPLL: {}MHz", gpll_actual / MHZ);
-        debug!("  - PPLL: {}MHz", self.ppll_hz / MHZ);
-
-        // 验证与 u-boot 预期值的一致性
-        verify_pll_frequency(PllId::CPLL, cpll_actual, CPLL_HZ);
-        verify_pll_frequency(PllId::GPLL, gpll_actual, GPLL_HZ);
-
-        if self.ppll_hz != PPLL_HZ {
-            let rate = self.pll_set_rate(PllId::PPLL, PPLL_HZ).unwrap();
-            verify_pll_frequency(PllId::PPLL, rate, PPLL_HZ);
-            self.ppll_hz = rate;
         }
-
-        info!("✓ CRU@{:x}: Clock configuration verified", self.base);
+        report
     }
 
     pub fn reset_assert(&mut self, id: RstId) {
@@ -255,9 +446,17 @@ impl Cru {
         self.reset.reset_deassert(id);
     }
 
+    /// 产生复位脉冲，复位保持至少 `width_us` 微秒
+    pub fn reset_pulse(&mut self, id: RstId, width_us: u64) {
+        self.reset.reset_pulse(id, width_us);
+    }
+
     /// 使能时钟
     ///
-    /// 清除时钟门控 bit，使时钟输出到外设
+    /// 参考 Linux: drivers/clk/clk.c:clk_core_enable()
+    ///
+    /// 使能计数加 1。首次使能时先使能当前父时钟和依赖的总线时钟 (如
+    /// `CLK_I2C1` 依赖 `PCLK_I2C1`)，再清除自身的门控 bit
     ///
     /// # 参数
     ///
@@ -273,29 +472,44 @@ impl Cru {
     /// cru.clk_enable(CLK_I2C1)?;
     /// ```
     pub fn clk_enable(&mut self, id: ClkId) -> ClockResult<()> {
-        let gate = self.find_clk_gate(id).ok_or(ClockError::unsupported(id))?;
-        if matches!(gate.kind, ClkType::Composite) {
-            return Ok(());
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+
+        let count = self.enable_count.get(&id).copied().unwrap_or(0);
+        // 计数为 0 但仍有记录的关键时钟已持有父时钟和依赖的引用
+        if !self.enable_count.contains_key(&id) {
+            // 父时钟未建模的时钟 (孤儿时钟) 只处理自身门控
+            let parent = self.clk_current_parent(node).ok();
+            if let Some(parent) = parent {
+                self.clk_enable(parent)?;
+            }
+
+            for (i, link) in tree::clk_links(id).enumerate() {
+                if let Err(e) = self.clk_enable(link) {
+                    // 回滚已使能的依赖
+                    for done in tree::clk_links(id).take(i) {
+                        let _ = self.clk_disable(done);
+                    }
+                    if let Some(parent) = parent {
+                        let _ = self.clk_disable(parent);
+                    }
+                    return Err(e);
+                }
+            }
+
+            self.clk_gate_enable(id);
         }
 
-        let offset = self.get_gate_reg_offset(gate);
-
-        // Rockchip 写掩码机制：清除 bit
-        // 高 16 位 = (1 << bit)，低 16 位 = 0
-        let mask = 1u32 << gate.bit;
-        self.write(offset, mask << 16);
-
-        debug!(
-            "CRU@{:x}: clk_enable({}) = reg[0x{:03x}]:bit{}",
-            self.base, id, offset, gate.bit
-        );
-
+        self.enable_count.insert(id, count + 1);
         Ok(())
     }
 
     /// 禁止时钟
     ///
-    /// 设置时钟门控 bit，停止时钟输出
+    /// 参考 Linux: drivers/clk/clk.c:clk_core_disable()
+    ///
+    /// 使能计数减 1。计数归零时设置自身的门控 bit，并释放父时钟和依赖的总线时钟。
+    /// 关键时钟 (见 [`Cru::clk_is_critical`]) 计数归零时不会被关闭，
+    /// 也继续持有父时钟和依赖的总线时钟
     ///
     /// # 参数
     ///
@@ -303,7 +517,8 @@ impl Cru {
     ///
     /// # 返回
     ///
-    /// 成功返回 Ok(())，失败返回 Err
+    /// 成功返回 Ok(())，未使能的时钟返回 `ClockError::DisableFailed`，
+    /// 未使能的关键时钟返回 `ClockError::CriticalClock`
     ///
     /// # 示例
     ///
@@ -311,24 +526,143 @@ impl Cru {
     /// cru.clk_disable(CLK_I2C1)?;
     /// ```
     pub fn clk_disable(&mut self, id: ClkId) -> ClockResult<()> {
-        let gate = self.find_clk_gate(id).ok_or(ClockError::unsupported(id))?;
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        let critical = self.clk_is_critical(id)?;
+
+        let count = self.enable_count.get(&id).copied().unwrap_or(0);
+        if count == 0 {
+            // 参考 Linux: clk_core_disable() 的 "Disabling critical" 检查
+            if critical {
+                warn!("{}: refusing to disable critical clock", node.name);
+                return Err(ClockError::critical_clock(id));
+            }
+            return Err(ClockError::disable_failed(id, "unbalanced disable"));
+        }
+
+        if count > 1 {
+            self.enable_count.insert(id, count - 1);
+            return Ok(());
+        }
+
+        if critical {
+            // 保留计数为 0 的记录，再次使能时不重复获取父时钟和依赖
+            self.enable_count.insert(id, 0);
+            return Ok(());
+        }
+
+        self.enable_count.remove(&id);
+        self.clk_gate_disable(id);
+
+        // 释放所有依赖和父时钟后再返回第一个错误，避免计数不一致
+        let mut result = Ok(());
+        for link in tree::clk_links(id) {
+            result = result.and(self.clk_disable(link));
+        }
+        if let Ok(parent) = self.clk_current_parent(node) {
+            result = result.and(self.clk_disable(parent));
+        }
+
+        result
+    }
+
+    /// 检查时钟是否为关键时钟
+    ///
+    /// 关键时钟包括时钟树中带 `ClkFlags::IS_CRITICAL` 标志的节点、
+    /// SoC 的关键时钟列表和运行时通过 [`Cru::clk_mark_critical`] 标记的时钟
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回 true 表示关键时钟，时钟不在时钟树中返回 `ClockError::UnsupportedClock`
+    pub fn clk_is_critical(&self, id: ClkId) -> ClockResult<bool> {
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        Ok(node.is_critical() || tree::is_critical_clk(id) || self.critical.contains(&id))
+    }
+
+    /// 将时钟标记为关键时钟
+    ///
+    /// 标记后 `clk_disable` 不会再关闭该时钟 (如调试串口)，标记本身不修改门控寄存器
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # Errors
+    ///
+    /// 时钟不在时钟树中返回 `ClockError::UnsupportedClock`
+    pub fn clk_mark_critical(&mut self, id: ClkId) -> ClockResult<()> {
+        self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        if self.critical.insert(id) {
+            debug!("{}: marked critical", id);
+        }
+        Ok(())
+    }
+
+    /// 获取时钟的使能计数
+    ///
+    /// 与 [`Cru::clk_is_enabled`] 不同，这里返回的是软件记录的使用者数量，
+    /// 不读取门控寄存器
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回使能计数，0 表示没有使用者
+    pub fn clk_enable_count(&self, id: ClkId) -> ClockResult<u32> {
+        self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        Ok(self.enable_count.get(&id).copied().unwrap_or(0))
+    }
+
+    /// 清除时钟门控 bit (不处理计数和父时钟)
+    fn clk_gate_enable(&mut self, id: ClkId) {
+        let Some(gate) = self.find_clk_gate(id) else {
+            return;
+        };
+        if matches!(gate.kind, ClkType::Composite) {
+            return;
+        }
+
+        let offset = self.get_gate_reg_offset(gate);
+
+        // Rockchip 写掩码机制：清除 bit
+        // 高 16 位 = (1 << bit)，低 16 位 = 0
+        let mask = 1u32 << gate.bit;
+        self.gate_write(gate, mask << 16);
+
+        debug!(
+            "CRU@{:x}: clk_enable({}) = reg[0x{:03x}]:bit{}",
+            self.base, id, offset, gate.bit
+        );
+    }
+
+    /// 设置时钟门控 bit (不处理计数和父时钟)
+    fn clk_gate_disable(&mut self, id: ClkId) {
+        let Some(gate) = self.find_clk_gate(id) else {
+            return;
+        };
         let offset = self.get_gate_reg_offset(gate);
 
         // Rockchip 写掩码机制：设置 bit
         // 高 16 位 = (1 << bit)，低 16 位 = (1 << bit)
         let mask = 1u32 << gate.bit;
-        self.write(offset, (mask << 16) | mask);
+        self.gate_write(gate, (mask << 16) | mask);
 
         debug!(
             "CRU@{:x}: clk_disable({}) = reg[0x{:03x}]:bit{}",
             self.base, id, offset, gate.bit
         );
-
-        Ok(())
     }
 
     /// 检查时钟是否已使能
     ///
+    /// 直接读取门控寄存器，反映硬件状态 (可能由 bootloader 打开)，
+    /// 软件使能计数见 [`Cru::clk_enable_count`]
+    ///
     /// # 参数
     ///
     /// * `id` - 时钟 ID
@@ -342,11 +676,9 @@ impl Cru {
             return Ok(true);
         }
 
-        let offset = self.get_gate_reg_offset(gate);
-
         // 读取寄存器，检查 bit
         // bit = 0 表示使能，bit = 1 表示禁止
-        let value = self.read(offset);
+        let value = self.gate_read(gate);
         let enabled = (value & (1 << gate.bit)) == 0;
 
         Ok(enabled)
@@ -362,70 +694,13 @@ impl Cru {
     ///
     /// 返回时钟频率 (Hz)，如果不支持该时钟则返回错误
     pub fn clk_get_rate(&self, id: crate::clock::ClkId) -> ClockResult<u64> {
-        // 1. PLL 时钟
-        if is_pll_clk(id) {
-            let pll_id = PllId::try_from(id).map_err(|_| ClockError::unsupported(id))?;
-            return self.pll_get_rate(pll_id);
-        }
-
-        // 2. I2C 时钟
-        if is_i2c_clk(id) {
-            return self.i2c_get_rate(id);
-        }
-
-        // 3. UART 时钟
-        if is_uart_clk(id) {
-            return self.uart_get_rate(id);
-        }
-
-        // 4. SPI 时钟
-        if is_spi_clk(id) {
-            return self.spi_get_rate(id);
-        }
-
-        // 5. PWM 时钟
-        if matches!(id, CLK_PWM1 | CLK_PWM2 | CLK_PWM3 | CLK_PMU1PWM) {
-            return self.pwm_get_rate(id);
-        }
-
-        // 6. ADC 时钟
-        if matches!(id, CLK_SARADC | CLK_TSADC) {
-            return self.adc_get_rate(id);
-        }
-
-        // 7. MMC/EMMC/SDIO/SFC 时钟
-        if is_mmc_clk(id) {
-            return self.mmc_get_rate(id);
-        }
-
-        // 8. USB 时钟
-        if is_usb_clk(id) {
-            return self.usb_get_rate(id);
-        }
-
-        // 9. 根时钟
-        if matches!(
-            id,
-            ACLK_BUS_ROOT
-                | ACLK_TOP_ROOT
-                | PCLK_TOP_ROOT
-                | ACLK_LOW_TOP_ROOT
-                | ACLK_CENTER_ROOT
-                | PCLK_CENTER_ROOT
-                | HCLK_CENTER_ROOT
-                | ACLK_CENTER_LOW_ROOT
-        ) {
-            return self.root_clk_get_rate(id);
-        }
-
-        Err(ClockError::rate_read_failed(
-            id,
-            "Clock type not supported yet",
-        ))
+        self.clk_tree_get_rate(id)
     }
 
     /// 设置时钟频率
     ///
+    /// 变化前后向注册了通知的时钟发送频率变化事件，见 [`Cru::clk_notifier_register`]
+    ///
     /// # 参数
     ///
     /// * `id` - 时钟 ID
@@ -434,7 +709,19 @@ impl Cru {
     /// # 返回
     ///
     /// 返回实际设置的频率 (Hz)，如果不支持该时钟则返回错误
+    ///
+    /// # Errors
+    ///
+    /// 通知回调否决变化时返回 `ClockError::RateChangeVetoed`，不修改寄存器
     pub fn clk_set_rate(&mut self, id: crate::clock::ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let pending = self.clk_notify_pre(id, rate_hz)?;
+        let result = self.clk_core_set_rate(id, rate_hz);
+        self.clk_notify_post(pending);
+        result
+    }
+
+    /// 按时钟类型设置频率，不发送通知
+    fn clk_core_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
         // 1. PLL 时钟
         if is_pll_clk(id) {
             let pll_id = PllId::try_from(id).map_err(|_| ClockError::unsupported(id))?;
@@ -463,17 +750,70 @@ impl Cru {
 
         // 6. ADC 时钟
         if matches!(id, CLK_SARADC | CLK_TSADC) {
-            return self.adc_set_rate(id, rate_hz);
+            return self.clk_tree_set_rate(id, rate_hz);
         }
 
-        // 7. MMC/EMMC/SDIO/SFC 时钟
+        // 7. MMC/EMMC/SDIO/SDMMC/SFC 时钟
         if is_mmc_clk(id) {
             return self.mmc_set_rate(id, rate_hz);
         }
 
-        // 8. USB 时钟
+        // 8. USB 时钟，HCLK_USB_ROOT 只有 mux，不支持设置频率
         if is_usb_clk(id) {
-            return self.usb_set_rate(id, rate_hz);
+            return match id {
+                ACLK_USB_ROOT | CLK_UTMI_OTG2 => self.clk_tree_set_rate(id, rate_hz),
+                _ => Err(ClockError::unsupported(id)),
+            };
+        }
+
+        // 9. GMAC 时钟
+        if is_gmac_clk(id) {
+            return self.clk_tree_set_rate(id, rate_hz);
+        }
+
+        // 10. VOP 时钟
+        if is_vop_clk(id) {
+            return self.vop_set_rate(id, rate_hz);
+        }
+
+        // 11. CPU 集群时钟
+        if is_cpu_clk(id) {
+            return self.cpu_set_rate(id, rate_hz);
+        }
+
+        // 12. 视频编解码时钟
+        if is_video_clk(id) {
+            return self.video_set_rate(id, rate_hz);
+        }
+
+        // 13. GPU 时钟
+        if is_gpu_clk(id) {
+            return self.gpu_set_rate(id, rate_hz);
+        }
+
+        // 14. NPU 时钟
+        if is_npu_clk(id) {
+            return self.npu_set_rate(id, rate_hz);
+        }
+
+        // 15. 摄像头时钟
+        if is_camera_clk(id) {
+            return self.camera_set_rate(id, rate_hz);
+        }
+
+        // 16. PHP 时钟 (PCIe/SATA)
+        if is_php_clk(id) {
+            return self.php_set_rate(id, rate_hz);
+        }
+
+        // 17. 音频时钟 (I2S/SPDIF/PDM)
+        if is_audio_clk(id) {
+            return self.audio_set_rate(id, rate_hz);
+        }
+
+        // 18. 总线根时钟
+        if is_root_clk(id) {
+            return self.root_set_rate(id, rate_hz);
         }
 
         // 其他时钟类型暂不支持设置
@@ -542,51 +882,12 @@ impl Cru {
         &[super::syscon::grf_mmio::SYS_GRF]
     }
 
-    fn reg(&self, offset: u32) -> *mut u32 {
-        (self.base + offset as usize) as *mut u32
-    }
-
     fn read(&self, offset: u32) -> u32 {
-        unsafe { core::ptr::read_volatile(self.reg(offset)) }
+        self.regs.read(offset as usize)
     }
 
     fn write(&self, offset: u32, value: u32) {
-        unsafe { core::ptr::write_volatile(self.reg(offset), value) }
-    }
-}
-
-/// 验证 PLL 频率
-///
-/// 对比实际读取的 PLL 频率与 u-boot 配置的预期频率
-///
-/// # 参数
-///
-/// * `pll_id` - PLL ID
-/// * `actual_hz` - 实际读取的频率 (Hz)
-/// * `expected_hz` - 预期频率 (Hz)
-fn verify_pll_frequency(pll_id: PllId, actual_hz: u64, expected_hz: u64) {
-    let diff_hz = actual_hz.abs_diff(expected_hz);
-
-    // 允许 0.1% 的误差
-    let tolerance = expected_hz / 1000;
-
-    if diff_hz <= tolerance {
-        debug!(
-            "✓ {}: {}MHz (expected: {}MHz, diff: {}Hz)",
-            pll_id.name(),
-            actual_hz / MHZ,
-            expected_hz / MHZ,
-            diff_hz
-        );
-    } else {
-        log::warn!(
-            "⚠️ {}: {}MHz (expected: {}MHz, diff: {}Hz, tolerance: {}Hz)",
-            pll_id.name(),
-            actual_hz / MHZ,
-            expected_hz / MHZ,
-            diff_hz,
-            tolerance
-        );
+        self.regs.write(offset as usize, value)
     }
 }
 
@@ -596,7 +897,17 @@ fn verify_pll_frequency(pll_id: PllId, actual_hz: u64, expected_hz: u64) {
 
 #[cfg(test)]
 mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
     use super::*;
+    use crate::{
+        delay::FakeDelay,
+        regmap::{RegFile, RegOp, TraceAccess},
+        variants::rk3588::cru::{
+            reset::*,
+            sim::{SimCru, sim_cru},
+        },
+    };
 
     /// 测试 u-boot 配置值的常量验证
     #[test]
@@ -782,4 +1093,131 @@ mod tests {
         let rate = calc_pll_rate(fin, 3, 425, 2, 0);
         assert_eq!(rate, NPLL_HZ as u64, "NPLL calculation mismatch");
     }
+
+    #[test]
+    fn test_critical_clock() {
+        let (_, mut cru) = sim_cru();
+
+        // 时钟树标志和 SoC 列表中的关键时钟
+        assert!(cru.clk_is_critical(ACLK_BUS_ROOT).unwrap());
+        assert!(cru.clk_is_critical(CLK_PMU1_400M_SRC).unwrap());
+        assert!(matches!(
+            cru.clk_disable(ACLK_BUS_ROOT),
+            Err(ClockError::CriticalClock {
+                clk_id: ACLK_BUS_ROOT
+            })
+        ));
+
+        // 计数归零后门控保持打开
+        cru.clk_enable(CLK_PMU1_400M_SRC).unwrap();
+        cru.clk_disable(CLK_PMU1_400M_SRC).unwrap();
+        assert!(cru.clk_is_enabled(CLK_PMU1_400M_SRC).unwrap());
+        assert!(matches!(
+            cru.clk_disable(CLK_PMU1_400M_SRC),
+            Err(ClockError::CriticalClock { .. })
+        ));
+
+        // 运行时标记
+        assert!(!cru.clk_is_critical(SCLK_UART2).unwrap());
+        cru.clk_enable(SCLK_UART2).unwrap();
+        cru.clk_mark_critical(SCLK_UART2).unwrap();
+        assert!(cru.clk_is_critical(SCLK_UART2).unwrap());
+        cru.clk_disable(SCLK_UART2).unwrap();
+        assert!(cru.clk_is_enabled(SCLK_UART2).unwrap());
+        // 父时钟和总线时钟的引用保留，UART 不会因上游被关闭而停止
+        assert!(cru.clk_is_enabled(CLK_UART2_SRC).unwrap());
+        assert!(cru.clk_is_enabled(PCLK_UART2).unwrap());
+        assert_eq!(cru.clk_enable_count(CLK_UART2_SRC).unwrap(), 1);
+        assert_eq!(cru.clk_enable_count(PCLK_UART2).unwrap(), 1);
+
+        // 再次使能和禁止不会重复获取引用
+        cru.clk_enable(SCLK_UART2).unwrap();
+        cru.clk_disable(SCLK_UART2).unwrap();
+        assert_eq!(cru.clk_enable_count(SCLK_UART2).unwrap(), 0);
+        assert_eq!(cru.clk_enable_count(CLK_UART2_SRC).unwrap(), 1);
+        assert_eq!(cru.clk_enable_count(PCLK_UART2).unwrap(), 1);
+
+        // 普通时钟不受影响
+        cru.clk_enable(SCLK_UART3).unwrap();
+        cru.clk_disable(SCLK_UART3).unwrap();
+        assert!(!cru.clk_is_enabled(SCLK_UART3).unwrap());
+        assert!(matches!(
+            cru.clk_disable(SCLK_UART3),
+            Err(ClockError::DisableFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_init_enforce() {
+        // 复位状态下 PLL 未配置，Enforce 按 u-boot 的默认值配置
+        let sim = Arc::new(SimCru::new());
+        let cru = Cru::new_with_access_and_policy(
+            0,
+            sim.clone(),
+            Arc::new(RegFile::new(0x1000)),
+            InitPolicy::Enforce,
+        );
+
+        assert_eq!(cru.cpll_hz, CPLL_HZ);
+        assert_eq!(cru.gpll_hz, GPLL_HZ);
+        assert_eq!(cru.ppll_hz, PPLL_HZ);
+        assert_eq!(cru.pll_get_rate(PllId::PPLL).unwrap(), PPLL_HZ);
+        assert!(get_pll(PllId::PPLL).is_locked(sim.as_ref()));
+        assert_eq!(cru.clk_get_rate(ACLK_BUS_ROOT).unwrap(), GPLL_HZ / 4);
+        assert_eq!(
+            cru.clk_get_rate(ACLK_TOP_S400).unwrap(),
+            cru.clk_get_rate(CLK_400M_SRC).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_init_verify_and_no_touch_do_not_write() {
+        for policy in [InitPolicy::Verify, InitPolicy::NoTouch] {
+            let writes = Arc::new(AtomicUsize::new(0));
+            let counter = writes.clone();
+            let regs = TraceAccess::new(SimCru::new(), move |op| {
+                if matches!(op, RegOp::Write { .. }) {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+            let cru = Cru::new_with_access_and_policy(
+                0,
+                Arc::new(regs),
+                Arc::new(RegFile::new(0x1000)),
+                policy,
+            );
+
+            // PPLL 保持复位状态 (SLOW 模式输出 24MHz)
+            assert_eq!(writes.load(Ordering::Relaxed), 0, "{:?}", policy);
+            assert_eq!(cru.ppll_hz, OSC_HZ);
+        }
+    }
+
+    #[test]
+    fn test_reset_pulse() {
+        let delay = Arc::new(FakeDelay::new());
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let trace = {
+            let log = log.clone();
+            let delay = delay.clone();
+            TraceAccess::new(SimCru::with_uboot_defaults(), move |op| {
+                if let RegOp::Write { value, .. } = op {
+                    log.lock().unwrap().push((delay.elapsed_us(), value));
+                }
+            })
+        };
+        let mut cru = Cru::from_access(
+            0,
+            Arc::new(trace),
+            Arc::new(RegFile::new(0x1000)),
+            delay.clone(),
+        );
+
+        // 置位和释放之间至少间隔 10us
+        cru.reset_pulse(SRST_A_RKNN0, 10);
+        assert_eq!(
+            *log.lock().unwrap(),
+            [(0, (1 << 22) | (1 << 6)), (10, 1 << 22)]
+        );
+    }
 }
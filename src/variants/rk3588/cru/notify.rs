@@ -0,0 +1,370 @@
+//! RK3588 时钟频率变化通知
+//!
+//! 参考 Linux: drivers/clk/clk.c:clk_propagate_rate_change()
+//!
+//! `clk_set_rate` 修改寄存器前，向目标时钟及其子孙时钟中注册了通知的时钟发送
+//! `Pre` 事件，新频率按请求频率和当前分频估算。设置过程中需要修改 PLL 时 (如 VOP
+//! 重新配置 V0PLL)，修改前再向共用该 PLL 的时钟发送 `Pre` 事件。修改后重新读取所有
+//! 注册了通知的时钟，频率变化的发送 `Post` 事件，收到 `Pre` 但频率没有变化的发送
+//! `Abort` 事件
+
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use super::*;
+use crate::clock::notify::*;
+
+/// 查找祖先时钟时的最大层数
+const MAX_CLK_DEPTH: usize = 16;
+
+/// 注册了通知的时钟在频率变化前的状态
+pub(crate) struct PendingRateChange {
+    clk_id: ClkId,
+    old_rate: u64,
+    /// 预计的新频率
+    new_rate: u64,
+    /// 是否已发送 `Pre` 事件
+    notified: bool,
+}
+
+impl Cru {
+    /// 注册时钟频率变化通知
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    /// * `notifier` - 回调
+    ///
+    /// # 返回
+    ///
+    /// 返回用于注销的通知 ID
+    ///
+    /// # Errors
+    ///
+    /// 时钟不支持读取频率时返回对应的错误
+    pub fn clk_notifier_register(
+        &mut self,
+        id: ClkId,
+        notifier: ClkNotifier,
+    ) -> ClockResult<NotifierId> {
+        self.clk_get_rate(id)?;
+
+        let notifier_id = NotifierId::from(self.next_notifier_id);
+        self.next_notifier_id += 1;
+        self.notifiers.push((notifier_id, id, notifier));
+        debug!("{}: {} registered", id, notifier_id);
+        Ok(notifier_id)
+    }
+
+    /// 注销时钟频率变化通知
+    ///
+    /// # 返回
+    ///
+    /// 返回 true 表示已注销，false 表示通知 ID 不存在
+    pub fn clk_notifier_unregister(&mut self, notifier_id: NotifierId) -> bool {
+        let count = self.notifiers.len();
+        self.notifiers.retain(|(nid, _, _)| *nid != notifier_id);
+        self.notifiers.len() != count
+    }
+
+    /// `id` 是否为 `ancestor` 本身或其子孙时钟
+    fn clk_is_descendant(&self, mut id: ClkId, ancestor: ClkId) -> bool {
+        for _ in 0..MAX_CLK_DEPTH {
+            if id == ancestor {
+                return true;
+            }
+            match self.clk_get_parent(id) {
+                Ok(parent) => id = parent,
+                Err(_) => return false,
+            }
+        }
+        false
+    }
+
+    /// 调用时钟上注册的所有回调
+    ///
+    /// 参考 Linux: drivers/clk/clk.c:__clk_notify()
+    ///
+    /// # 返回
+    ///
+    /// 任一回调否决时返回 [`NotifyAction::Veto`]
+    fn clk_notify(&self, change: RateChange) -> NotifyAction {
+        let mut action = NotifyAction::Ok;
+        for (_, _, notifier) in self
+            .notifiers
+            .iter()
+            .filter(|(_, id, _)| *id == change.clk_id)
+        {
+            if notifier(&change) == NotifyAction::Veto {
+                action = NotifyAction::Veto;
+            }
+        }
+        action
+    }
+
+    /// 频率变化前发送 `Pre` 事件
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 要设置频率的时钟 ID
+    /// * `rate_hz` - 请求的频率 (Hz)
+    ///
+    /// # 返回
+    ///
+    /// 返回所有注册了通知的时钟在变化前的状态，供 [`Cru::clk_notify_post`] 使用
+    ///
+    /// # Errors
+    ///
+    /// 回调否决时，向已通知的时钟发送 `Abort` 事件并返回 `ClockError::RateChangeVetoed`
+    pub(crate) fn clk_notify_pre(
+        &self,
+        id: ClkId,
+        rate_hz: u64,
+    ) -> ClockResult<Vec<PendingRateChange>> {
+        self.clk_notify_pre_except(id, rate_hz, None)
+    }
+
+    /// 设置 `id` 的频率时需要顺带修改 PLL 频率，修改前发送 `Pre` 事件
+    ///
+    /// 像素时钟、CPU 集群等会重新配置 PLL，共用该 PLL 但不是 `id` 子孙的时钟没有收到
+    /// `clk_set_rate` 发送的 `Pre` 事件，在这里补发，回调同样可以否决。`id` 及其子孙时钟
+    /// 已经收到通知，不再重复发送。`Post` 事件仍由 `clk_set_rate` 统一发送
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 正在设置频率的时钟 ID
+    /// * `pll` - 要修改的 PLL 时钟 ID
+    /// * `rate_hz` - PLL 的新频率 (Hz)
+    ///
+    /// # 返回
+    ///
+    /// 返回补发了 `Pre` 事件的时钟，供 [`Cru::clk_notify_abort_nested`] 使用
+    ///
+    /// # Errors
+    ///
+    /// 回调否决时，向已通知的时钟发送 `Abort` 事件并返回 `ClockError::RateChangeVetoed`
+    pub(crate) fn clk_notify_pre_nested(
+        &self,
+        id: ClkId,
+        pll: ClkId,
+        rate_hz: u64,
+    ) -> ClockResult<Vec<PendingRateChange>> {
+        self.clk_notify_pre_except(pll, rate_hz, Some(id))
+    }
+
+    /// PLL 修改失败时，向 [`Cru::clk_notify_pre_nested`] 通知过且频率没有变化的时钟发送
+    /// `Abort` 事件
+    pub(crate) fn clk_notify_abort_nested(&self, pending: Vec<PendingRateChange>) {
+        for p in pending.into_iter().filter(|p| p.notified) {
+            if self.clk_get_rate(p.clk_id).unwrap_or(p.old_rate) == p.old_rate {
+                self.clk_notify(RateChange {
+                    clk_id: p.clk_id,
+                    event: RateChangeEvent::Abort,
+                    old_rate: p.old_rate,
+                    new_rate: p.new_rate,
+                });
+            }
+        }
+    }
+
+    /// 发送 `Pre` 事件，`notified` 及其子孙时钟除外
+    fn clk_notify_pre_except(
+        &self,
+        id: ClkId,
+        rate_hz: u64,
+        notified: Option<ClkId>,
+    ) -> ClockResult<Vec<PendingRateChange>> {
+        if self.notifiers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let old_target = self.clk_get_rate(id).unwrap_or(0);
+        let clocks: BTreeSet<ClkId> = self.notifiers.iter().map(|(_, id, _)| *id).collect();
+        let pending: Vec<_> = clocks
+            .into_iter()
+            .filter_map(|clk_id| {
+                let old_rate = self.clk_get_rate(clk_id).ok()?;
+                let new_rate = if !self.clk_is_descendant(clk_id, id) {
+                    old_rate
+                } else if clk_id == id {
+                    rate_hz
+                } else if old_target == 0 {
+                    old_rate
+                } else {
+                    // 子孙时钟的分频不变，频率按比例变化
+                    (old_rate as u128 * rate_hz as u128 / old_target as u128) as u64
+                };
+                let skip = notified.is_some_and(|n| self.clk_is_descendant(clk_id, n));
+                Some(PendingRateChange {
+                    clk_id,
+                    old_rate,
+                    new_rate,
+                    notified: new_rate != old_rate && !skip,
+                })
+            })
+            .collect();
+
+        let mut vetoed = None;
+        for p in pending.iter().filter(|p| p.notified) {
+            let action = self.clk_notify(RateChange {
+                clk_id: p.clk_id,
+                event: RateChangeEvent::Pre,
+                old_rate: p.old_rate,
+                new_rate: p.new_rate,
+            });
+            if action == NotifyAction::Veto && vetoed.is_none() {
+                vetoed = Some(p.clk_id);
+            }
+        }
+
+        if let Some(notified) = vetoed {
+            warn!("{}: rate change vetoed by notifier on {}", id, notified);
+            for p in pending.iter().filter(|p| p.notified) {
+                self.clk_notify(RateChange {
+                    clk_id: p.clk_id,
+                    event: RateChangeEvent::Abort,
+                    old_rate: p.old_rate,
+                    new_rate: p.new_rate,
+                });
+            }
+            return Err(ClockError::rate_change_vetoed(id, notified));
+        }
+        Ok(pending)
+    }
+
+    /// 频率变化后发送 `Post` 或 `Abort` 事件
+    ///
+    /// # 参数
+    ///
+    /// * `pending` - [`Cru::clk_notify_pre`] 返回的变化前状态
+    pub(crate) fn clk_notify_post(&self, pending: Vec<PendingRateChange>) {
+        for p in pending {
+            let rate = self.clk_get_rate(p.clk_id).unwrap_or(p.old_rate);
+            let (event, new_rate) = if rate != p.old_rate {
+                (RateChangeEvent::Post, rate)
+            } else if p.notified {
+                (RateChangeEvent::Abort, p.new_rate)
+            } else {
+                continue;
+            };
+            self.clk_notify(RateChange {
+                clk_id: p.clk_id,
+                event,
+                old_rate: p.old_rate,
+                new_rate,
+            });
+        }
+    }
+}
+
+// =============================================================================
+// 单元测试
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variants::rk3588::cru::sim::sim_cru;
+
+    #[test]
+    fn test_rate_change_notifier() {
+        let (_, mut cru) = sim_cru();
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let notifier = {
+            let events = events.clone();
+            Arc::new(move |change: &RateChange| {
+                events.lock().unwrap().push(*change);
+                NotifyAction::Ok
+            })
+        };
+        cru.clk_notifier_register(CLK_100M_SRC, notifier).unwrap();
+
+        // clk_100m_src = GPLL / 12，Pre 事件按比例估算新频率
+        cru.clk_set_rate(PLL_GPLL, 1200 * MHZ).unwrap();
+        let change = |event, old_rate, new_rate| RateChange {
+            clk_id: CLK_100M_SRC,
+            event,
+            old_rate,
+            new_rate,
+        };
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                change(RateChangeEvent::Pre, 99 * MHZ, 100 * MHZ),
+                change(RateChangeEvent::Post, 99 * MHZ, 100 * MHZ),
+            ]
+        );
+
+        // 否决时不修改频率，已通知的时钟收到 Abort
+        events.lock().unwrap().clear();
+        let veto = cru
+            .clk_notifier_register(PLL_GPLL, Arc::new(|_: &RateChange| NotifyAction::Veto))
+            .unwrap();
+        assert!(matches!(
+            cru.clk_set_rate(PLL_GPLL, GPLL_HZ),
+            Err(ClockError::RateChangeVetoed {
+                clk_id: PLL_GPLL,
+                notified: PLL_GPLL
+            })
+        ));
+        assert_eq!(cru.clk_get_rate(PLL_GPLL).unwrap(), 1200 * MHZ);
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                change(RateChangeEvent::Pre, 100 * MHZ, 99 * MHZ),
+                change(RateChangeEvent::Abort, 100 * MHZ, 99 * MHZ),
+            ]
+        );
+
+        // 不相关的时钟变化不会通知
+        events.lock().unwrap().clear();
+        assert!(cru.clk_notifier_unregister(veto));
+        assert!(!cru.clk_notifier_unregister(veto));
+        cru.clk_set_rate(PLL_NPLL, 850 * MHZ).unwrap();
+        assert!(events.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rate_change_notifier_shared_pll() {
+        let (_, mut cru) = sim_cru();
+        cru.clk_set_rate(DCLK_VOP3, 25_175_000).unwrap();
+        // 关闭 DCLK_VOP3，允许其他 VOP 重新配置 V0PLL
+        cru.clk_enable(DCLK_VOP3).unwrap();
+        cru.clk_disable(DCLK_VOP3).unwrap();
+
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let notifier = {
+            let events = events.clone();
+            Arc::new(move |change: &RateChange| {
+                events.lock().unwrap().push(change.event);
+                NotifyAction::Ok
+            })
+        };
+        cru.clk_notifier_register(DCLK_VOP3, notifier).unwrap();
+
+        // DCLK_VOP1_SRC 重新配置 V0PLL，DCLK_VOP3 不是它的子孙，但也先收到 Pre
+        cru.clk_set_rate(DCLK_VOP1_SRC, 65 * MHZ).unwrap();
+        assert_eq!(cru.clk_get_parent(DCLK_VOP1_SRC).unwrap(), PLL_V0PLL);
+        assert_eq!(
+            *events.lock().unwrap(),
+            [RateChangeEvent::Pre, RateChangeEvent::Post]
+        );
+
+        // DCLK_VOP3 的使用者可以否决 V0PLL 的修改
+        events.lock().unwrap().clear();
+        let v0pll = cru.clk_get_rate(PLL_V0PLL).unwrap();
+        cru.clk_notifier_register(DCLK_VOP3, Arc::new(|_: &RateChange| NotifyAction::Veto))
+            .unwrap();
+        assert!(matches!(
+            cru.clk_set_rate(DCLK_VOP1_SRC, 40 * MHZ),
+            Err(ClockError::RateChangeVetoed {
+                clk_id: PLL_V0PLL,
+                notified: DCLK_VOP3
+            })
+        ));
+        assert_eq!(cru.clk_get_rate(PLL_V0PLL).unwrap(), v0pll);
+        assert_eq!(
+            *events.lock().unwrap(),
+            [RateChangeEvent::Pre, RateChangeEvent::Abort]
+        );
+    }
+}
@@ -2,905 +2,1037 @@
 //!
 //! 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c
 
-use alloc::vec::Vec;
-
 use super::clock::*;
 use super::consts::*;
 use super::*;
+use crate::clock::tree::ClkKind;
 use crate::clock::{ClockError, ClockResult};
 
 impl Cru {
     // ========================================================================
-    // I2C 时钟
+    // 总线根时钟
     // ========================================================================
 
-    /// 获取 I2C 时钟频率
+    /// 设置总线根时钟频率
+    ///
+    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_top_set_clk()
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_i2c_get_clk()
+    /// - ACLK_BUS_ROOT / ACLK_TOP_ROOT / ACLK_LOW_TOP_ROOT: 在 PLL 中选择父时钟并分频
+    ///   (clksel_con[38], clksel_con[8])
+    /// - PCLK_TOP_ROOT / ACLK_TOP_S400 / ACLK_TOP_S200 / CENTER / PMU 根时钟:
+    ///   优先在固定频率源中选择不超过目标频率的最接近值 (clksel_con[8], clksel_con[9])
+    /// - PCLK_PMU0_ROOT: 只是 pclk_pmu1_root 的门控，设置 pclk_pmu1_root
     ///
-    /// I2C 时钟源选择：100MHz 或 200MHz
+    /// 挂在根时钟下的总线时钟频率随之变化，调用者需要保证对应的外设能够容忍
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn i2c_get_rate(&self, id: ClkId) -> ClockResult<u64> {
-        let (con, sel_shift) = match id {
-            CLK_I2C0 => (pmu_clksel_con(3), 6),
-            CLK_I2C1 => (clksel_con(38), 6),
-            CLK_I2C2 => (clksel_con(38), 7),
-            CLK_I2C3 => (clksel_con(38), 8),
-            CLK_I2C4 => (clksel_con(38), 9),
-            CLK_I2C5 => (clksel_con(38), 10),
-            CLK_I2C6 => (clksel_con(38), 11),
-            CLK_I2C7 => (clksel_con(38), 12),
-            CLK_I2C8 => (clksel_con(38), 13),
-            _ => return Err(ClockError::unsupported(id)),
-        };
-
-        let sel = (self.read(con) >> sel_shift) & 1;
-        Ok(if sel == 0 { 200 * MHZ } else { 100 * MHZ })
+    /// 目标频率为 0 或没有可用的父时钟时返回 `ClockError::InvalidRate`
+    pub(crate) fn root_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        match id {
+            PCLK_PMU0_ROOT => self.clk_tree_set_rate(PCLK_PMU1_ROOT, rate_hz),
+            _ => self.clk_tree_set_rate(id, rate_hz),
+        }
     }
 
+    // ========================================================================
+    // I2C 时钟
+    // ========================================================================
+
     /// 设置 I2C 时钟频率
     ///
     /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_i2c_set_clk()
     ///
+    /// 按时钟树选择不超过目标频率的最接近的父时钟，已使能时钟的引用计数随父时钟转移。
+    /// 通过 [`Cru::clk_set_parent`] 固定父时钟后不再切换
+    ///
     /// # 时钟源
     ///
-    /// - 100MHz: GPLL/12 或 CPLL/15
-    /// - 200MHz: GPLL/6 或 CPLL/7.5
+    /// - CLK_I2C0: clk_pmu1_200m_src / clk_pmu1_100m_src
+    /// - CLK_I2C1-8: clk_200m_src / clk_100m_src
     ///
     /// # Errors
     ///
     /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
     pub(crate) fn i2c_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
-        let src_200m = if rate_hz >= 198 * MHZ { 0 } else { 1 };
-
-        let (offset, mask, shift) = match id {
-            CLK_I2C0 => (pmu_clksel_con(3), 1 << 6, 6),
-            CLK_I2C1 => (clksel_con(38), 1 << 6, 6),
-            CLK_I2C2 => (clksel_con(38), 1 << 7, 7),
-            CLK_I2C3 => (clksel_con(38), 1 << 8, 8),
-            CLK_I2C4 => (clksel_con(38), 1 << 9, 9),
-            CLK_I2C5 => (clksel_con(38), 1 << 10, 10),
-            CLK_I2C6 => (clksel_con(38), 1 << 11, 11),
-            CLK_I2C7 => (clksel_con(38), 1 << 12, 12),
-            CLK_I2C8 => (clksel_con(38), 1 << 13, 13),
-            _ => return Err(ClockError::unsupported(id)),
-        };
-
-        self.clrsetreg(offset, mask, src_200m << shift);
-
-        Ok(if src_200m == 0 { 200 * MHZ } else { 100 * MHZ })
+        match id {
+            CLK_I2C0 | CLK_I2C1 | CLK_I2C2 | CLK_I2C3 | CLK_I2C4 | CLK_I2C5 | CLK_I2C6
+            | CLK_I2C7 | CLK_I2C8 => self.clk_tree_set_rate(id, rate_hz),
+            _ => Err(ClockError::unsupported(id)),
+        }
     }
 
     // ========================================================================
     // SPI 时钟
     // ========================================================================
 
-    /// 获取 SPI 时钟频率
+    /// 设置 SPI 时钟频率
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_spi_get_clk()
+    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_spi_set_clk()
+    ///
+    /// 按时钟树在 clk_200m_src / clk_150m_src / xin24m 中选择不超过目标频率的最接近值
     ///
     /// # Errors
     ///
     /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn spi_get_rate(&self, id: ClkId) -> ClockResult<u64> {
-        let con = self.read(clksel_con(59));
-        let sel_shift = match id {
-            CLK_SPI0 => 2,
-            CLK_SPI1 => 4,
-            CLK_SPI2 => 6,
-            CLK_SPI3 => 8,
-            CLK_SPI4 => 10,
-            _ => return Err(ClockError::unsupported(id)),
-        };
-
-        let sel = (con >> sel_shift) & 0x3;
-        Ok(match sel {
-            0 => 200 * MHZ, // CLK_SPI_SEL_200M
-            1 => 150 * MHZ, // CLK_SPI_SEL_150M
-            2 => OSC_HZ,    // CLK_SPI_SEL_24M
-            _ => 0,
-        })
+    pub(crate) fn spi_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        match id {
+            CLK_SPI0 | CLK_SPI1 | CLK_SPI2 | CLK_SPI3 | CLK_SPI4 => {
+                self.clk_tree_set_rate(id, rate_hz)
+            }
+            _ => Err(ClockError::unsupported(id)),
+        }
     }
 
-    /// 设置 SPI 时钟频率
+    // ========================================================================
+    // PWM 时钟
+    // ========================================================================
+
+    /// 设置 PWM 时钟频率
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_spi_set_clk()
+    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_pwm_set_clk()
+    ///
+    /// 按时钟树在 clk_100m_src / clk_50m_src / xin24m 中选择不超过目标频率的最接近值
     ///
     /// # Errors
     ///
     /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn spi_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
-        let src_clk = if rate_hz >= 198 * MHZ {
-            0 // CLK_SPI_SEL_200M
-        } else if rate_hz >= 140 * MHZ {
-            1 // CLK_SPI_SEL_150M
-        } else {
-            2 // CLK_SPI_SEL_24M
-        };
-
-        let (mask, shift) = match id {
-            CLK_SPI0 => (0x3 << 2, 2),
-            CLK_SPI1 => (0x3 << 4, 4),
-            CLK_SPI2 => (0x3 << 6, 6),
-            CLK_SPI3 => (0x3 << 8, 8),
-            CLK_SPI4 => (0x3 << 10, 10),
-            _ => return Err(ClockError::unsupported(id)),
-        };
-
-        self.clrsetreg(clksel_con(59), mask, src_clk << shift);
-
-        Ok(match src_clk {
-            0 => 200 * MHZ,
-            1 => 150 * MHZ,
-            2 => OSC_HZ,
-            _ => 0,
-        })
+    pub(crate) fn pwm_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        match id {
+            CLK_PWM1 | CLK_PWM2 | CLK_PWM3 | CLK_PMU1PWM => self.clk_tree_set_rate(id, rate_hz),
+            _ => Err(ClockError::unsupported(id)),
+        }
     }
 
     // ========================================================================
-    // PWM 时钟
+    // UART 时钟
     // ========================================================================
 
-    /// 获取 PWM 时钟频率
+    /// 设置 UART 时钟频率
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_pwm_get_clk()
+    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_uart_set_rate()
+    ///
+    /// SCLK_UARTn 只是 clk_uartn 的门控，设置 clk_uartn；
+    /// src/frac/xin24m 的选择见 [`Cru::clk_frac_mux_set_rate`]
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn pwm_get_rate(&self, id: ClkId) -> ClockResult<u64> {
-        let (con, sel_shift) = match id {
-            CLK_PWM1 => (clksel_con(59), 12),
-            CLK_PWM2 => (clksel_con(59), 14),
-            CLK_PWM3 => (clksel_con(60), 0),
-            CLK_PMU1PWM => (pmu_clksel_con(2), 9),
+    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`；
+    /// 无法得到目标频率时返回 `ClockError::InvalidRate`
+    pub(crate) fn uart_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let mux = match id {
+            SCLK_UART0 | CLK_UART0 => CLK_UART0,
+            SCLK_UART1 | CLK_UART1 => CLK_UART1,
+            SCLK_UART2 | CLK_UART2 => CLK_UART2,
+            SCLK_UART3 | CLK_UART3 => CLK_UART3,
+            SCLK_UART4 | CLK_UART4 => CLK_UART4,
+            SCLK_UART5 | CLK_UART5 => CLK_UART5,
+            SCLK_UART6 | CLK_UART6 => CLK_UART6,
+            SCLK_UART7 | CLK_UART7 => CLK_UART7,
+            SCLK_UART8 | CLK_UART8 => CLK_UART8,
+            SCLK_UART9 | CLK_UART9 => CLK_UART9,
             _ => return Err(ClockError::unsupported(id)),
         };
 
-        let sel = (self.read(con) >> sel_shift) & 0x3;
-        Ok(match sel {
-            0 => 100 * MHZ, // CLK_PWM_SEL_100M
-            1 => 50 * MHZ,  // CLK_PWM_SEL_50M
-            2 => OSC_HZ,    // CLK_PWM_SEL_24M
-            _ => 0,
-        })
+        self.clk_frac_mux_set_rate(mux, rate_hz)
     }
 
-    /// 设置 PWM 时钟频率
+    // ========================================================================
+    // MMC/SDMMC 时钟
+    // ========================================================================
+
+    /// 设置 MMC 时钟频率
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_pwm_set_clk()
+    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
+    ///
+    /// 按时钟树选择不超过目标频率的最接近的父时钟和分频，支持的时钟：
+    /// - CCLK_EMMC: EMMC card clock (CLKSEL_CON(77))
+    /// - BCLK_EMMC: EMMC bus clock (CLKSEL_CON(78))
+    /// - CCLK_SRC_SDIO: SDIO source clock (CLKSEL_CON(172))
+    /// - SCLK_SFC: SFC clock (CLKSEL_CON(78))
+    /// - CCLK_SRC_SDMMC / HCLK_SDMMC: SD 卡控制器时钟 (SECURE CRU)
+    /// - NVM/SDIO 总线时钟: 设置对应的根时钟 (hclk_nvm_root/aclk_nvm_root/hclk_sdio_root)
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    /// * `rate_hz` - 目标频率 (Hz)
+    ///
+    /// # 返回
+    ///
+    /// 返回实际设置的频率
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn pwm_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
-        let src_clk = if rate_hz >= 99 * MHZ {
-            0 // CLK_PWM_SEL_100M
-        } else if rate_hz >= 50 * MHZ {
-            1 // CLK_PWM_SEL_50M
-        } else {
-            2 // CLK_PWM_SEL_24M
-        };
-
-        let (offset, mask, shift) = match id {
-            CLK_PWM1 => (clksel_con(59), 0x3 << 12, 12),
-            CLK_PWM2 => (clksel_con(59), 0x3 << 14, 14),
-            CLK_PWM3 => (clksel_con(60), 0x3, 0),
-            CLK_PMU1PWM => (pmu_clksel_con(2), 0x3 << 9, 9),
+    /// 如果时钟 ID 不支持或无法设置目标频率，返回错误
+    pub(crate) fn mmc_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let id = match id {
+            HCLK_EMMC | HCLK_SFC | HCLK_SFC_XIP => HCLK_NVM_ROOT,
+            ACLK_EMMC => ACLK_NVM_ROOT,
+            HCLK_SDIO => HCLK_SDIO_ROOT,
+            HCLK_NVM_ROOT | ACLK_NVM_ROOT | HCLK_SDIO_ROOT | CCLK_SRC_SDMMC | HCLK_SDMMC
+            | CCLK_EMMC | BCLK_EMMC | CCLK_SRC_SDIO | SCLK_SFC => id,
             _ => return Err(ClockError::unsupported(id)),
         };
 
-        self.clrsetreg(offset, mask, src_clk << shift);
-
-        Ok(match src_clk {
-            0 => 100 * MHZ,
-            1 => 50 * MHZ,
-            2 => OSC_HZ,
-            _ => 0,
-        })
+        self.clk_tree_set_rate(id, rate_hz)
     }
 
     // ========================================================================
-    // ADC (SARADC/TSADC) 时钟
+    // PHP 时钟
     // ========================================================================
 
-    /// 获取 ADC 时钟频率
+    /// 设置 PHP 域 (PCIe/SATA) 时钟频率
+    ///
+    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
+    ///
+    /// - PCIe 控制器的 ACLK: 设置 aclk_pcie_root
+    /// - PCIe 控制器的 PCLK: 设置 pclk_php_root
+    /// - SATA ACLK / ACLK_MMU_PHP: 设置 aclk_php_root (同时影响 GMAC 和 USB3OTG2)
+    /// - CLK_RXOOBn / 根时钟: 按时钟树选择最接近的父时钟和分频
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_adc_get_clk()
+    /// AUX 时钟固定为 24MHz，PIPE 时钟和 PHY 参考时钟由 PHY 或 PPLL 决定，不支持设置
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn adc_get_rate(&self, id: ClkId) -> ClockResult<u64> {
-        Ok(match id {
-            CLK_SARADC => {
-                let con = self.read(clksel_con(40));
-                let div = ((con & 0xFF) >> 6) as u64;
-                let sel = (con >> 14) & 1;
-                let prate = if sel == 1 { OSC_HZ } else { self.gpll_hz };
-                prate / (div + 1)
-            }
-            CLK_TSADC => {
-                let con = self.read(clksel_con(41));
-                let div = (con & 0xFF) as u64;
-                let sel = (con >> 8) & 1;
-                let prate = if sel == 1 { OSC_HZ } else { 100 * MHZ };
-                prate / (div + 1)
-            }
-            _ => return Err(ClockError::unsupported(id)),
-        })
+    /// 时钟频率不可设置时返回 `ClockError::UnsupportedClock`，
+    /// 没有合适的父时钟时返回 `ClockError::InvalidRate`
+    pub(crate) fn php_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let root = match id {
+            ACLK_PCIE_BRIDGE | ACLK_MMU_PCIE | ACLK_PHP_GIC_ITS => ACLK_PCIE_ROOT,
+            id if (ACLK_PCIE_4L_DBI..=ACLK_PCIE_1L2_SLV).contains(&id) => ACLK_PCIE_ROOT,
+            id if (PCLK_PCIE_4L..=PCLK_PCIE_1L2).contains(&id) => PCLK_PHP_ROOT,
+            ACLK_MMU_PHP | ACLK_SATA0 | ACLK_SATA1 | ACLK_SATA2 => ACLK_PHP_ROOT,
+            _ => id,
+        };
+        self.clk_tree_set_rate(root, rate_hz)
     }
 
-    /// 设置 ADC 时钟频率
+    // ========================================================================
+    // 视频编解码时钟
+    // ========================================================================
+
+    /// 设置视频编解码时钟频率
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_adc_set_clk()
+    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
+    ///
+    /// 各编解码器的 ACLK/HCLK 只是根时钟后的门控，设置它们时修改对应的根时钟。
+    /// VDPU 域的根时钟由 VPU121 和 JPEG 编码器共享，修改会同时影响同域的其他设备
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn adc_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
-        Ok(match id {
-            CLK_SARADC => {
-                if OSC_HZ.is_multiple_of(rate_hz) {
-                    let src_clk_div = (OSC_HZ / rate_hz) as u32;
-                    self.clrsetreg(
-                        clksel_con(40),
-                        (1 << 14) | (0xFF << 6),
-                        (1 << 14) | ((src_clk_div - 1) << 6),
-                    );
-                    OSC_HZ / (src_clk_div as u64)
-                } else {
-                    let src_clk_div = (self.gpll_hz / rate_hz) as u32;
-                    self.clrsetreg(
-                        clksel_con(40),
-                        (1 << 14) | (0xFF << 6),
-                        (src_clk_div - 1) << 6,
-                    );
-                    self.gpll_hz / (src_clk_div as u64)
-                }
-            }
-            CLK_TSADC => {
-                if OSC_HZ.is_multiple_of(rate_hz) {
-                    let src_clk_div = (OSC_HZ / rate_hz).min(255) as u32;
-                    self.clrsetreg(
-                        clksel_con(41),
-                        (1 << 8) | 0xFF,
-                        (1 << 8) | (src_clk_div - 1),
-                    );
-                    OSC_HZ / (src_clk_div as u64)
-                } else {
-                    let src_clk_div = (self.gpll_hz / rate_hz).min(7) as u32;
-                    self.clrsetreg(clksel_con(41), (1 << 8) | 0xFF, src_clk_div - 1);
-                    100 * MHZ / (src_clk_div as u64)
-                }
-            }
-            _ => return Err(ClockError::unsupported(id)),
-        })
+    /// 没有合适的父时钟时返回 `ClockError::InvalidRate`
+    pub(crate) fn video_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let root = match id {
+            ACLK_RKVDEC0 => ACLK_RKVDEC0_ROOT,
+            HCLK_RKVDEC0 => HCLK_RKVDEC0_ROOT,
+            ACLK_RKVDEC1 => ACLK_RKVDEC1_ROOT,
+            HCLK_RKVDEC1 => HCLK_RKVDEC1_ROOT,
+            ACLK_RKVENC0 => ACLK_RKVENC0_ROOT,
+            HCLK_RKVENC0 => HCLK_RKVENC0_ROOT,
+            ACLK_RKVENC1 => ACLK_RKVENC1_ROOT,
+            HCLK_RKVENC1 => HCLK_RKVENC1_ROOT,
+            ACLK_VPU | ACLK_JPEG_ENCODER0 | ACLK_JPEG_ENCODER1 | ACLK_JPEG_ENCODER2
+            | ACLK_JPEG_ENCODER3 => ACLK_VDPU_LOW_ROOT,
+            HCLK_VPU | HCLK_JPEG_ENCODER0 | HCLK_JPEG_ENCODER1 | HCLK_JPEG_ENCODER2
+            | HCLK_JPEG_ENCODER3 | HCLK_JPEG_DECODER => HCLK_VDPU_ROOT,
+            ACLK_JPEG_DECODER => ACLK_JPEG_DECODER_ROOT,
+            ACLK_AV1 => ACLK_AV1_ROOT,
+            PCLK_AV1 => PCLK_AV1_ROOT,
+            _ => id,
+        };
+        self.clk_tree_set_rate(root, rate_hz)
     }
 
     // ========================================================================
-    // UART 时钟
+    // 摄像头时钟
     // ========================================================================
 
-    /// 获取 UART 时钟频率
+    /// 设置摄像头 (VI/ISP) 时钟频率
+    ///
+    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_uart_get_rate()
+    /// - ACLK/HCLK/PCLK 门控: 设置 VI 域或 ISP1 域对应的根时钟
+    /// - ISP 核心的 MARVIN/VICAP 门控: 设置 clk_ispN_core
+    /// - MCLK 输出: 在 24M/SPLL/GPLL/CPLL 中选择误差最小的父时钟和分频，
+    ///   无法精确得到目标频率时给出警告
+    ///
+    /// # 返回
     ///
-    /// 注意：仅支持 SCLK_UART0-3 (ID: 632-635)
+    /// 返回设置后实际的时钟频率，调用者可以据此配置传感器的 PLL
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn uart_get_rate(&self, id: ClkId) -> ClockResult<u64> {
-        let reg = match id {
-            SCLK_UART1 => 41,
-            SCLK_UART2 => 43,
-            SCLK_UART3 => 45,
-            SCLK_UART4 => 47,
-            SCLK_UART5 => 49,
-            SCLK_UART6 => 51,
-            SCLK_UART7 => 53,
-            SCLK_UART8 => 55,
-            SCLK_UART9 => 57,
-            _ => return Err(ClockError::unsupported(id)),
+    /// 没有合适的父时钟时返回 `ClockError::InvalidRate`
+    pub(crate) fn camera_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let root = match id {
+            ACLK_VICAP | ACLK_ISP0 | ACLK_FISHEYE0 | ACLK_FISHEYE1 => ACLK_VI_ROOT,
+            HCLK_VICAP | HCLK_ISP0 | HCLK_FISHEYE0 | HCLK_FISHEYE1 => HCLK_VI_ROOT,
+            PCLK_CSI_HOST_0 | PCLK_CSI_HOST_1 | PCLK_CSI_HOST_2 | PCLK_CSI_HOST_3
+            | PCLK_CSI_HOST_4 | PCLK_CSI_HOST_5 => PCLK_VI_ROOT,
+            CLK_ISP0_CORE_MARVIN | CLK_ISP0_CORE_VICAP => CLK_ISP0_CORE,
+            CLK_ISP1_CORE_MARVIN | CLK_ISP1_CORE_VICAP => CLK_ISP1_CORE,
+            ACLK_ISP1 => ACLK_ISP1_ROOT,
+            HCLK_ISP1 => HCLK_ISP1_ROOT,
+            _ => id,
         };
+        let rate = self.clk_tree_set_rate(root, rate_hz)?;
 
-        let con = self.read(clksel_con(reg + 2));
-        let src = con & 0x3;
+        if (CLK_CIFOUT_OUT..=CLK_MIPI_CAMARAOUT_M4).contains(&id) && rate != rate_hz {
+            warn!(
+                "camera mclk {}: requested {}Hz, got {}Hz",
+                id, rate_hz, rate
+            );
+        }
+        Ok(rate)
+    }
 
-        let con = self.read(clksel_con(reg));
-        let div = ((con >> 9) & 0x1F) as u64;
-        let p_src = (con >> 14) & 1;
-        let p_rate = if p_src == 0 {
-            self.gpll_hz
-        } else {
-            self.cpll_hz
-        };
+    // ========================================================================
+    // GPU 时钟
+    // ========================================================================
 
-        Ok(match src {
-            0 => p_rate / (div + 1), // CLK_UART_SEL_SRC
-            1 => {
-                // CLK_UART_SEL_FRAC
-                let fracdiv = self.read(clksel_con(reg + 1));
-                let n = (fracdiv >> 16) & 0xFFFF;
-                let m = fracdiv & 0xFFFF;
-                (p_rate / (div + 1)) * n as u64 / m as u64
+    /// 设置 GPU 时钟频率
+    ///
+    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
+    ///
+    /// - CLK_GPU / CLK_GPU_SRC: 切换到 CRU 分频路径 (clk_gpu_src)，
+    ///   按时钟树在 GPLL/CPLL/AUPLL/NPLL/SPLL 中选择不超过目标频率的最接近值
+    /// - CLK_GPU_COREGROUP / CLK_CORE_GPU_PVTM: 与 clk_gpu_src 同频，设置 clk_gpu_src
+    /// - CLK_GPU_STACKS / ACLK_*_GPU_BIU: 在 clk_gpu_src 基础上调整各自的分频
+    /// - PCLK_GPU_GRF: 设置 pclk_gpu_root
+    ///
+    /// 使用 PVTPLL 时通过 `clk_set_parent(CLK_GPU, CLK_GPU_PVTPLL)` 切换
+    ///
+    /// # Errors
+    ///
+    /// 没有合适的父时钟时返回 `ClockError::InvalidRate`
+    pub(crate) fn gpu_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        match id {
+            CLK_GPU | CLK_GPU_SRC => {
+                let rate = self.clk_tree_set_rate(CLK_GPU_SRC, rate_hz)?;
+                self.clk_core_set_parent(CLK_GPU, CLK_GPU_SRC)?;
+                Ok(rate)
             }
-            2 => OSC_HZ, // CLK_UART_SEL_XIN24M
-            _ => 0,
-        })
+            CLK_GPU_COREGROUP | CLK_CORE_GPU_PVTM => self.clk_tree_set_rate(CLK_GPU_SRC, rate_hz),
+            PCLK_GPU_GRF => self.clk_tree_set_rate(PCLK_GPU_ROOT, rate_hz),
+            _ => self.clk_tree_set_rate(id, rate_hz),
+        }
     }
 
-    /// 设置 UART 时钟频率
+    // ========================================================================
+    // NPU 时钟
+    // ========================================================================
+
+    /// 设置 NPU 时钟频率
     ///
-    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_uart_set_rate()
+    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
+    ///
+    /// - ACLK_NPU0~2 / CLK_NPU_MUX / CLK_NPU_DSU0: 切换到 CRU 分频路径 (clk_npu_dsu0)，
+    ///   按时钟树在 GPLL/CPLL/AUPLL/NPLL/SPLL 中选择不超过目标频率的最接近值。
+    ///   三个 NPU 核共享同一个时钟
+    /// - HCLK_NPU0~2 / PCLK_NPU_ROOT 等: 设置对应的根时钟
     ///
-    /// 注意：仅支持 SCLK_UART0-3 (ID: 632-635)
+    /// 使用 PVTPLL 时通过 `clk_set_parent(CLK_NPU_MUX, CLK_NPU_PVTPLL)` 切换
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn uart_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
-        let reg = match id {
-            SCLK_UART1 => 41,
-            SCLK_UART2 => 43,
-            SCLK_UART3 => 45,
-            SCLK_UART4 => 47,
-            SCLK_UART5 => 49,
-            SCLK_UART6 => 51,
-            SCLK_UART7 => 53,
-            SCLK_UART8 => 55,
-            SCLK_UART9 => 57,
-            _ => return Err(ClockError::unsupported(id)),
-        };
-
-        let (clk_src, uart_src, div) = if self.gpll_hz.is_multiple_of(rate_hz) {
-            (0, 0, (self.gpll_hz / rate_hz) as u32) // GPLL, SEL_SRC
-        } else if self.cpll_hz.is_multiple_of(rate_hz) {
-            (1, 0, (self.cpll_hz / rate_hz) as u32) // CPLL, SEL_SRC
-        } else if rate_hz == OSC_HZ {
-            (0, 2, 2) // GPLL, SEL_XIN24M
-        } else {
-            // 小数分频模式 - 简化实现
-            (0, 1, 2) // GPLL, SEL_FRAC
-        };
-
-        // 配置时钟源和分频
-        self.clrsetreg(
-            clksel_con(reg),
-            (1 << 14) | (0x1F << 9),
-            (clk_src << 14) | ((div - 1) << 9),
-        );
-
-        // 配置 UART 时钟选择
-        self.clrsetreg(clksel_con(reg + 2), 0x3, uart_src);
-
-        Ok(match uart_src {
-            0 => {
-                if clk_src == 0 {
-                    self.gpll_hz / div as u64
-                } else {
-                    self.cpll_hz / div as u64
-                }
+    /// 没有合适的父时钟时返回 `ClockError::InvalidRate`
+    pub(crate) fn npu_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        match id {
+            ACLK_NPU0 | ACLK_NPU1 | ACLK_NPU2 | CLK_NPU_MUX | CLK_NPU_DSU0 => {
+                let rate = self.clk_tree_set_rate(CLK_NPU_DSU0, rate_hz)?;
+                self.clk_core_set_parent(CLK_NPU_MUX, CLK_NPU_DSU0)?;
+                Ok(rate)
             }
-            2 => OSC_HZ,
-            _ => rate_hz,
-        })
+            HCLK_NPU0 | HCLK_NPU1 | HCLK_NPU2 => self.clk_tree_set_rate(HCLK_NPU_ROOT, rate_hz),
+            PCLK_NPU_PVTM | PCLK_NPU_GRF => self.clk_tree_set_rate(PCLK_NPU_ROOT, rate_hz),
+            _ => self.clk_tree_set_rate(id, rate_hz),
+        }
     }
 
     // ========================================================================
-    // MMC/SDMMC 时钟
+    // 音频时钟
     // ========================================================================
 
-    /// 获取 MMC 时钟频率
+    /// 设置音频 (I2S/SPDIF/PDM) 时钟频率
     ///
     /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
     ///
-    /// 支持的时钟：
-    /// - CCLK_EMMC: EMMC card clock (CLKSEL_CON(77))
-    /// - BCLK_EMMC: EMMC bus clock (CLKSEL_CON(78))
-    /// - CCLK_SRC_SDIO: SDIO source clock (CLKSEL_CON(172))
-    /// - SCLK_SFC: SFC clock (CLKSEL_CON(78))
+    /// - MCLK_* / *_MCLKOUT_TO_IO 门控: 设置其父时钟
+    /// - CLK_I2Sn / CLK_SPDIFn: 见 [`Cru::clk_frac_mux_set_rate`]，
+    ///   44.1kHz/48kHz 系列的 MCLK (如 11.2896MHz、12.288MHz) 可以精确得到
+    /// - I2Sn_MCLKOUT: 设置当前选择的 TX/RX MCLK，选择 xin12m 时只能在父时钟中切换
+    /// - 其他时钟 (src、PDM、SPDIF RX): 按时钟树选择最接近的父时钟和分频
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn mmc_get_rate(&self, id: ClkId) -> ClockResult<u64> {
-        // 根据时钟 ID 确定寄存器和位域
-        let (con_reg, sel_shift, sel_mask, div_shift, div_mask, _parent_sources): (
-            u32,
-            u32,
-            u32,
-            u32,
-            u32,
-            &[u64],
-        ) = match id {
-            CCLK_EMMC => {
-                // CLksel_CON(77): sel[14:15], div[8:13]
-                static PARENTS: [u64; 3] = [0, 0, 24 * MHZ];
-                (
-                    77,
-                    clk_sel77::CCLK_EMMC_SEL_SHIFT,
-                    clk_sel77::CCLK_EMMC_SEL_MASK,
-                    clk_sel77::CCLK_EMMC_DIV_SHIFT,
-                    clk_sel77::CCLK_EMMC_DIV_MASK,
-                    &PARENTS, // 稍后填充实际值
-                )
-            }
-            BCLK_EMMC => {
-                // CLKSEL_CON(78): sel[5], div[0:4]
-                static PARENTS: [u64; 2] = [0, 0];
-                (
-                    78,
-                    clk_sel78::BCLK_EMMC_SEL_SHIFT,
-                    clk_sel78::BCLK_EMMC_SEL_MASK,
-                    clk_sel78::BCLK_EMMC_DIV_SHIFT,
-                    clk_sel78::BCLK_EMMC_DIV_MASK,
-                    &PARENTS, // 稍后填充实际值
-                )
-            }
-            CCLK_SRC_SDIO => {
-                // CLKSEL_CON(172): sel[8:9], div[2:7]
-                static PARENTS: [u64; 3] = [0, 0, 24 * MHZ];
-                (
-                    172,
-                    clk_sel172::CCLK_SDIO_SRC_SEL_SHIFT,
-                    clk_sel172::CCLK_SDIO_SRC_SEL_MASK,
-                    clk_sel172::CCLK_SDIO_SRC_DIV_SHIFT,
-                    clk_sel172::CCLK_SDIO_SRC_DIV_MASK,
-                    &PARENTS, // 稍后填充实际值
-                )
-            }
-            SCLK_SFC => {
-                // CLKSEL_CON(78): sel[12:13], div[6:11]
-                static PARENTS: [u64; 3] = [0, 0, 24 * MHZ];
-                (
-                    78,
-                    clk_sel78::SCLK_SFC_SEL_SHIFT,
-                    clk_sel78::SCLK_SFC_SEL_MASK,
-                    clk_sel78::SCLK_SFC_DIV_SHIFT,
-                    clk_sel78::SCLK_SFC_DIV_MASK,
-                    &PARENTS, // 稍后填充实际值
-                )
-            }
-            _ => {
-                return Err(ClockError::unsupported(id));
-            }
-        };
+    /// 没有合适的父时钟时返回 `ClockError::InvalidRate`
+    pub(crate) fn audio_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+
+        if let ClkKind::Composite {
+            mux: None,
+            div: None,
+        } = node.kind
+            && let &[parent] = node.parents
+        {
+            return self.audio_set_rate(parent, rate_hz);
+        }
 
-        // 动态填充父时钟频率
-        let parents: Vec<u64> = match id {
-            CCLK_EMMC | CCLK_SRC_SDIO | SCLK_SFC => {
-                vec![self.gpll_hz, self.cpll_hz, 24 * MHZ]
+        if matches!(
+            id,
+            I2S0_8CH_MCLKOUT | I2S1_8CH_MCLKOUT | I2S2_2CH_MCLKOUT | I2S3_2CH_MCLKOUT
+        ) {
+            let parent = self.clk_current_parent(node)?;
+            if parent != XIN12M {
+                return self.audio_set_rate(parent, rate_hz);
             }
-            BCLK_EMMC => vec![self.gpll_hz, self.cpll_hz],
-            _ => return Err(ClockError::unsupported(id)),
-        };
-
-        // 读取寄存器
-        let val = self.read(clksel_con(con_reg));
-
-        // 提取时钟源选择和分频值
-        let sel = ((val & sel_mask) >> sel_shift) as usize;
-        let div = ((val & div_mask) >> div_shift) as u64;
+        }
 
-        // 获取父时钟频率
-        let parent_rate = parents
-            .get(sel)
-            .copied()
-            .ok_or_else(|| ClockError::rate_read_failed(id, "Invalid parent clock source"))?;
+        let has_frac = node
+            .parents
+            .get(1)
+            .and_then(|&p| self.find_clk_node(p))
+            .is_some_and(|p| matches!(p.kind, ClkKind::Frac { .. }));
+        if has_frac {
+            self.clk_frac_mux_set_rate(id, rate_hz)
+        } else {
+            self.clk_tree_set_rate(id, rate_hz)
+        }
+    }
 
-        // 计算实际频率: rate = parent_rate / (div + 1)
-        let rate = parent_rate / (div + 1);
+    // ========================================================================
+    // VOP 时钟
+    // ========================================================================
 
-        Ok(rate)
+    /// 设置 VOP 时钟频率
+    ///
+    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_dclk_vop_set_clk()
+    ///
+    /// - DCLK_VOPn_SRC / DCLK_VOP3: 见 [`Cru::dclk_vop_set_rate`]
+    /// - DCLK_VOP0~2: 当前选择 dclk_vopN_src 时设置其频率
+    ///   (Linux: CLK_SET_RATE_PARENT | CLK_SET_RATE_NO_REPARENT)
+    /// - ACLK_VOP / HCLK_VOP: 设置对应的根时钟
+    /// - 其他根时钟: 按时钟树选择最接近的父时钟和分频
+    ///
+    /// # Errors
+    ///
+    /// DCLK_VOP0~2 选择 HDMI PHY 像素时钟时返回 `ClockError::InvalidRate`，
+    /// 该频率由 HDMI PHY 驱动设置
+    pub(crate) fn vop_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        match id {
+            DCLK_VOP0_SRC | DCLK_VOP1_SRC | DCLK_VOP2_SRC | DCLK_VOP3 => {
+                self.dclk_vop_set_rate(id, rate_hz)
+            }
+            DCLK_VOP0 | DCLK_VOP1 | DCLK_VOP2 => {
+                let src = match id {
+                    DCLK_VOP0 => DCLK_VOP0_SRC,
+                    DCLK_VOP1 => DCLK_VOP1_SRC,
+                    _ => DCLK_VOP2_SRC,
+                };
+                if self.clk_get_parent(id)? != src {
+                    return Err(ClockError::invalid_rate(id, rate_hz));
+                }
+                self.dclk_vop_set_rate(src, rate_hz)
+            }
+            ACLK_VOP => {
+                self.clk_core_set_parent(ACLK_VOP_SUB_SRC, ACLK_VOP_ROOT)?;
+                self.clk_tree_set_rate(ACLK_VOP_ROOT, rate_hz)
+            }
+            HCLK_VOP => self.clk_tree_set_rate(HCLK_VOP_ROOT, rate_hz),
+            _ => self.clk_tree_set_rate(id, rate_hz),
+        }
     }
 
-    /// 设置 MMC 时钟频率
+    /// 设置 VOP 像素时钟源的频率
     ///
-    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
+    /// 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_dclk_vop_set_clk()
     ///
-    /// 支持的时钟：
-    /// - CCLK_EMMC: EMMC card clock (CLKSEL_CON(77))
-    /// - BCLK_EMMC: EMMC bus clock (CLKSEL_CON(78))
-    /// - CCLK_SRC_SDIO: SDIO source clock (CLKSEL_CON(172))
-    /// - SCLK_SFC: SFC clock (CLKSEL_CON(78))
+    /// 1. GPLL/CPLL 能整数分频得到目标频率时直接使用，不影响 V0PLL
+    /// 2. 否则切换到 V0PLL，取不低于 [`VOP_PLL_LIMIT_HZ`] 的偶数分频值，
+    ///    并把 V0PLL 重新配置为 `rate * div`
+    /// 3. V0PLL 正被其他已使能或门控打开 (如 bootloader 留下的显示输出) 的 VOP
+    ///    像素时钟使用时不重新配置，退回到在现有父时钟中选择最接近的频率
+    ///
+    /// AUPLL 留给音频使用，不作为自动选择的父时钟
     ///
     /// # 参数
     ///
-    /// * `id` - 时钟 ID
-    /// * `rate_hz` - 目标频率 (Hz)
+    /// * `id` - DCLK_VOP0_SRC ~ DCLK_VOP2_SRC 或 DCLK_VOP3
+    /// * `rate_hz` - 目标像素时钟频率 (Hz)
     ///
     /// # 返回
     ///
-    /// 返回实际设置的频率
+    /// 返回设置后实际的时钟频率
     ///
     /// # Errors
     ///
-    /// 如果时钟 ID 不支持或无法设置目标频率，返回错误
-    pub(crate) fn mmc_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
-        // 根据时钟 ID 确定寄存器和位域，以及可用的时钟源
-        let (con_reg, sel_shift, sel_mask, div_shift, div_mask, parent_sources): (
-            u32,
-            u32,
-            u32,
-            u32,
-            u32,
-            &[(u64, u32)],
-        ) = match id {
-            CCLK_EMMC => {
-                // CLKSEL_CON(77): sel[14:15], div[8:13]
-                static SOURCES: [(u64, u32); 3] = [
-                    (0, clk_sel77::CCLK_EMMC_SEL_GPLL),
-                    (0, clk_sel77::CCLK_EMMC_SEL_CPLL),
-                    (24 * MHZ, clk_sel77::CCLK_EMMC_SEL_24M),
-                ];
-                (
-                    77,
-                    clk_sel77::CCLK_EMMC_SEL_SHIFT,
-                    clk_sel77::CCLK_EMMC_SEL_MASK,
-                    clk_sel77::CCLK_EMMC_DIV_SHIFT,
-                    clk_sel77::CCLK_EMMC_DIV_MASK,
-                    &SOURCES, // 稍后填充实际值
-                )
-            }
-            BCLK_EMMC => {
-                // CLKSEL_CON(78): sel[5], div[0:4]
-                static SOURCES: [(u64, u32); 2] = [
-                    (0, clk_sel78::BCLK_EMMC_SEL_GPLL),
-                    (0, clk_sel78::BCLK_EMMC_SEL_CPLL),
-                ];
-                (
-                    78,
-                    clk_sel78::BCLK_EMMC_SEL_SHIFT,
-                    clk_sel78::BCLK_EMMC_SEL_MASK,
-                    clk_sel78::BCLK_EMMC_DIV_SHIFT,
-                    clk_sel78::BCLK_EMMC_DIV_MASK,
-                    &SOURCES, // 稍后填充实际值
-                )
-            }
-            CCLK_SRC_SDIO => {
-                // CLKSEL_CON(172): sel[8:9], div[2:7]
-                static SOURCES: [(u64, u32); 3] = [
-                    (0, clk_sel172::CCLK_SDIO_SRC_SEL_GPLL),
-                    (0, clk_sel172::CCLK_SDIO_SRC_SEL_CPLL),
-                    (24 * MHZ, clk_sel172::CCLK_SDIO_SRC_SEL_24M),
-                ];
-                (
-                    172,
-                    clk_sel172::CCLK_SDIO_SRC_SEL_SHIFT,
-                    clk_sel172::CCLK_SDIO_SRC_SEL_MASK,
-                    clk_sel172::CCLK_SDIO_SRC_DIV_SHIFT,
-                    clk_sel172::CCLK_SDIO_SRC_DIV_MASK,
-                    &SOURCES, // 稍后填充实际值
-                )
-            }
-            SCLK_SFC => {
-                // CLKSEL_CON(78): sel[12:13], div[6:11]
-                static SOURCES: [(u64, u32); 3] = [
-                    (0, clk_sel78::SCLK_SFC_SEL_GPLL),
-                    (0, clk_sel78::SCLK_SFC_SEL_CPLL),
-                    (24 * MHZ, clk_sel78::SCLK_SFC_SEL_24M),
-                ];
-                (
-                    78,
-                    clk_sel78::SCLK_SFC_SEL_SHIFT,
-                    clk_sel78::SCLK_SFC_SEL_MASK,
-                    clk_sel78::SCLK_SFC_DIV_SHIFT,
-                    clk_sel78::SCLK_SFC_DIV_MASK,
-                    &SOURCES, // 稍后填充实际值
-                )
-            }
-            _ => {
-                return Err(ClockError::unsupported(id));
+    /// 频率为 0 返回 `ClockError::InvalidRate`，V0PLL 配置失败时返回 PLL 错误
+    pub(crate) fn dclk_vop_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        let div = node.divider().ok_or(ClockError::unsupported(id))?;
+        if rate_hz == 0 {
+            return Err(ClockError::invalid_rate(id, rate_hz));
+        }
+        let max_div = div.field.max() as u64 + 1;
+
+        // 1. GPLL/CPLL 整数分频
+        for parent in [PLL_GPLL, PLL_CPLL] {
+            let parent_rate = self.clk_tree_get_rate(parent)?;
+            if parent_rate % rate_hz == 0 && parent_rate / rate_hz <= max_div {
+                return self.dclk_vop_apply(id, parent, (parent_rate / rate_hz) as u32);
             }
-        };
+        }
 
-        // 动态构建时钟源列表（填充实际 PLL 频率）
-        let sources: Vec<(u64, u32)> = match id {
-            CCLK_EMMC | CCLK_SRC_SDIO | SCLK_SFC => vec![
-                (self.gpll_hz, parent_sources[0].1),
-                (self.cpll_hz, parent_sources[1].1),
-                (24 * MHZ, parent_sources[2].1),
-            ],
-            BCLK_EMMC => vec![
-                (self.gpll_hz, parent_sources[0].1),
-                (self.cpll_hz, parent_sources[1].1),
-            ],
-            _ => return Err(ClockError::unsupported(id)),
-        };
+        // 2. V0PLL，其他 VOP 正在使用时不修改。bootloader 留下的显示输出没有使能计数，
+        //    门控打开同样视为使用中
+        let v0pll_busy = DCLK_VOP_SRCS.iter().any(|&other| {
+            other != id
+                && self.clk_get_parent(other).ok() == Some(PLL_V0PLL)
+                && (self.enable_count.contains_key(&other)
+                    || self.clk_is_enabled(other).unwrap_or(false))
+        });
+        if v0pll_busy {
+            warn!(
+                "{}: V0PLL is in use by another VOP, {}Hz may not be exact",
+                node.name, rate_hz
+            );
+            return self.clk_tree_set_rate(id, rate_hz);
+        }
 
-        // 选择最佳时钟源和分频值
-        let mut best_parent_rate = 0u64;
-        let mut best_sel = 0u32;
-        let mut best_div = 0u64;
-        let mut min_error = u64::MAX;
-
-        // 遍历所有可能的时钟源，找到最接近目标频率的配置
-        for &(parent_rate, sel_val) in &sources {
-            // 计算最佳分频值: div = parent_rate / target_rate
-            let div = (parent_rate + rate_hz / 2) / rate_hz; // 四舍五入
-
-            // 限制分频范围
-            let max_div = (div_mask >> div_shift) + 1;
-            let div = div.clamp(1, max_div as u64);
-
-            // 计算实际频率
-            let actual_rate = parent_rate / div;
-
-            // 计算误差
-            let error = actual_rate.abs_diff(rate_hz);
-
-            // 如果误差更小，则更新最佳配置
-            if error < min_error {
-                min_error = error;
-                best_parent_rate = parent_rate;
-                best_sel = sel_val;
-                best_div = div - 1; // 寄存器值 = div - 1
-            }
+        let mut vop_div = VOP_PLL_LIMIT_HZ.div_ceil(rate_hz).min(max_div);
+        if vop_div % 2 == 1 && vop_div < max_div {
+            vop_div += 1;
         }
+        let pll_rate = rate_hz * vop_div;
+        let pending = self.clk_notify_pre_nested(id, PLL_V0PLL, pll_rate)?;
+        if let Err(e) = self.pll_set_rate(PllId::V0PLL, pll_rate) {
+            self.clk_notify_abort_nested(pending);
+            return Err(e);
+        }
+        self.dclk_vop_apply(id, PLL_V0PLL, vop_div as u32)
+    }
 
-        // 使用 Rockchip 写掩码机制配置寄存器
-        // 格式: (mask << 16) | value
-        // mask = sel_mask | div_mask
-        // value = (sel << sel_shift) | (div << div_shift)
-        let mask = sel_mask | div_mask;
-        let value = (best_sel << sel_shift) | ((best_div as u32) << div_shift);
+    /// 写入像素时钟源的分频值和父时钟
+    fn dclk_vop_apply(&mut self, id: ClkId, parent: ClkId, div: u32) -> ClockResult<u64> {
+        let field = self
+            .find_clk_node(id)
+            .and_then(|node| node.divider())
+            .ok_or(ClockError::unsupported(id))?
+            .field;
+
+        debug!("dclk_vop({}): parent {}, div {}", id, parent, div);
+        self.clrsetreg(field.reg, field.mask, (div - 1) << field.shift);
+        self.clk_core_set_parent(id, parent)?;
+        self.clk_tree_get_rate(id)
+    }
+}
 
-        self.clrsetreg(clksel_con(con_reg), mask, value);
+/// VOP 像素时钟源 (可选择 V0PLL 的时钟)
+const DCLK_VOP_SRCS: [ClkId; 4] = [DCLK_VOP0_SRC, DCLK_VOP1_SRC, DCLK_VOP2_SRC, DCLK_VOP3];
+
+// =============================================================================
+// 单元测试
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        regmap::RegFile,
+        variants::rk3588::cru::{
+            reset::*,
+            sim::{SimCru, sim_cru},
+        },
+    };
+
+    #[test]
+    fn test_gmac_set_rate() {
+        let (_, mut cru) = sim_cru();
+
+        // 125M/50M 只能由 CPLL 精确分出
+        assert_eq!(
+            cru.clk_set_rate(CLK_GMAC_125M, 125 * MHZ).unwrap(),
+            125 * MHZ
+        );
+        assert_eq!(cru.clk_get_parent(CLK_GMAC_125M).unwrap(), PLL_CPLL);
+        assert_eq!(cru.clk_set_rate(CLK_GMAC_50M, 50 * MHZ).unwrap(), 50 * MHZ);
+        assert_eq!(cru.clk_get_parent(CLK_GMAC_50M).unwrap(), PLL_CPLL);
+
+        assert_eq!(
+            cru.clk_set_rate(REFCLKO25M_ETH0_OUT, 25 * MHZ).unwrap(),
+            25 * MHZ
+        );
+        assert_eq!(
+            cru.clk_set_rate(REFCLKO25M_ETH1_OUT, 25 * MHZ).unwrap(),
+            25 * MHZ
+        );
+        assert_eq!(
+            cru.clk_set_rate(CLK_GMAC0_PTP_REF, 50 * MHZ).unwrap(),
+            50 * MHZ
+        );
 
-        // 返回实际频率
-        let actual_rate = best_parent_rate / (best_div + 1);
-        Ok(actual_rate)
+        // 无法精确得到时选择不超过目标频率的最接近值
+        let rate = cru.clk_set_rate(CLK_GMAC1_PTP_REF, 70 * MHZ).unwrap();
+        assert_eq!(rate, CPLL_HZ / 22);
+        assert_eq!(cru.clk_get_rate(CLK_GMAC1_PTP_REF).unwrap(), rate);
     }
 
-    // ========================================================================
-    // USB 时钟
-    // ========================================================================
+    #[test]
+    fn test_gmac_enable_propagates() {
+        let (_, mut cru) = sim_cru();
 
-    /// 获取 USB 时钟频率
-    ///
-    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
-    ///
-    /// 支持的时钟：
-    /// - ACLK_USB_ROOT: USB ACLK root (CLKSEL_CON(96))
-    /// - HCLK_USB_ROOT: USB HCLK root (CLKSEL_CON(96))
-    /// - CLK_UTMI_OTG2: UTMI clock for OTG2 (CLKSEL_CON(84))
-    ///
-    /// # Errors
-    ///
-    /// 如果时钟 ID 不支持或寄存器读取失败，返回错误
-    pub(crate) fn usb_get_rate(&self, id: ClkId) -> ClockResult<u64> {
-        // 导入 USB clock ID 常量
-        use clock::{ACLK_USB_ROOT, CLK_UTMI_OTG2, HCLK_USB_ROOT};
-
-        // USB 时钟源常量
-        const CLK_150M: u64 = 150 * MHZ;
-        const CLK_100M: u64 = 100 * MHZ;
-        const CLK_50M: u64 = 50 * MHZ;
-
-        // 根据时钟 ID 确定寄存器和位域
-        let (con_reg, sel_shift, sel_mask, div_shift, div_mask, parent_sources): (
-            u32,
-            u32,
-            u32,
-            u32,
-            u32,
-            &[u64],
-        ) = match id {
-            ACLK_USB_ROOT => {
-                // CLKSEL_CON(96): sel[5], div[0:4]
-                static PARENTS: [u64; 2] = [0, 0];
-                (
-                    96,
-                    clk_sel96::ACLK_USB_ROOT_SEL_SHIFT,
-                    clk_sel96::ACLK_USB_ROOT_SEL_MASK,
-                    clk_sel96::ACLK_USB_ROOT_DIV_SHIFT,
-                    clk_sel96::ACLK_USB_ROOT_DIV_MASK,
-                    &PARENTS,
-                )
-            }
-            HCLK_USB_ROOT => {
-                // CLKSEL_CON(96): sel[6:7], 无 div
-                static PARENTS: [u64; 4] = [CLK_150M, CLK_100M, CLK_50M, 24 * MHZ];
-                (
-                    96,
-                    clk_sel96::HCLK_USB_ROOT_SEL_SHIFT,
-                    clk_sel96::HCLK_USB_ROOT_SEL_MASK,
-                    0, // 无 div
-                    0, // 无 div
-                    &PARENTS,
-                )
-            }
-            CLK_UTMI_OTG2 => {
-                // CLKSEL_CON(84): sel[12:13], div[8:11]
-                static PARENTS: [u64; 3] = [CLK_150M, CLK_50M, 24 * MHZ];
-                (
-                    84,
-                    clk_sel84::CLK_UTMI_OTG2_SEL_SHIFT,
-                    clk_sel84::CLK_UTMI_OTG2_SEL_MASK,
-                    clk_sel84::CLK_UTMI_OTG2_DIV_SHIFT,
-                    clk_sel84::CLK_UTMI_OTG2_DIV_MASK,
-                    &PARENTS,
-                )
-            }
-            _ => {
-                return Err(ClockError::unsupported(id));
-            }
-        };
+        cru.clk_enable(ACLK_GMAC0).unwrap();
+        assert!(cru.clk_is_enabled(ACLK_GMAC0).unwrap());
+        assert!(cru.clk_is_enabled(PCLK_GMAC0).unwrap());
+        assert!(cru.clk_is_enabled(ACLK_PHP_ROOT).unwrap());
+        assert!(cru.clk_is_enabled(PCLK_PHP_ROOT).unwrap());
+        assert_eq!(cru.clk_enable_count(ACLK_GMAC1).unwrap(), 0);
 
-        // 动态填充父时钟频率
-        let parents: Vec<u64> = match id {
-            ACLK_USB_ROOT => vec![self.gpll_hz, self.cpll_hz],
-            HCLK_USB_ROOT | CLK_UTMI_OTG2 => parent_sources.to_vec(),
-            _ => return Err(ClockError::unsupported(id)),
-        };
+        cru.clk_disable(ACLK_GMAC0).unwrap();
+        assert_eq!(cru.clk_enable_count(PCLK_GMAC0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_dclk_vop_from_gpll() {
+        let (sim, mut cru) = sim_cru();
+        let v0pll = sim.read(pll_con(88) as usize);
+
+        // 1080p60: 148.5MHz = GPLL / 8，不需要改动 V0PLL
+        assert_eq!(
+            cru.clk_set_rate(DCLK_VOP0, 148_500_000).unwrap(),
+            148_500_000
+        );
+        assert_eq!(cru.clk_get_parent(DCLK_VOP0_SRC).unwrap(), PLL_GPLL);
+        assert_eq!(cru.clk_get_rate(DCLK_VOP0).unwrap(), 148_500_000);
+        assert_eq!(sim.read(pll_con(88) as usize), v0pll);
+    }
 
-        // 读取寄存器
-        let val = self.read(clksel_con(con_reg));
+    #[test]
+    fn test_dclk_vop_reprograms_v0pll() {
+        let (_, mut cru) = sim_cru();
 
-        // 提取时钟源选择
-        let sel = ((val & sel_mask) >> sel_shift) as usize;
+        // 640x480@60: 25.175MHz 无法由 GPLL/CPLL 整数分频得到
+        let rate = cru.clk_set_rate(DCLK_VOP3, 25_175_000).unwrap();
+        assert_eq!(cru.clk_get_parent(DCLK_VOP3).unwrap(), PLL_V0PLL);
+        assert!(rate.abs_diff(25_175_000) < 1000, "dclk_vop3 = {rate}");
 
-        // 获取父时钟频率
-        let parent_rate = parents
-            .get(sel)
-            .copied()
-            .ok_or_else(|| ClockError::rate_read_failed(id, "Invalid parent clock source"))?;
+        let v0pll = cru.clk_get_rate(PLL_V0PLL).unwrap();
+        assert!(v0pll >= VOP_PLL_LIMIT_HZ);
+        let div = cru.read(clksel_con(113)) & clk_sel113::DCLK3_VOP_SRC_DIV_MASK;
+        assert_eq!((div + 1) % 2, 0);
+    }
+
+    #[test]
+    fn test_dclk_vop_v0pll_in_use() {
+        let (_, mut cru) = sim_cru();
+
+        cru.clk_set_rate(DCLK_VOP3, 25_175_000).unwrap();
+        cru.clk_enable(DCLK_VOP3).unwrap();
+        let v0pll = cru.clk_get_rate(PLL_V0PLL).unwrap();
+
+        // V0PLL 已被 DCLK_VOP3 使用，其他 VOP 不会改动它
+        cru.clk_set_rate(DCLK_VOP1_SRC, 65_000_000).unwrap();
+        assert_eq!(cru.clk_get_rate(PLL_V0PLL).unwrap(), v0pll);
+        assert!(cru.clk_get_rate(DCLK_VOP3).unwrap().abs_diff(25_175_000) < 1000);
+    }
 
-        // 对于无分频器的时钟 (HCLK_USB_ROOT)，直接返回父时钟频率
-        if id == HCLK_USB_ROOT {
-            return Ok(parent_rate);
+    #[test]
+    fn test_dclk_vop_v0pll_left_on_by_bootloader() {
+        let (_, mut cru) = sim_cru();
+
+        // DCLK_VOP3 没有使能计数，但门控打开 (bootloader 留下的显示输出)
+        cru.clk_set_rate(DCLK_VOP3, 25_175_000).unwrap();
+        assert_eq!(cru.clk_enable_count(DCLK_VOP3).unwrap(), 0);
+        assert!(cru.clk_is_enabled(DCLK_VOP3).unwrap());
+        let v0pll = cru.clk_get_rate(PLL_V0PLL).unwrap();
+
+        cru.clk_set_rate(DCLK_VOP1_SRC, 65 * MHZ).unwrap();
+        assert_eq!(cru.clk_get_rate(PLL_V0PLL).unwrap(), v0pll);
+
+        // 门控关闭后可以重新配置
+        cru.clk_enable(DCLK_VOP3).unwrap();
+        cru.clk_disable(DCLK_VOP3).unwrap();
+        cru.clk_set_rate(DCLK_VOP1_SRC, 65 * MHZ).unwrap();
+        assert_ne!(cru.clk_get_rate(PLL_V0PLL).unwrap(), v0pll);
+    }
+
+    #[test]
+    fn test_dclk_vop_hdmiphy_parent() {
+        let (_, mut cru) = sim_cru();
+
+        cru.clk_set_parent(DCLK_VOP2, CLK_HDMIPHY_PIXEL0).unwrap();
+        assert!(cru.clk_set_rate(DCLK_VOP2, 148_500_000).is_err());
+        assert!(cru.clk_get_rate(DCLK_VOP2).is_err());
+    }
+
+    #[test]
+    fn test_vop_gates() {
+        let (sim, mut cru) = sim_cru();
+
+        cru.clk_enable(ACLK_VOP).unwrap();
+        assert_eq!(cru.clk_enable_count(ACLK_VOP_ROOT).unwrap(), 1);
+
+        // clkgate_con(53) 属于主 CRU
+        cru.clk_enable(DCLK_VOP1).unwrap();
+        cru.clk_disable(DCLK_VOP1).unwrap();
+        assert_eq!(sim.read(clkgate_con(53) as usize) & 1, 1);
+        assert!(!cru.clk_is_enabled(DCLK_VOP1).unwrap());
+    }
+
+    #[test]
+    fn test_npu_set_rate() {
+        let (_, mut cru) = sim_cru();
+
+        cru.clk_set_parent(CLK_NPU_MUX, CLK_NPU_PVTPLL).unwrap();
+        assert!(cru.clk_get_rate(ACLK_NPU0).is_err());
+
+        // 800MHz 无法精确得到，取不超过目标的 CPLL / 2
+        assert_eq!(cru.clk_set_rate(ACLK_NPU1, 800 * MHZ).unwrap(), 750 * MHZ);
+        assert_eq!(cru.clk_get_parent(CLK_NPU_MUX).unwrap(), CLK_NPU_DSU0);
+        for id in [ACLK_NPU0, ACLK_NPU1, ACLK_NPU2] {
+            assert_eq!(cru.clk_get_rate(id).unwrap(), 750 * MHZ);
         }
 
-        // 提取分频值并计算实际频率
-        let div = ((val & div_mask) >> div_shift) as u64;
-        let rate = parent_rate / (div + 1);
+        // clk_100m_src = GPLL / 12 = 99MHz
+        let src_rate = cru.clk_get_rate(CLK_100M_SRC).unwrap();
+        assert_eq!(cru.clk_set_rate(HCLK_NPU0, 100 * MHZ).unwrap(), src_rate);
+        assert_eq!(cru.clk_get_parent(HCLK_NPU_ROOT).unwrap(), CLK_100M_SRC);
+    }
 
-        Ok(rate)
+    #[test]
+    fn test_npu_enable_and_reset() {
+        let (sim, mut cru) = sim_cru();
+
+        cru.clk_enable(ACLK_NPU0).unwrap();
+        assert_eq!(cru.clk_enable_count(HCLK_NPU0).unwrap(), 1);
+        assert_eq!(cru.clk_enable_count(CLK_NPU_DSU0).unwrap(), 1);
+
+        cru.reset_assert(SRST_A_RKNN0);
+        assert_eq!(sim.read(softrst_con(30) as usize), 1 << 6);
+        cru.reset_deassert(SRST_A_RKNN0);
+        assert_eq!(sim.read(softrst_con(30) as usize), 0);
     }
 
-    /// 设置 USB 时钟频率
-    ///
-    /// 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
-    ///
-    /// 支持的时钟：
-    /// - ACLK_USB_ROOT: USB ACLK root (CLKSEL_CON(96))
-    /// - CLK_UTMI_OTG2: UTMI clock for OTG2 (CLKSEL_CON(84))
-    ///
-    /// 注意: HCLK_USB_ROOT 是 COMPOSITE_NODIV 时钟，不支持 set_rate
-    ///
-    /// # Errors
-    ///
-    /// 如果时钟 ID 不支持或寄存器写入失败，返回错误
-    pub(crate) fn usb_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
-        // 导入 USB clock ID 常量
-        use clock::{ACLK_USB_ROOT, CLK_UTMI_OTG2, HCLK_USB_ROOT};
+    #[test]
+    fn test_gpu_set_rate() {
+        let (_, mut cru) = sim_cru();
+
+        cru.clk_set_parent(CLK_GPU, CLK_GPU_PVTPLL).unwrap();
+        assert_eq!(cru.clk_set_rate(CLK_GPU, 594 * MHZ).unwrap(), 594 * MHZ);
+        assert_eq!(cru.clk_get_parent(CLK_GPU).unwrap(), CLK_GPU_SRC);
+        assert_eq!(cru.clk_get_parent(CLK_GPU_SRC).unwrap(), PLL_GPLL);
+        assert_eq!(cru.clk_get_rate(CLK_GPU_COREGROUP).unwrap(), 594 * MHZ);
+
+        // BIU 在 clk_gpu_src 基础上分频
+        assert_eq!(
+            cru.clk_set_rate(ACLK_M0_GPU_BIU, 300 * MHZ).unwrap(),
+            297 * MHZ
+        );
+        assert_eq!(cru.clk_get_rate(CLK_GPU).unwrap(), 594 * MHZ);
+    }
+
+    #[test]
+    fn test_video_set_rate() {
+        let (_, mut cru) = sim_cru();
+
+        // 编解码器的 ACLK 设置对应的根时钟
+        assert_eq!(
+            cru.clk_set_rate(ACLK_RKVENC0, 594 * MHZ).unwrap(),
+            594 * MHZ
+        );
+        assert_eq!(cru.clk_get_rate(ACLK_RKVENC0_ROOT).unwrap(), 594 * MHZ);
+        assert_eq!(
+            cru.clk_set_rate(CLK_RKVENC0_CORE, 800 * MHZ).unwrap(),
+            CPLL_HZ / 2
+        );
+        // 复位状态下 clk_1000m_src 为 GPLL / 1.5
+        assert_eq!(
+            cru.clk_set_rate(CLK_RKVDEC0_HEVC_CA, 800 * MHZ).unwrap(),
+            cru.clk_get_rate(CLK_1000M_SRC).unwrap()
+        );
+
+        // JPEG 编码器与 VPU121 共享 aclk_vdpu_low_root
+        assert_eq!(
+            cru.clk_set_rate(ACLK_JPEG_ENCODER0, 200 * MHZ).unwrap(),
+            cru.clk_get_rate(CLK_200M_SRC).unwrap()
+        );
+        assert_eq!(
+            cru.clk_get_rate(ACLK_VPU).unwrap(),
+            cru.clk_get_rate(ACLK_JPEG_ENCODER0).unwrap()
+        );
+    }
 
-        const CLK_150M: u64 = 150 * MHZ;
-        const CLK_50M: u64 = 50 * MHZ;
+    #[test]
+    fn test_video_enable_propagates() {
+        let (_, mut cru) = sim_cru();
 
-        // HCLK_USB_ROOT 是 COMPOSITE_NODIV，不支持 set_rate
-        if id == HCLK_USB_ROOT {
-            return Err(ClockError::unsupported(id));
+        cru.clk_enable(ACLK_RKVENC1).unwrap();
+        assert!(cru.clk_is_enabled(HCLK_RKVENC1).unwrap());
+        assert!(cru.clk_is_enabled(ACLK_RKVENC1_ROOT).unwrap());
+        assert!(cru.clk_is_enabled(HCLK_RKVENC1_ROOT).unwrap());
+        assert_eq!(cru.clk_enable_count(ACLK_RKVENC0).unwrap(), 0);
+
+        cru.clk_disable(ACLK_RKVENC1).unwrap();
+        assert_eq!(cru.clk_enable_count(HCLK_RKVENC1).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_camera_mclk_exact_rates() {
+        let (_, mut cru) = sim_cru();
+
+        // 常用传感器 MCLK 都能精确得到
+        for (rate, parent) in [(24 * MHZ, XIN24M), (37_125_000, PLL_GPLL), (27 * MHZ, SPLL)] {
+            assert_eq!(cru.clk_set_rate(CLK_MIPI_CAMARAOUT_M1, rate).unwrap(), rate);
+            assert_eq!(cru.clk_get_parent(CLK_MIPI_CAMARAOUT_M1).unwrap(), parent);
         }
 
-        // 根据时钟 ID 确定寄存器和位域
-        let (con_reg, sel_shift, sel_mask, div_shift, div_mask, parent_sources): (
-            u32,
-            u32,
-            u32,
-            u32,
-            u32,
-            &[(u64, u32)],
-        ) = match id {
-            ACLK_USB_ROOT => {
-                static SOURCES: [(u64, u32); 2] = [
-                    (0, clk_sel96::ACLK_USB_ROOT_SEL_GPLL),
-                    (0, clk_sel96::ACLK_USB_ROOT_SEL_CPLL),
-                ];
-                (
-                    96,
-                    clk_sel96::ACLK_USB_ROOT_SEL_SHIFT,
-                    clk_sel96::ACLK_USB_ROOT_SEL_MASK,
-                    clk_sel96::ACLK_USB_ROOT_DIV_SHIFT,
-                    clk_sel96::ACLK_USB_ROOT_DIV_MASK,
-                    &SOURCES,
-                )
-            }
-            CLK_UTMI_OTG2 => {
-                static SOURCES: [(u64, u32); 3] = [
-                    (CLK_150M, clk_sel84::CLK_UTMI_OTG2_SEL_150M),
-                    (CLK_50M, clk_sel84::CLK_UTMI_OTG2_SEL_50M),
-                    (24 * MHZ, clk_sel84::CLK_UTMI_OTG2_SEL_24M),
-                ];
-                (
-                    84,
-                    clk_sel84::CLK_UTMI_OTG2_SEL_SHIFT,
-                    clk_sel84::CLK_UTMI_OTG2_SEL_MASK,
-                    clk_sel84::CLK_UTMI_OTG2_DIV_SHIFT,
-                    clk_sel84::CLK_UTMI_OTG2_DIV_MASK,
-                    &SOURCES,
-                )
-            }
-            _ => {
-                return Err(ClockError::unsupported(id));
-            }
-        };
+        // 无法精确得到时返回实际频率
+        let rate = cru.clk_set_rate(CLK_MIPI_CAMARAOUT_M4, 19 * MHZ).unwrap();
+        assert!(rate <= 19 * MHZ);
+        assert_eq!(cru.clk_get_rate(CLK_MIPI_CAMARAOUT_M4).unwrap(), rate);
+    }
 
-        // 动态填充父时钟频率
-        let sources: Vec<(u64, u32)> = match id {
-            ACLK_USB_ROOT => vec![
-                (self.gpll_hz, parent_sources[0].1),
-                (self.cpll_hz, parent_sources[1].1),
-            ],
-            CLK_UTMI_OTG2 => parent_sources.to_vec(),
-            _ => return Err(ClockError::unsupported(id)),
-        };
+    #[test]
+    fn test_camera_set_rate() {
+        let (_, mut cru) = sim_cru();
 
-        // 查找最佳时钟源和分频值
-        let mut best_parent_rate = 0u64;
-        let mut best_sel = 0u32;
-        let mut best_div = 0u64;
-        let mut min_error = u64::MAX;
-
-        for &(parent_rate, sel_val) in &sources {
-            // 计算最佳分频值 (四舍五入)
-            let div = (parent_rate + rate_hz / 2) / rate_hz;
-
-            // 限制分频范围
-            let max_div = (div_mask >> div_shift) + 1;
-            let div = div.clamp(1, max_div as u64);
-
-            // 计算实际频率和误差
-            let actual_rate = parent_rate / div;
-            let error = actual_rate.abs_diff(rate_hz);
-
-            // 如果误差更小，则更新最佳配置
-            if error < min_error {
-                min_error = error;
-                best_parent_rate = parent_rate;
-                best_sel = sel_val;
-                best_div = div - 1; // 寄存器值 = div - 1
-            }
+        assert_eq!(cru.clk_set_rate(ACLK_ISP0, 594 * MHZ).unwrap(), 594 * MHZ);
+        assert_eq!(cru.clk_get_rate(ACLK_VICAP).unwrap(), 594 * MHZ);
+        assert_eq!(
+            cru.clk_set_rate(CLK_ISP1_CORE_MARVIN, 702 * MHZ).unwrap(),
+            702 * MHZ
+        );
+        assert_eq!(cru.clk_get_parent(CLK_ISP1_CORE).unwrap(), SPLL);
+    }
+
+    #[test]
+    fn test_pcie_power_up_sequence() {
+        let (sim, mut cru) = sim_cru();
+
+        // NVMe (PCIe3.0 x4): 保持复位，使能时钟，按顺序释放复位
+        cru.reset_assert(SRST_PCIE0_POWER_UP);
+        cru.reset_assert(SRST_P_PCIE0);
+        assert_eq!(sim.read(softrst_con(32) as usize) & (1 << 13), 1 << 13);
+        assert_eq!(sim.read(softrst_con(33) as usize) & (1 << 12), 1 << 12);
+
+        cru.clk_enable(ACLK_PCIE_4L_MSTR).unwrap();
+        cru.clk_enable(CLK_PCIE4L_PIPE).unwrap();
+        for id in [
+            ACLK_PCIE_4L_SLV,
+            ACLK_PCIE_4L_DBI,
+            PCLK_PCIE_4L,
+            CLK_PCIE_AUX0,
+            ACLK_MMU_PCIE,
+            ACLK_PCIE_ROOT,
+        ] {
+            assert!(cru.clk_enable_count(id).unwrap() > 0, "{id} not enabled");
         }
+        assert_eq!(cru.clk_enable_count(ACLK_PCIE_2L_MSTR).unwrap(), 0);
 
-        // 使用 Rockchip 写掩码机制配置寄存器
-        let mask = sel_mask | div_mask;
-        let value = (best_sel << sel_shift) | ((best_div as u32) << div_shift);
+        cru.reset_deassert(SRST_PCIE0_POWER_UP);
+        cru.reset_deassert(SRST_P_PCIE0);
+        assert_eq!(sim.read(softrst_con(32) as usize) & (1 << 13), 0);
+        assert_eq!(sim.read(softrst_con(33) as usize) & (1 << 12), 0);
 
-        self.clrsetreg(clksel_con(con_reg), mask, value);
+        assert_eq!(
+            cru.clk_set_rate(ACLK_PCIE_4L_MSTR, 300 * MHZ).unwrap(),
+            cru.clk_get_rate(ACLK_PCIE_ROOT).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_php_cru_gate_and_reset() {
+        let (sim, mut cru) = sim_cru();
+
+        cru.clk_enable(CLK_PHY0_REF_ALT_P).unwrap();
+        cru.clk_disable(CLK_PHY0_REF_ALT_P).unwrap();
+        assert_eq!(sim.read(php_clkgate_con(0) as usize), 1 << 5);
 
-        // 返回实际频率
-        let actual_rate = best_parent_rate / (best_div + 1);
-        Ok(actual_rate)
+        cru.reset_assert(SRST_PCIE30_PHY);
+        assert_eq!(sim.read(php_softrst_con(0) as usize), 1 << 10);
     }
 
-    // ========================================================================
-    // 根时钟
-    // ========================================================================
+    #[test]
+    fn test_audio_mclk_exact_rates() {
+        let (sim, mut cru) = sim_cru();
+
+        // 44.1kHz/48kHz 系列 MCLK 经 GPLL 小数分频精确得到
+        for (rate, n, m) in [(12_288_000, 128, 12375), (11_289_600, 196, 20625)] {
+            assert_eq!(cru.clk_set_rate(MCLK_I2S0_8CH_TX, rate).unwrap(), rate);
+            assert_eq!(
+                cru.clk_get_parent(CLK_I2S0_8CH_TX).unwrap(),
+                CLK_I2S0_8CH_TX_FRAC
+            );
+            assert_eq!(sim.read(clksel_con(25) as usize), (n << 16) | m);
+        }
 
-    /// 获取根时钟频率
-    ///
-    /// # Errors
-    ///
-    /// 如果时钟 ID 不支持，返回 `ClockError::UnsupportedClock`
-    pub(crate) fn root_clk_get_rate(&self, id: ClkId) -> ClockResult<u64> {
-        Ok(match id {
-            ACLK_BUS_ROOT => {
-                let clksel_38 = self.read(clksel_con(38));
-                let div = ((clksel_38 & 0x1F) + 1) as u64;
-                self.gpll_hz / div
-            }
-            ACLK_TOP_ROOT | ACLK_LOW_TOP_ROOT => 200 * MHZ,
-            PCLK_TOP_ROOT => 100 * MHZ,
-            ACLK_CENTER_ROOT | PCLK_CENTER_ROOT | HCLK_CENTER_ROOT | ACLK_CENTER_LOW_ROOT => {
-                self.gpll_hz / 2
-            }
-            _ => OSC_HZ,
-        })
+        // PMU 中的 I2S1 从 CPLL 小数分频
+        assert_eq!(
+            cru.clk_set_rate(MCLK_I2S1_8CH_TX, 12_288_000).unwrap(),
+            12_288_000
+        );
+
+        // 整数分频能精确得到时不使用小数分频 (AUPLL 未配置，为 24MHz)
+        assert_eq!(cru.clk_set_rate(CLK_SPDIF0, 12 * MHZ).unwrap(), 12 * MHZ);
+        assert_eq!(cru.clk_get_parent(CLK_SPDIF0).unwrap(), CLK_SPDIF0_SRC);
+    }
+
+    #[test]
+    fn test_audio_mclkout_to_io() {
+        let grf = Arc::new(RegFile::new(0x1000));
+        let mut cru = Cru::new_with_access(0, Arc::new(SimCru::with_uboot_defaults()), grf.clone());
+
+        // MCLK 输出到 IO 的门控在 SYS_GRF_SOC_CON6，置位为关闭
+        cru.clk_enable(I2S0_8CH_MCLKOUT_TO_IO).unwrap();
+        assert_eq!(grf.read(sys_grf_soc_con(6) as usize), 1 << 16);
+        assert!(cru.clk_enable_count(MCLK_I2S0_8CH_TX).unwrap() > 0);
+
+        assert_eq!(
+            cru.clk_set_rate(I2S0_8CH_MCLKOUT_TO_IO, 12_288_000)
+                .unwrap(),
+            12_288_000
+        );
+
+        cru.clk_disable(I2S0_8CH_MCLKOUT_TO_IO).unwrap();
+        assert_eq!(grf.read(sys_grf_soc_con(6) as usize), (1 << 16) | 1);
+    }
+
+    #[test]
+    fn test_sdmmc_clocks() {
+        let (sim, mut cru) = sim_cru();
+
+        // 卡识别阶段 400kHz: 24MHz / 60
+        assert_eq!(cru.clk_set_rate(CCLK_SRC_SDMMC, 400_000).unwrap(), 400_000);
+        assert_eq!(cru.clk_get_parent(CCLK_SRC_SDMMC).unwrap(), XIN24M);
+
+        // 高速模式 50MHz: GPLL / 24
+        assert_eq!(
+            cru.clk_set_rate(CCLK_SRC_SDMMC, 50 * MHZ).unwrap(),
+            49_500_000
+        );
+        assert_eq!(sim.read(secure_clksel_con(3) as usize), 23 << 6);
+
+        cru.clk_enable(CCLK_SRC_SDMMC).unwrap();
+        cru.clk_enable(HCLK_SDMMC).unwrap();
+        assert!(cru.clk_is_enabled(CCLK_SRC_SDMMC).unwrap());
+        cru.clk_disable(CCLK_SRC_SDMMC).unwrap();
+        assert_eq!(sim.read(secure_clkgate_con(1) as usize), 1 << 10);
+
+        // eMMC/SFC 总线时钟共享 hclk_nvm_root
+        let rate = cru.clk_set_rate(HCLK_EMMC, 100 * MHZ).unwrap();
+        assert_eq!(rate, cru.clk_get_rate(CLK_100M_SRC).unwrap());
+        assert_eq!(cru.clk_get_rate(HCLK_SFC).unwrap(), rate);
+    }
+
+    #[test]
+    fn test_root_clock_set_rate() {
+        let (sim, mut cru) = sim_cru();
+
+        // 低功耗: 总线根时钟降到 150MHz，CPLL / 10 比 GPLL / 8 更接近
+        assert_eq!(
+            cru.clk_set_rate(ACLK_BUS_ROOT, 150 * MHZ).unwrap(),
+            150 * MHZ
+        );
+        assert_eq!(sim.read(clksel_con(38) as usize) & 0x3f, (1 << 5) | 9);
+
+        assert_eq!(
+            cru.clk_set_rate(PCLK_TOP_ROOT, 50 * MHZ).unwrap(),
+            49_500_000
+        );
+        assert_eq!(cru.clk_get_rate(PCLK_I2C1).unwrap(), 49_500_000);
+
+        // DMA 负载: 提高 ACLK_TOP_ROOT 和 CENTER 根时钟
+        assert_eq!(
+            cru.clk_set_rate(ACLK_TOP_ROOT, 750 * MHZ).unwrap(),
+            750 * MHZ
+        );
+        assert_eq!(
+            cru.clk_set_rate(ACLK_CENTER_ROOT, 700 * MHZ).unwrap(),
+            cru.clk_get_rate(CLK_700M_SRC).unwrap()
+        );
+        assert_eq!(cru.clk_get_parent(ACLK_CENTER_ROOT).unwrap(), CLK_700M_SRC);
+        assert_eq!(
+            cru.clk_set_rate(ACLK_TOP_S400, 200 * MHZ).unwrap(),
+            198 * MHZ
+        );
+
+        // pclk_pmu0_root 随 pclk_pmu1_root 变化
+        assert_eq!(
+            cru.clk_set_rate(PCLK_PMU0_ROOT, 24 * MHZ).unwrap(),
+            24 * MHZ
+        );
+        assert_eq!(cru.clk_get_parent(PCLK_PMU1_ROOT).unwrap(), XIN24M);
+        assert_eq!(cru.clk_get_rate(PCLK_UART0).unwrap(), 24 * MHZ);
+    }
+
+    #[test]
+    fn test_usb_set_rate_moves_enable_count() {
+        let (_, mut cru) = sim_cru();
+
+        // GPLL / 6 = 198MHz 比 CPLL / 8 更接近 200MHz
+        assert_eq!(
+            cru.clk_set_rate(ACLK_USB_ROOT, 200 * MHZ).unwrap(),
+            198 * MHZ
+        );
+        assert_eq!(cru.clk_get_parent(ACLK_USB_ROOT).unwrap(), PLL_GPLL);
+        cru.clk_enable(ACLK_USB_ROOT).unwrap();
+        let gpll = cru.clk_enable_count(PLL_GPLL).unwrap();
+        let cpll = cru.clk_enable_count(PLL_CPLL).unwrap();
+
+        // 150MHz 只能由 CPLL / 10 精确得到，使能计数随父时钟转移
+        assert_eq!(
+            cru.clk_set_rate(ACLK_USB_ROOT, 150 * MHZ).unwrap(),
+            150 * MHZ
+        );
+        assert_eq!(cru.clk_get_parent(ACLK_USB_ROOT).unwrap(), PLL_CPLL);
+        assert_eq!(cru.clk_enable_count(PLL_GPLL).unwrap(), gpll - 1);
+        assert_eq!(cru.clk_enable_count(PLL_CPLL).unwrap(), cpll + 1);
+
+        cru.clk_disable(ACLK_USB_ROOT).unwrap();
+        assert_eq!(cru.clk_enable_count(PLL_GPLL).unwrap(), gpll - 1);
+        assert_eq!(cru.clk_enable_count(PLL_CPLL).unwrap(), cpll);
+
+        // HCLK_USB_ROOT 只有 mux
+        assert!(matches!(
+            cru.clk_set_rate(HCLK_USB_ROOT, 100 * MHZ),
+            Err(ClockError::UnsupportedClock { .. })
+        ));
+    }
+
+    #[test]
+    fn test_adc_usb_invalid_rates() {
+        let (_, mut cru) = sim_cru();
+
+        for id in [CLK_SARADC, CLK_TSADC, ACLK_USB_ROOT, CLK_UTMI_OTG2] {
+            assert!(
+                matches!(cru.clk_set_rate(id, 0), Err(ClockError::InvalidRate { .. })),
+                "{id}"
+            );
+        }
+
+        // 超过父时钟频率时不分频
+        assert_eq!(cru.clk_set_rate(CLK_SARADC, 2 * GPLL_HZ).unwrap(), GPLL_HZ);
+        assert_eq!(
+            cru.clk_set_rate(CLK_UTMI_OTG2, 2 * GPLL_HZ).unwrap(),
+            cru.clk_get_rate(CLK_150M_SRC).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_usb_adc_set_rate_keeps_pinned_parent() {
+        let (_, mut cru) = sim_cru();
+
+        // 未固定时 GPLL / 6 = 198MHz 更接近，固定到 CPLL 后只能用 CPLL / 8
+        cru.clk_set_parent(ACLK_USB_ROOT, PLL_CPLL).unwrap();
+        assert_eq!(
+            cru.clk_set_rate(ACLK_USB_ROOT, 200 * MHZ).unwrap(),
+            CPLL_HZ / 8
+        );
+        assert_eq!(cru.clk_get_parent(ACLK_USB_ROOT).unwrap(), PLL_CPLL);
+
+        cru.clk_set_parent(CLK_SARADC, XIN24M).unwrap();
+        assert_eq!(cru.clk_set_rate(CLK_SARADC, 12 * MHZ).unwrap(), 12 * MHZ);
+        assert_eq!(cru.clk_get_parent(CLK_SARADC).unwrap(), XIN24M);
     }
 }
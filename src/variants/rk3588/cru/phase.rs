@@ -0,0 +1,241 @@
+//! RK3588 SD/SDIO 采样和驱动时钟相位
+//!
+//! 参考 Linux: drivers/clk/rockchip/clk-mmc-phase.c
+//!
+//! SDMMC/SDIO 控制器 (dw_mmc) 的驱动时钟 (drv) 和采样时钟 (sample) 相位
+//! 由 CRU 中的 SDMMC_CON/SDIO_CON 寄存器控制，每个寄存器 [11:1] 为：
+//!
+//! - `[2:1]`: 粗调相位，0/90/180/270 度
+//! - `[10:3]`: 延迟线单元数，每个单元约 60ps
+//! - `[11]`: 使能延迟线
+//!
+//! 延迟线单元对应的角度与时钟频率有关，频率为 0 时无法换算。
+//! eMMC 控制器 (SDHCI) 使用自带的 DLL，不经过 CRU 调节相位
+
+use super::clock::*;
+use super::consts::*;
+use super::*;
+use crate::clock::{ClockError, ClockResult};
+
+/// 相位寄存器字段在寄存器中的偏移 (Linux: MMC() 的 shift 参数)
+const MMC_PHASE_SHIFT: u32 = 1;
+/// 相位字段掩码 (未移位)
+const MMC_PHASE_MASK: u32 = 0x7ff;
+
+const MMC_DELAY_SEL: u32 = 1 << 10;
+const MMC_DEGREE_MASK: u32 = 0x3;
+const MMC_DELAYNUM_OFFSET: u32 = 2;
+const MMC_DELAYNUM_MASK: u32 = 0xff << MMC_DELAYNUM_OFFSET;
+
+/// 每个延迟线单元的延迟 (ps)
+const MMC_DELAY_ELEMENT_PSEC: u64 = 60;
+
+/// 相位时钟对应的控制寄存器
+fn mmc_phase_reg(id: ClkId) -> ClockResult<u32> {
+    match id {
+        SCLK_SDMMC_DRV => Ok(RK3588_SDMMC_CON0),
+        SCLK_SDMMC_SAMPLE => Ok(RK3588_SDMMC_CON1),
+        SCLK_SDIO_DRV => Ok(RK3588_SDIO_CON0),
+        SCLK_SDIO_SAMPLE => Ok(RK3588_SDIO_CON1),
+        _ => Err(ClockError::unsupported(id)),
+    }
+}
+
+/// 由相位字段计算相位角度
+///
+/// 参考 Linux: rockchip_mmc_get_phase()
+fn phase_from_raw(raw: u32, rate: u64) -> u32 {
+    let mut degrees = (raw & MMC_DEGREE_MASK) as u64 * 90;
+    if raw & MMC_DELAY_SEL != 0 {
+        // 每个延迟单元对应的角度 * 1000000
+        let factor = (MMC_DELAY_ELEMENT_PSEC / 10) * 36 * (rate / 10000);
+        let delay_num = ((raw & MMC_DELAYNUM_MASK) >> MMC_DELAYNUM_OFFSET) as u64;
+        degrees += (delay_num * factor + 500_000) / 1_000_000;
+    }
+    (degrees % 360) as u32
+}
+
+/// 由相位角度计算相位字段
+///
+/// 参考 Linux: rockchip_mmc_set_phase()
+///
+/// 90 度的整数倍使用粗调，余下部分换算成延迟线单元数，最多 255 个
+fn raw_from_phase(degrees: u32, rate: u64) -> u32 {
+    let degrees = degrees % 360;
+    let nineties = degrees / 90;
+    let remainder = (degrees % 90) as u64;
+
+    // PSECS_PER_SEC / 10000 / 10
+    let delay = 10_000_000 * remainder;
+    let element = (rate / 1000) * 36 * (MMC_DELAY_ELEMENT_PSEC / 10);
+    let delay_num = ((delay + element / 2) / element).min(0xff) as u32;
+
+    let mut raw = if delay_num != 0 { MMC_DELAY_SEL } else { 0 };
+    raw |= delay_num << MMC_DELAYNUM_OFFSET;
+    raw | nineties
+}
+
+impl Cru {
+    /// 读取相位字段
+    fn mmc_phase_raw(&self, id: ClkId) -> ClockResult<u32> {
+        let reg = mmc_phase_reg(id)?;
+        Ok((self.read(reg) >> MMC_PHASE_SHIFT) & MMC_PHASE_MASK)
+    }
+
+    /// 写入相位字段
+    fn mmc_set_phase_raw(&mut self, id: ClkId, raw: u32) -> ClockResult<()> {
+        let reg = mmc_phase_reg(id)?;
+        self.clrsetreg(
+            reg,
+            MMC_PHASE_MASK << MMC_PHASE_SHIFT,
+            raw << MMC_PHASE_SHIFT,
+        );
+        Ok(())
+    }
+
+    /// 获取 SD/SDIO 采样或驱动时钟的相位
+    ///
+    /// # 参数
+    ///
+    /// * `id` - SCLK_SDMMC_DRV / SCLK_SDMMC_SAMPLE / SCLK_SDIO_DRV / SCLK_SDIO_SAMPLE
+    ///
+    /// # 返回
+    ///
+    /// 返回相位角度 (0-359)，时钟频率为 0 时返回 0
+    ///
+    /// # Errors
+    ///
+    /// 时钟 ID 不支持相位调节时返回 `ClockError::UnsupportedClock`
+    pub fn mmc_get_phase(&self, id: ClkId) -> ClockResult<u32> {
+        let raw = self.mmc_phase_raw(id)?;
+        let rate = self.clk_get_rate(id)?;
+        // 恒定电平没有可测量的相位
+        if rate == 0 {
+            return Ok(0);
+        }
+        Ok(phase_from_raw(raw, rate))
+    }
+
+    /// 设置 SD/SDIO 采样或驱动时钟的相位
+    ///
+    /// 延迟线单元数按当前时钟频率换算，修改频率后需要重新设置相位
+    ///
+    /// # 参数
+    ///
+    /// * `id` - SCLK_SDMMC_DRV / SCLK_SDMMC_SAMPLE / SCLK_SDIO_DRV / SCLK_SDIO_SAMPLE
+    /// * `degrees` - 目标相位角度，超过 360 时取模
+    ///
+    /// # 返回
+    ///
+    /// 返回设置后实际的相位角度
+    ///
+    /// # Errors
+    ///
+    /// 时钟 ID 不支持相位调节时返回 `ClockError::UnsupportedClock`，
+    /// 时钟频率为 0 时返回 `ClockError::InvalidRate`
+    pub fn mmc_set_phase(&mut self, id: ClkId, degrees: u32) -> ClockResult<u32> {
+        mmc_phase_reg(id)?;
+        let rate = self.clk_get_rate(id)?;
+        if rate == 0 {
+            return Err(ClockError::invalid_rate(id, rate));
+        }
+
+        let raw = raw_from_phase(degrees, rate);
+        debug!(
+            "{}: set phase {} degrees (raw {:#x}, {}Hz)",
+            id, degrees, raw, rate
+        );
+        self.mmc_set_phase_raw(id, raw)?;
+        Ok(phase_from_raw(raw, rate))
+    }
+
+    /// 获取延迟线单元数
+    ///
+    /// # 参数
+    ///
+    /// * `id` - SCLK_SDMMC_DRV / SCLK_SDMMC_SAMPLE / SCLK_SDIO_DRV / SCLK_SDIO_SAMPLE
+    ///
+    /// # 返回
+    ///
+    /// 返回当前使用的延迟线单元数，延迟线未使能时返回 0
+    ///
+    /// # Errors
+    ///
+    /// 时钟 ID 不支持相位调节时返回 `ClockError::UnsupportedClock`
+    pub fn mmc_get_delay_num(&self, id: ClkId) -> ClockResult<u8> {
+        let raw = self.mmc_phase_raw(id)?;
+        if raw & MMC_DELAY_SEL == 0 {
+            return Ok(0);
+        }
+        Ok(((raw & MMC_DELAYNUM_MASK) >> MMC_DELAYNUM_OFFSET) as u8)
+    }
+
+    /// 设置延迟线单元数，保持粗调相位不变
+    ///
+    /// 用于按单元逐步扫描采样点，`delay_num` 为 0 时关闭延迟线
+    ///
+    /// # 参数
+    ///
+    /// * `id` - SCLK_SDMMC_DRV / SCLK_SDMMC_SAMPLE / SCLK_SDIO_DRV / SCLK_SDIO_SAMPLE
+    /// * `delay_num` - 延迟线单元数
+    ///
+    /// # Errors
+    ///
+    /// 时钟 ID 不支持相位调节时返回 `ClockError::UnsupportedClock`
+    pub fn mmc_set_delay_num(&mut self, id: ClkId, delay_num: u8) -> ClockResult<()> {
+        let nineties = self.mmc_phase_raw(id)? & MMC_DEGREE_MASK;
+        let mut raw = nineties | ((delay_num as u32) << MMC_DELAYNUM_OFFSET);
+        if delay_num != 0 {
+            raw |= MMC_DELAY_SEL;
+        }
+        self.mmc_set_phase_raw(id, raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variants::rk3588::cru::sim::sim_cru;
+
+    #[test]
+    fn test_mmc_phase_raw() {
+        // 100MHz 时钟 (cclk 200MHz)：每个延迟单元约 2.16 度
+        let rate = 100 * MHZ;
+        assert_eq!(raw_from_phase(180, rate), 2);
+        assert_eq!(phase_from_raw(2, rate), 180);
+
+        // 45 度 = 0 * 90 + 21 个延迟单元 (45.36 度)
+        let raw = raw_from_phase(45, rate);
+        assert_eq!(raw, MMC_DELAY_SEL | (21 << MMC_DELAYNUM_OFFSET));
+        assert_eq!(phase_from_raw(raw, rate), 45);
+
+        // 超过 360 度时取模
+        assert_eq!(raw_from_phase(450, rate), raw_from_phase(90, rate));
+
+        // 低频时延迟单元数最多 255 个
+        let raw = raw_from_phase(89, 200_000);
+        assert_eq!((raw & MMC_DELAYNUM_MASK) >> MMC_DELAYNUM_OFFSET, 0xff);
+    }
+
+    #[test]
+    fn test_mmc_phase() {
+        let (sim, mut cru) = sim_cru();
+
+        // cclk 198MHz，相位时钟 99MHz
+        cru.clk_set_rate(CCLK_SRC_SDMMC, 200 * MHZ).unwrap();
+        assert_eq!(cru.clk_get_rate(SCLK_SDMMC_SAMPLE).unwrap(), 99 * MHZ);
+
+        assert_eq!(cru.mmc_set_phase(SCLK_SDMMC_SAMPLE, 90).unwrap(), 90);
+        assert_eq!(sim.read(RK3588_SDMMC_CON1 as usize), 1 << 1);
+        assert_eq!(cru.mmc_get_delay_num(SCLK_SDMMC_SAMPLE).unwrap(), 0);
+
+        // 每个延迟单元约 2.14 度
+        cru.mmc_set_delay_num(SCLK_SDMMC_SAMPLE, 10).unwrap();
+        assert_eq!(cru.mmc_get_delay_num(SCLK_SDMMC_SAMPLE).unwrap(), 10);
+        assert_eq!(cru.mmc_get_phase(SCLK_SDMMC_SAMPLE).unwrap(), 111);
+
+        // 驱动时钟独立配置
+        assert_eq!(cru.mmc_get_phase(SCLK_SDMMC_DRV).unwrap(), 0);
+        assert!(cru.mmc_set_phase(CCLK_EMMC, 90).is_err());
+    }
+}
@@ -6,7 +6,30 @@ use super::{ClockError, ClockResult, Cru, consts::*};
 use crate::clock::{ClkId, pll::*};
 
 /// PLL 模式掩码
-const PLL_MODE_MASK: u32 = 0x3;
+pub(crate) const PLL_MODE_MASK: u32 = 0x3;
+
+/// VCO 频率范围 (参考 Linux: drivers/clk/rockchip/clk-pll.c)
+const PLL_VCO_MIN_HZ: u64 = 2250 * MHZ;
+const PLL_VCO_MAX_HZ: u64 = 4500 * MHZ;
+
+/// 输出频率范围
+const PLL_FOUT_MIN_HZ: u64 = 37_500_000;
+const PLL_FOUT_MAX_HZ: u64 = 4500 * MHZ;
+
+/// P/M/S 合法取值范围
+///
+/// P 寄存器有 6 位，这里限制鉴相频率 (24MHz / P) 不低于 6MHz
+const PLL_P_MIN: u32 = 1;
+const PLL_P_MAX: u32 = 4;
+const PLL_M_MIN: u32 = 64;
+const PLL_M_MAX: u32 = 1023;
+const PLL_S_MAX: u32 = 6;
+
+/// K 小数部分的分母 (16 位)
+const PLL_FRAC_DEN: u64 = 65536;
+
+/// PLL 锁定超时 (参考 Linux: rockchip_rk3588_pll_wait_lock() 的 1000us)
+pub(crate) const PLL_LOCK_TIMEOUT_US: u64 = 1000;
 
 /// RK3588 PLL 时钟 ID
 ///
@@ -196,9 +219,9 @@ impl Cru {
         let mode_con = self.read(pll_cfg.mode_offset);
         let mode_shift = pll_cfg.mode_shift;
 
-        // PPLL (ID=8) 特殊处理: 始终认为是 NORMAL 模式
-        let pll_id_val = pll_id as u32;
-        let mode = if pll_id_val == 8 {
+        // PPLL 特殊处理: 始终认为是 NORMAL 模式
+        // (u-boot 中 PPLL 的枚举值为 8，这里的 PllId 从 1 开始)
+        let mode = if pll_id == PllId::PPLL {
             pll_mode::PLL_MODE_NORMAL
         } else {
             (mode_con & (PLL_MODE_MASK << mode_shift)) >> mode_shift
@@ -364,14 +387,12 @@ impl Cru {
             (p << pllcon1::P_SHIFT) | (s << pllcon1::S_SHIFT),
         );
 
-        // 写入 K (16 bits, 如果有小数分频)
-        if k != 0 {
-            self.clrsetreg(
-                pll_cfg.con_offset + pll_con(2),
-                pllcon2::K_MASK,
-                k << pllcon2::K_SHIFT,
-            );
-        }
+        // 写入 K (16 bits)，整数分频时清零，避免残留上一次的小数部分
+        self.clrsetreg(
+            pll_cfg.con_offset + pll_con(2),
+            pllcon2::K_MASK,
+            k << pllcon2::K_SHIFT,
+        );
 
         debug!("{}: PLL parameters written", pll_id.name());
 
@@ -389,29 +410,24 @@ impl Cru {
         //             udelay(1);
         //         }
         // ========================================================================
-        let mut timeout = 1000; // 1ms timeout (1000 * 1us)
-        let con6_addr = pll_cfg.con_offset + pll_con(6);
+        let mut waited_us = 0;
 
-        while self.read(con6_addr) & pllcon6::LOCK_STATUS == 0 {
-            if timeout == 0 {
+        while !pll_cfg.is_locked(self.regs.as_ref()) {
+            if waited_us >= PLL_LOCK_TIMEOUT_US {
                 log::error!("⚠️ {}: PLL lock timeout!", pll_id.name());
+                if let Ok(rate) = self.pll_get_rate(pll_id) {
+                    self.pll_update_cache(pll_id, rate);
+                }
                 return Err(ClockError::pll_config_error(
                     crate::clock::ClkId::from(pll_id as u32),
                     "PLL lock timeout",
                 ));
             }
-            // 简单延迟循环 (裸机环境)
-            for _ in 0..100 {
-                core::hint::spin_loop();
-            }
-            timeout -= 1;
+            self.delay.delay_us(1);
+            waited_us += 1;
         }
 
-        debug!(
-            "{}: PLL locked after {} attempts",
-            pll_id.name(),
-            1000 - timeout
-        );
+        debug!("{}: PLL locked after {}us", pll_id.name(), waited_us);
 
         // ========================================================================
         // 7. 切换到 NORMAL 模式
@@ -431,6 +447,7 @@ impl Cru {
         // 8. 验证实际输出频率
         // ========================================================================
         let actual_rate = self.pll_get_rate(pll_id)?;
+        self.pll_update_cache(pll_id, actual_rate);
 
         log::info!(
             "✓ CRU@{:x}: {} set to {}MHz (requested: {}MHz)",
@@ -442,6 +459,16 @@ impl Cru {
 
         Ok(actual_rate)
     }
+
+    /// 更新 CPLL/GPLL/PPLL 的缓存频率，外设时钟按缓存频率计算分频
+    fn pll_update_cache(&mut self, pll_id: PllId, rate_hz: u64) {
+        match pll_id {
+            PllId::CPLL => self.cpll_hz = rate_hz,
+            PllId::GPLL => self.gpll_hz = rate_hz,
+            PllId::PPLL => self.ppll_hz = rate_hz,
+            _ => {}
+        }
+    }
 }
 
 /// 创建 RK3588 PLL 速率表项
@@ -526,8 +553,94 @@ pub const fn calc_pll_rate(fin: u64, p: u32, m: u32, s: u32, k: u32) -> u64 {
     }
 }
 
+/// PLL 参数求解结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PllSolution {
+    /// P 分频系数
+    pub p: u32,
+    /// M 分频系数
+    pub m: u32,
+    /// S 分频系数
+    pub s: u32,
+    /// K 小数分频系数
+    pub k: u32,
+    /// 按硬件公式计算的实际输出频率 (Hz)
+    pub rate: u64,
+    /// 与目标频率的误差 (Hz)
+    pub error_hz: u64,
+}
+
+/// 搜索 PLL 参数
+///
+/// 参考 Linux: drivers/clk/rockchip/clk-pll.c:rockchip_rk3588_pll_frac_by_auto()
+///
+/// 在 VCO 范围内遍历 S 和 P，由 VCO 频率求出整数部分 M，余数换算为 16 位的
+/// 小数部分 K。能整数分频时优先返回 K=0 的解，否则返回误差最小的解，
+/// 误差相同时优先选择较小的 P (鉴相频率更高，抖动更小)
+///
+/// # 参数
+///
+/// * `rate_hz` - 目标频率 (Hz)
+///
+/// # 返回
+///
+/// 返回最优参数及其误差，目标频率超出 PLL 输出范围时返回 None
+#[must_use]
+pub fn solve_pll_params(rate_hz: u64) -> Option<PllSolution> {
+    if !(PLL_FOUT_MIN_HZ..=PLL_FOUT_MAX_HZ).contains(&rate_hz) {
+        return None;
+    }
+
+    let fin = OSC_HZ;
+    let mut best: Option<PllSolution> = None;
+
+    for s in 0..=PLL_S_MAX {
+        let fvco = rate_hz << s;
+        if !(PLL_VCO_MIN_HZ..=PLL_VCO_MAX_HZ).contains(&fvco) {
+            continue;
+        }
+
+        for p in PLL_P_MIN..=PLL_P_MAX {
+            // fvco = fin / p * (m + k / 65536)
+            let m = (fvco * p as u64 / fin) as u32;
+            let rem = fvco * p as u64 - m as u64 * fin;
+            let mut k = ((rem * PLL_FRAC_DEN + fin / 2) / fin) as u32;
+            let mut m = m;
+            if k as u64 == PLL_FRAC_DEN {
+                m += 1;
+                k = 0;
+            }
+            if !(PLL_M_MIN..=PLL_M_MAX).contains(&m) {
+                continue;
+            }
+
+            let rate = calc_pll_rate(fin, p, m, s, k);
+            let candidate = PllSolution {
+                p,
+                m,
+                s,
+                k,
+                rate,
+                error_hz: rate.abs_diff(rate_hz),
+            };
+
+            let better = match best {
+                None => true,
+                Some(b) => (candidate.error_hz, candidate.k != 0) < (b.error_hz, b.k != 0),
+            };
+            if better {
+                best = Some(candidate);
+            }
+        }
+    }
+
+    best
+}
+
 /// 查找或计算 PLL 参数
 ///
+/// 优先使用预设频率表，表中没有时通过 [`solve_pll_params`] 求解
+///
 /// # 参数
 ///
 /// * `pll_id` - PLL ID
@@ -553,52 +666,42 @@ pub fn find_pll_params(pll_id: PllId, rate_hz: u64) -> Result<(u32, u32, u32, u3
         }
     }
 
-    // 2. 如果预设表没有,尝试简单计算 (仅支持整数分频)
-    // 公式: fout = ((fin / p) * m) >> s
-    // 简化: 设 p=2, s=1, 则 fout = (fin / 2 * m) >> 1 = fin * m / 4
-    // 因此: m = fout * 4 / fin
-
-    let fin = OSC_HZ;
-    let target_vco = rate_hz * 4; // 假设 s=2 (后分频4)
-
-    // 检查 VCO 频率范围
-    const VCO_MIN_HZ: u64 = 2250 * MHZ;
-    const VCO_MAX_HZ: u64 = 4500 * MHZ;
-
-    if !(VCO_MIN_HZ..=VCO_MAX_HZ).contains(&target_vco) {
-        return Err("Target frequency out of VCO range");
-    }
-
-    // 计算参数: p=2, s=2 (后分频4)
-    let p = 2u32;
-    let s = 2u32;
-    let m = ((rate_hz << s) / (fin / p as u64)) as u32;
-    let k = 0u32; // 暂不支持小数分频计算
+    // 2. 预设表没有时搜索 P/M/S/K
+    let sol = solve_pll_params(rate_hz).ok_or("Target frequency out of VCO range")?;
 
     // 验证计算结果
-    let check_rate = calc_pll_rate(fin, p, m, s, k);
     let tolerance = rate_hz / 1000; // 0.1% 容差
-
-    if check_rate.abs_diff(rate_hz) > tolerance {
+    if sol.error_hz > tolerance {
         return Err("Cannot calculate accurate PLL parameters");
     }
 
-    log::warn!(
-        "⚠️ {}: No preset rate table entry for {}MHz, calculated: p={}, m={}, s={}, k={}",
+    debug!(
+        "{}: no preset rate table entry for {}Hz, calculated: p={}, m={}, s={}, k={}, error={}Hz",
         pll_id.name(),
-        rate_hz / MHZ,
-        p,
-        m,
-        s,
-        k
+        rate_hz,
+        sol.p,
+        sol.m,
+        sol.s,
+        sol.k,
+        sol.error_hz
     );
 
-    Ok((p, m, s, k))
+    Ok((sol.p, sol.m, sol.s, sol.k))
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::sync::Arc;
+
     use super::*;
+    use crate::{
+        delay::FakeDelay,
+        regmap::{RegAccess, RegFile},
+        variants::rk3588::cru::{
+            clock::*,
+            sim::{SimCru, sim_cru},
+        },
+    };
 
     #[test]
     fn test_pll_rate_table_count() {
@@ -622,6 +725,58 @@ mod tests {
         assert_eq!(rate, 786_431_991);
     }
 
+    #[test]
+    fn test_solve_pll_params_integer() {
+        // 148.5MHz (1080p60 像素时钟): VCO = 148.5 * 16 = 2376MHz = 24MHz * 99
+        let sol = solve_pll_params(148_500_000).unwrap();
+        assert_eq!(sol.k, 0);
+        assert_eq!(sol.error_hz, 0);
+        assert_eq!(sol.rate, 148_500_000);
+        assert_eq!(calc_pll_rate(OSC_HZ, sol.p, sol.m, sol.s, sol.k), sol.rate);
+    }
+
+    #[test]
+    fn test_solve_pll_params_fractional() {
+        // 786.432MHz (48kHz * 16384): 需要小数分频
+        let sol = solve_pll_params(786_432_000).unwrap();
+        assert_ne!(sol.k, 0);
+        assert!(sol.error_hz < 10, "error {}Hz too large", sol.error_hz);
+        assert_eq!(calc_pll_rate(OSC_HZ, sol.p, sol.m, sol.s, sol.k), sol.rate);
+
+        // 722.5344MHz (44.1kHz * 16384)
+        let sol = solve_pll_params(722_534_400).unwrap();
+        assert!(sol.error_hz < 10, "error {}Hz too large", sol.error_hz);
+    }
+
+    #[test]
+    fn test_solve_pll_params_limits() {
+        assert!(solve_pll_params(10 * MHZ).is_none());
+        assert!(solve_pll_params(5000 * MHZ).is_none());
+
+        // 所有解都必须落在合法范围内
+        for rate in [37_500_000, 100 * MHZ, 594 * MHZ, 1_000_000_007, 4500 * MHZ] {
+            let sol = solve_pll_params(rate).unwrap();
+            assert!((PLL_P_MIN..=PLL_P_MAX).contains(&sol.p));
+            assert!((PLL_M_MIN..=PLL_M_MAX).contains(&sol.m));
+            assert!(sol.s <= PLL_S_MAX);
+            assert!((sol.k as u64) < PLL_FRAC_DEN);
+            let fvco = rate << sol.s;
+            assert!((PLL_VCO_MIN_HZ..=PLL_VCO_MAX_HZ).contains(&fvco));
+        }
+    }
+
+    #[test]
+    fn test_find_pll_params_solver() {
+        // 不在预设表中的频率由求解器计算
+        let (p, m, s, k) = find_pll_params(PllId::AUPLL, 1_128_960_000).unwrap();
+        let rate = calc_pll_rate(OSC_HZ, p, m, s, k);
+        // K 的分辨率为 24MHz / P / 65536，误差在几十 Hz 以内
+        assert!(rate.abs_diff(1_128_960_000) < 50);
+
+        let (p, m, s, k) = find_pll_params(PllId::V0PLL, 148_500_000).unwrap();
+        assert_eq!(calc_pll_rate(OSC_HZ, p, m, s, k), 148_500_000);
+    }
+
     #[test]
     fn test_pll_count() {
         // RK3588 应该有 9 个 PLL
@@ -1076,4 +1231,49 @@ mod tests {
             "Round-trip conversion should preserve PllId"
         );
     }
+
+    #[test]
+    fn test_pll_set_rate_updates_cache() {
+        let (_, mut cru) = sim_cru();
+
+        // GPLL 改为 1GHz 后，外设按新频率计算分频: 1000MHz / 5
+        assert_eq!(cru.clk_set_rate(PLL_GPLL, 1000 * MHZ).unwrap(), 1000 * MHZ);
+        assert_eq!(
+            cru.clk_set_rate(ACLK_USB_ROOT, 200 * MHZ).unwrap(),
+            200 * MHZ
+        );
+        assert_eq!(cru.clk_get_rate(ACLK_USB_ROOT).unwrap(), 200 * MHZ);
+    }
+
+    /// PLL 始终不锁定的 CRU
+    struct NeverLock(SimCru);
+
+    impl RegAccess for NeverLock {
+        fn read(&self, offset: usize) -> u32 {
+            let value = self.0.read(offset);
+            match SimCru::lock_reg_pll(offset) {
+                Some(_) => value & !pllcon6::LOCK_STATUS,
+                None => value,
+            }
+        }
+
+        fn write(&self, offset: usize, value: u32) {
+            self.0.write(offset, value);
+        }
+    }
+
+    #[test]
+    fn test_pll_lock_timeout() {
+        let delay = Arc::new(FakeDelay::new());
+        let mut cru = Cru::from_access(
+            0,
+            Arc::new(NeverLock(SimCru::with_uboot_defaults())),
+            Arc::new(RegFile::new(0x1000)),
+            delay.clone(),
+        );
+
+        // 超时按延时后端计时，与 CPU 频率无关
+        assert!(cru.clk_set_rate(PLL_NPLL, 850 * MHZ).is_err());
+        assert_eq!(delay.elapsed_us(), PLL_LOCK_TIMEOUT_US);
+    }
 }
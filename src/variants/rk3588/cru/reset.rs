@@ -0,0 +1,312 @@
+//! RK3588 复位 ID 定义
+//!
+//! 参考 u-boot: include/dt-bindings/reset/rockchip,rk3588-cru.h
+//!
+//! 复位 ID 按寄存器编号：`ID = SOFTRST_CON 编号 * 16 + bit`，
+//! 与 [`ResetRockchip`](crate::ResetRockchip) 的寄存器计算方式一致。
+//! PHP CRU 的复位 ID 加上 [`PHP_RST_BASE`]，使计算出的寄存器落在 php_softrst_con
+
+#![allow(dead_code)]
+
+use super::consts::RK3588_PHP_CRU_BASE;
+use crate::rst::RstId;
+
+/// PHP CRU 复位 ID 的起点
+///
+/// php_softrst_con 与主 CRU softrst_con 相距 `RK3588_PHP_CRU_BASE` 字节，
+/// 每个寄存器 16 个复位 (参考 Linux: RK3588_PHPTOPCRU_RESET_OFFSET)
+pub const PHP_RST_BASE: u64 = RK3588_PHP_CRU_BASE as u64 / 4 * 16;
+
+/// 批量定义复位 ID 常量
+macro_rules! rst_id_group {
+    ($($name:ident = ($con:expr, $bit:expr)),* $(,)?) => {
+        rst_id_group!(@base 0, $($name = ($con, $bit)),*);
+    };
+    (php: $($name:ident = ($con:expr, $bit:expr)),* $(,)?) => {
+        rst_id_group!(@base PHP_RST_BASE, $($name = ($con, $bit)),*);
+    };
+    (@base $base:expr, $($name:ident = ($con:expr, $bit:expr)),*) => {
+        $(
+            pub const $name: RstId = RstId::new($base + $con * 16 + $bit);
+        )*
+    };
+}
+
+// =============================================================================
+// NPU 复位 ID
+// =============================================================================
+
+// SOFTRST_CON27
+rst_id_group!(
+    SRST_A_RKNN1 = (27, 0),
+    SRST_A_RKNN1_BIU = (27, 2),
+    SRST_H_RKNN1 = (27, 4),
+    SRST_H_RKNN1_BIU = (27, 6),
+);
+
+// SOFTRST_CON28
+rst_id_group!(
+    SRST_A_RKNN2 = (28, 0),
+    SRST_A_RKNN2_BIU = (28, 2),
+    SRST_H_RKNN2 = (28, 4),
+    SRST_H_RKNN2_BIU = (28, 6),
+);
+
+// SOFTRST_CON29
+rst_id_group!(
+    SRST_A_RKNN_DSU0 = (29, 3),
+    SRST_P_NPUTOP_BIU = (29, 5),
+    SRST_P_NPU_TIMER = (29, 6),
+    SRST_NPUTIMER0 = (29, 8),
+    SRST_NPUTIMER1 = (29, 9),
+    SRST_P_NPU_WDT = (29, 10),
+    SRST_T_NPU_WDT = (29, 11),
+    SRST_P_NPU_PVTM = (29, 12),
+    SRST_P_NPU_GRF = (29, 13),
+    SRST_NPU_PVTM = (29, 14),
+);
+
+// SOFTRST_CON30
+rst_id_group!(
+    SRST_NPU_PVTPLL = (30, 0),
+    SRST_H_NPU_CM0_BIU = (30, 2),
+    SRST_F_NPU_CM0_CORE = (30, 3),
+    SRST_T_NPU_CM0_JTAG = (30, 4),
+    SRST_A_RKNN0 = (30, 6),
+    SRST_A_RKNN0_BIU = (30, 8),
+    SRST_H_RKNN0 = (30, 10),
+    SRST_H_RKNN0_BIU = (30, 12),
+);
+
+// =============================================================================
+// GPU 复位 ID
+// =============================================================================
+
+// SOFTRST_CON66
+rst_id_group!(
+    SRST_GPU = (66, 4),
+    SRST_A_S_GPU_BIU = (66, 7),
+    SRST_A_M0_GPU_BIU = (66, 8),
+    SRST_A_M1_GPU_BIU = (66, 9),
+    SRST_A_M2_GPU_BIU = (66, 10),
+    SRST_A_M3_GPU_BIU = (66, 11),
+    SRST_P_GPU_BIU = (66, 14),
+    SRST_P_GPU_PVTM = (66, 15),
+);
+
+// SOFTRST_CON67
+rst_id_group!(
+    SRST_GPU_PVTM = (67, 0),
+    SRST_P_GPU_GRF = (67, 2),
+    SRST_GPU_PVTPLL = (67, 3),
+    SRST_GPU_JTAG = (67, 4),
+);
+
+// SOFTRST_CON68
+rst_id_group!(
+    SRST_A_AV1_BIU = (68, 1),
+    SRST_A_AV1 = (68, 2),
+    SRST_P_AV1_BIU = (68, 4),
+    SRST_P_AV1 = (68, 5),
+);
+
+// =============================================================================
+// PHP (PCIe/SATA/GMAC/USB3) 复位 ID
+// =============================================================================
+//
+// PCIe 控制器上电顺序 (参考 Linux: pcie-dw-rockchip.c)：
+// 1. 保持 SRST_PCIEn_POWER_UP 和 SRST_P_PCIEn
+// 2. 初始化 PHY，使能控制器时钟 (包括来自 PHY 的 PIPE 时钟)
+// 3. 先释放 SRST_PCIEn_POWER_UP，再释放 SRST_P_PCIEn
+//
+// 控制器编号: 0 = PCIe3.0 x4, 1 = PCIe3.0 x2, 2~4 = PCIe2.0 x1 (1L0~1L2)
+
+// SOFTRST_CON32
+rst_id_group!(
+    SRST_A_PCIE_BRIDGE = (32, 8),
+    SRST_A_GMAC0 = (32, 10),
+    SRST_A_GMAC1 = (32, 11),
+    SRST_PCIE0_POWER_UP = (32, 13),
+    SRST_PCIE1_POWER_UP = (32, 14),
+    SRST_PCIE2_POWER_UP = (32, 15),
+);
+
+// SOFTRST_CON33
+rst_id_group!(
+    SRST_PCIE3_POWER_UP = (33, 0),
+    SRST_PCIE4_POWER_UP = (33, 1),
+    SRST_P_PCIE0 = (33, 12),
+    SRST_P_PCIE1 = (33, 13),
+    SRST_P_PCIE2 = (33, 14),
+    SRST_P_PCIE3 = (33, 15),
+);
+
+// SOFTRST_CON34
+rst_id_group!(
+    SRST_P_PCIE4 = (34, 0),
+    SRST_A_PHP_GIC_ITS = (34, 6),
+    SRST_A_MMU_PCIE = (34, 7),
+    SRST_A_MMU_PHP = (34, 8),
+    SRST_A_MMU_BIU = (34, 9),
+);
+
+// SOFTRST_CON35
+rst_id_group!(SRST_A_USB3OTG2 = (35, 7),);
+
+// SOFTRST_CON37
+rst_id_group!(
+    SRST_PMALIVE0 = (37, 4),
+    SRST_PMALIVE1 = (37, 5),
+    SRST_PMALIVE2 = (37, 6),
+    SRST_A_SATA0 = (37, 7),
+    SRST_A_SATA1 = (37, 8),
+    SRST_A_SATA2 = (37, 9),
+    SRST_RXOOB0 = (37, 10),
+    SRST_RXOOB1 = (37, 11),
+    SRST_RXOOB2 = (37, 12),
+    SRST_ASIC0 = (37, 13),
+    SRST_ASIC1 = (37, 14),
+    SRST_ASIC2 = (37, 15),
+);
+
+// PHPTOPCRU_SOFTRST_CON00
+rst_id_group!(php:
+    SRST_P_PHPTOP_CRU = (0, 1),
+    SRST_P_PCIE2_GRF0 = (0, 2),
+    SRST_P_PCIE2_GRF1 = (0, 3),
+    SRST_P_PCIE2_GRF2 = (0, 4),
+    SRST_P_PCIE2_PHY0 = (0, 5),
+    SRST_P_PCIE2_PHY1 = (0, 6),
+    SRST_P_PCIE2_PHY2 = (0, 7),
+    SRST_P_PCIE3_PHY = (0, 8),
+    SRST_P_APB2ASB_SLV_CHIP_TOP = (0, 9),
+    SRST_PCIE30_PHY = (0, 10),
+);
+
+// =============================================================================
+// 摄像头 (VI/ISP) 复位 ID
+// =============================================================================
+
+// SOFTRST_CON26
+rst_id_group!(
+    SRST_ISP1 = (26, 3),
+    SRST_ISP1_VICAP = (26, 4),
+    SRST_A_ISP1_BIU = (26, 6),
+    SRST_H_ISP1_BIU = (26, 8),
+);
+
+// SOFTRST_CON49
+rst_id_group!(
+    SRST_A_VI_BIU = (49, 3),
+    SRST_H_VI_BIU = (49, 4),
+    SRST_P_VI_BIU = (49, 5),
+    SRST_D_VICAP = (49, 6),
+    SRST_A_VICAP = (49, 7),
+    SRST_H_VICAP = (49, 8),
+    SRST_ISP0 = (49, 10),
+    SRST_ISP0_VICAP = (49, 11),
+);
+
+// SOFTRST_CON50
+rst_id_group!(
+    SRST_FISHEYE0 = (50, 0),
+    SRST_FISHEYE1 = (50, 3),
+    SRST_P_CSI_HOST_0 = (50, 4),
+    SRST_P_CSI_HOST_1 = (50, 5),
+    SRST_P_CSI_HOST_2 = (50, 6),
+    SRST_P_CSI_HOST_3 = (50, 7),
+    SRST_P_CSI_HOST_4 = (50, 8),
+    SRST_P_CSI_HOST_5 = (50, 9),
+);
+
+// SOFTRST_CON51
+rst_id_group!(
+    SRST_CSIHOST0_VICAP = (51, 4),
+    SRST_CSIHOST1_VICAP = (51, 5),
+    SRST_CSIHOST2_VICAP = (51, 6),
+    SRST_CSIHOST3_VICAP = (51, 7),
+    SRST_CSIHOST4_VICAP = (51, 8),
+    SRST_CSIHOST5_VICAP = (51, 9),
+);
+
+// =============================================================================
+// 视频编解码复位 ID
+// =============================================================================
+
+// SOFTRST_CON40
+rst_id_group!(
+    SRST_A_RKVDEC_CCU = (40, 2),
+    SRST_H_RKVDEC0 = (40, 3),
+    SRST_A_RKVDEC0 = (40, 4),
+    SRST_H_RKVDEC0_BIU = (40, 5),
+    SRST_A_RKVDEC0_BIU = (40, 6),
+    SRST_RKVDEC0_CA = (40, 7),
+    SRST_RKVDEC0_HEVC_CA = (40, 8),
+    SRST_RKVDEC0_CORE = (40, 9),
+);
+
+// SOFTRST_CON41
+rst_id_group!(
+    SRST_H_RKVDEC1 = (41, 2),
+    SRST_A_RKVDEC1 = (41, 3),
+    SRST_H_RKVDEC1_BIU = (41, 4),
+    SRST_A_RKVDEC1_BIU = (41, 5),
+    SRST_RKVDEC1_CA = (41, 6),
+    SRST_RKVDEC1_HEVC_CA = (41, 7),
+    SRST_RKVDEC1_CORE = (41, 8),
+);
+
+// SOFTRST_CON44
+rst_id_group!(
+    SRST_A_VDPU_BIU = (44, 4),
+    SRST_A_VDPU_LOW_BIU = (44, 5),
+    SRST_H_VDPU_BIU = (44, 6),
+    SRST_A_JPEG_DECODER_BIU = (44, 7),
+    SRST_A_VPU = (44, 8),
+    SRST_H_VPU = (44, 9),
+    SRST_A_JPEG_ENCODER0 = (44, 10),
+    SRST_H_JPEG_ENCODER0 = (44, 11),
+    SRST_A_JPEG_ENCODER1 = (44, 12),
+    SRST_H_JPEG_ENCODER1 = (44, 13),
+    SRST_A_JPEG_ENCODER2 = (44, 14),
+    SRST_H_JPEG_ENCODER2 = (44, 15),
+);
+
+// SOFTRST_CON45
+rst_id_group!(
+    SRST_A_JPEG_ENCODER3 = (45, 0),
+    SRST_H_JPEG_ENCODER3 = (45, 1),
+    SRST_A_JPEG_DECODER = (45, 2),
+    SRST_H_JPEG_DECODER = (45, 3),
+);
+
+// SOFTRST_CON47
+rst_id_group!(
+    SRST_H_RKVENC0_BIU = (47, 2),
+    SRST_A_RKVENC0_BIU = (47, 3),
+    SRST_H_RKVENC0 = (47, 4),
+    SRST_A_RKVENC0 = (47, 5),
+    SRST_CORE_RKVENC0 = (47, 6),
+);
+
+// SOFTRST_CON48
+rst_id_group!(
+    SRST_H_RKVENC1_BIU = (48, 2),
+    SRST_A_RKVENC1_BIU = (48, 3),
+    SRST_H_RKVENC1 = (48, 4),
+    SRST_A_RKVENC1 = (48, 5),
+    SRST_CORE_RKVENC1 = (48, 6),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rst_id_encoding() {
+        assert_eq!(SRST_A_RKNN1.value(), 27 * 16);
+        assert_eq!(SRST_A_RKNN0.value(), 30 * 16 + 6);
+        assert_eq!(SRST_H_RKNN0_BIU.value(), 492);
+        assert_eq!(SRST_PCIE30_PHY.value(), 0x8000 * 4 + 10);
+    }
+}
@@ -0,0 +1,354 @@
+//! RK3588 CRU 寄存器模拟
+//!
+//! 在主机上模拟 CRU 寄存器块中本驱动会用到的硬件行为，
+//! 使 `init`、`pll_set_rate` 以及各 `*_set_rate` 路径无需开发板即可测试：
+//!
+//! - 写掩码: 高 16 位为写使能位，只更新对应的低 16 位 (clksel/gate/softrst/mode/PLLCON)
+//! - 小数分频寄存器: 按 32 位原样写入
+//! - PLL 锁定: PLLCON1 的 PWRDOWN 清零后，PLLCON6 的 LOCK_STATUS 置位
+//!
+//! # 示例
+//!
+//! ```rust,ignore
+//! let cru = Cru::new_with_access(
+//!     SocType::Rk3588,
+//!     0,
+//!     Arc::new(SimCru::with_uboot_defaults()),
+//!     Arc::new(RegFile::new(0x1000)),
+//! );
+//! ```
+
+use alloc::collections::BTreeSet;
+
+use super::{
+    clock::*,
+    consts::*,
+    pll::{PLL_MODE_MASK, PllId, get_pll},
+    tree::clk_nodes,
+};
+use crate::{
+    clock::{
+        ClkId,
+        tree::{ClkKind, DivKind},
+    },
+    regmap::{RegAccess, RegFile},
+};
+
+/// CRU 寄存器空间大小 (覆盖 DSU CRU)
+pub const CRU_SIZE: usize = 0x5c000;
+
+/// 所有 PLL
+const PLLS: [PllId; 9] = [
+    PllId::B0PLL,
+    PllId::B1PLL,
+    PllId::LPLL,
+    PllId::V0PLL,
+    PllId::AUPLL,
+    PllId::CPLL,
+    PllId::GPLL,
+    PllId::NPLL,
+    PllId::PPLL,
+];
+
+/// 公共时钟源的标称频率 (u-boot 启动后均选择 GPLL)
+const SRC_RATES: [(ClkId, u64); 16] = [
+    (CLK_50M_SRC, 50 * MHZ),
+    (CLK_100M_SRC, 100 * MHZ),
+    (CLK_150M_SRC, 150 * MHZ),
+    (CLK_200M_SRC, 200 * MHZ),
+    (CLK_250M_SRC, 250 * MHZ),
+    (CLK_300M_SRC, 300 * MHZ),
+    (CLK_350M_SRC, 350 * MHZ),
+    (CLK_400M_SRC, 400 * MHZ),
+    (CLK_450M_SRC, 450 * MHZ),
+    (CLK_500M_SRC, 500 * MHZ),
+    (CLK_600M_SRC, 600 * MHZ),
+    (CLK_650M_SRC, 650 * MHZ),
+    (CLK_700M_SRC, 700 * MHZ),
+    (CLK_800M_SRC, 800 * MHZ),
+    (CLK_1000M_SRC, 1000 * MHZ),
+    (CLK_1200M_SRC, 1200 * MHZ),
+];
+
+/// 模拟的 RK3588 CRU 寄存器块
+#[derive(Debug)]
+pub struct SimCru {
+    regs: RegFile,
+    /// 不使用写掩码的寄存器 (小数分频寄存器)
+    raw_regs: BTreeSet<usize>,
+}
+
+impl SimCru {
+    /// 创建处于复位状态的 CRU (所有寄存器为 0，PLL 处于 SLOW 模式)
+    pub fn new() -> Self {
+        let raw_regs = clk_nodes()
+            .filter_map(|node| match node.kind {
+                ClkKind::Frac { reg } => Some(reg as usize),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            regs: RegFile::new(CRU_SIZE),
+            raw_regs,
+        }
+    }
+
+    /// 创建与 u-boot `rk3588_clk_init()` 执行后状态一致的 CRU
+    ///
+    /// - GPLL: 1188MHz, CPLL: 1500MHz, PPLL: 1100MHz
+    /// - ACLK_BUS_ROOT: GPLL / 4 (clksel_con[38])
+    /// - ACLK_TOP_S400/S200: 400MHz/200MHz (clksel_con[9])
+    /// - clk_50m_src ~ clk_1200m_src: 由 GPLL 分频得到最接近标称值的频率
+    /// - clk_pmu1_50m_src/clk_pmu1_100m_src/clk_pmu1_200m_src: clk_pmu1_400m_src 的 8/4/2 分频
+    ///   (pmu_clksel_con[0])
+    pub fn with_uboot_defaults() -> Self {
+        let sim = Self::new();
+        sim.set_pll(PllId::GPLL, 2, 198, 1, 0);
+        sim.set_pll(PllId::CPLL, 2, 250, 1, 0);
+        sim.set_pll(PllId::PPLL, 3, 550, 2, 0);
+
+        let div = (GPLL_HZ.div_ceil(300 * MHZ) - 1) as u32;
+        sim.write(clksel_con(38) as usize, (0x7f << 16) | div);
+        sim.write(clksel_con(9) as usize, 0xf << 22);
+
+        for (id, rate) in SRC_RATES {
+            sim.set_div(id, GPLL_HZ, rate);
+        }
+        sim.set_div(CLK_PMU1_50M_SRC, 400 * MHZ, 50 * MHZ);
+        sim.set_div(CLK_PMU1_100M_SRC, 400 * MHZ, 100 * MHZ);
+        sim.set_div(CLK_PMU1_200M_SRC, 400 * MHZ, 200 * MHZ);
+        sim
+    }
+
+    /// 按父时钟频率配置分频值，使输出最接近目标频率
+    fn set_div(&self, id: ClkId, parent_rate: u64, rate: u64) {
+        let Some(div) = clk_nodes().find(|n| n.id == id).and_then(|n| n.divider()) else {
+            return;
+        };
+        let val = match div.kind {
+            DivKind::Linear => (parent_rate + rate / 2) / rate - 1,
+            DivKind::Half => (parent_rate * 2 / rate).saturating_sub(3) / 2,
+        } as u32;
+        let field = div.field;
+        self.write(
+            field.reg as usize,
+            (field.mask << 16) | ((val.min(field.max())) << field.shift),
+        );
+    }
+
+    /// 直接配置 PLL 参数并切换到 NORMAL 模式
+    ///
+    /// # 参数
+    ///
+    /// * `pll_id` - PLL ID
+    /// * `p`, `m`, `s`, `k` - PLL 分频参数
+    pub fn set_pll(&self, pll_id: PllId, p: u32, m: u32, s: u32, k: u32) {
+        let pll = get_pll(pll_id);
+        let con = pll.con_offset as usize;
+        self.write(con, (pllcon0::M_MASK << 16) | (m << pllcon0::M_SHIFT));
+        self.write(
+            con + 0x4,
+            ((pllcon1::P_MASK | pllcon1::S_MASK | pllcon1::PWRDOWN) << 16)
+                | (p << pllcon1::P_SHIFT)
+                | (s << pllcon1::S_SHIFT),
+        );
+        self.write(con + 0x8, (pllcon2::K_MASK << 16) | (k << pllcon2::K_SHIFT));
+        self.write(
+            pll.mode_offset as usize,
+            (PLL_MODE_MASK << (pll.mode_shift + 16))
+                | (pll_mode::PLL_MODE_NORMAL << pll.mode_shift),
+        );
+    }
+
+    /// PLLCON6 偏移对应的 PLL
+    pub(crate) fn lock_reg_pll(offset: usize) -> Option<PllId> {
+        PLLS.into_iter()
+            .find(|&id| get_pll(id).con_offset as usize + 0x18 == offset)
+    }
+}
+
+impl Default for SimCru {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegAccess for SimCru {
+    fn read(&self, offset: usize) -> u32 {
+        match Self::lock_reg_pll(offset) {
+            Some(id) => {
+                let con1 = self.regs.read(get_pll(id).con_offset as usize + 0x4);
+                if con1 & pllcon1::PWRDOWN == 0 {
+                    pllcon6::LOCK_STATUS
+                } else {
+                    0
+                }
+            }
+            None => self.regs.read(offset),
+        }
+    }
+
+    fn write(&self, offset: usize, value: u32) {
+        if Self::lock_reg_pll(offset).is_some() {
+            // PLLCON6 为只读状态寄存器
+            return;
+        }
+
+        if self.raw_regs.contains(&offset) {
+            self.regs.write(offset, value);
+            return;
+        }
+
+        let mask = value >> 16;
+        let current = self.regs.read(offset);
+        self.regs
+            .write(offset, (current & !mask) | (value & mask & 0xffff));
+    }
+}
+
+/// 按 u-boot 默认值构造模拟器和 Cru，供各模块的单元测试共用
+///
+/// 返回的模拟器与 Cru 共享寄存器，用于检查寄存器的写入结果
+#[cfg(test)]
+pub(crate) fn sim_cru() -> (alloc::sync::Arc<SimCru>, super::Cru) {
+    let sim = alloc::sync::Arc::new(SimCru::with_uboot_defaults());
+    let cru =
+        super::Cru::new_with_access(0, sim.clone(), alloc::sync::Arc::new(RegFile::new(0x1000)));
+    (sim, cru)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variants::rk3588::cru::Cru;
+
+    #[test]
+    fn test_hiword_write() {
+        let sim = SimCru::new();
+        let reg = clksel_con(1) as usize;
+
+        sim.write(reg, 0xffff_1234);
+        assert_eq!(sim.read(reg), 0x1234);
+
+        // 只有写使能的位被更新
+        sim.write(reg, (0xf << 16) | 0xffff);
+        assert_eq!(sim.read(reg), 0x123f);
+
+        // 高 16 位为 0 时写入无效
+        sim.write(reg, 0);
+        assert_eq!(sim.read(reg), 0x123f);
+    }
+
+    #[test]
+    fn test_frac_reg_raw_write() {
+        let sim = SimCru::new();
+        let reg = clksel_con(44) as usize;
+
+        sim.write(reg, (3 << 16) | 50);
+        assert_eq!(sim.read(reg), (3 << 16) | 50);
+    }
+
+    #[test]
+    fn test_pll_lock_follows_power_down() {
+        let sim = SimCru::new();
+        let gpll = get_pll(PllId::GPLL);
+        let con1 = gpll.con_offset as usize + 0x4;
+
+        sim.write(con1, (pllcon1::PWRDOWN << 16) | pllcon1::PWRDOWN);
+        assert!(!gpll.is_locked(&sim));
+
+        sim.write(con1, pllcon1::PWRDOWN << 16);
+        assert!(gpll.is_locked(&sim));
+
+        // 写入 PLLCON6 不会改变锁定状态
+        sim.write(gpll.con_offset as usize + 0x18, 0xffff_0000);
+        assert!(gpll.is_locked(&sim));
+    }
+
+    #[test]
+    fn test_init_with_uboot_defaults() {
+        let (_, cru) = sim_cru();
+
+        assert_eq!(cru.gpll_hz, GPLL_HZ);
+        assert_eq!(cru.cpll_hz, CPLL_HZ);
+        assert_eq!(cru.ppll_hz, PPLL_HZ);
+        assert_eq!(cru.clk_get_rate(ACLK_BUS_ROOT).unwrap(), GPLL_HZ / 4);
+    }
+
+    #[test]
+    fn test_pll_set_rate() {
+        let (sim, mut cru) = sim_cru();
+
+        assert_eq!(cru.clk_set_rate(PLL_NPLL, 850 * MHZ).unwrap(), 850 * MHZ);
+        assert_eq!(cru.clk_get_rate(PLL_NPLL).unwrap(), 850 * MHZ);
+
+        // 小数分频
+        let rate = cru.clk_set_rate(PLL_AUPLL, 786_432_000).unwrap();
+        assert!(rate.abs_diff(786_432_000) < 10);
+
+        // 切回整数分频时清除 K
+        cru.clk_set_rate(PLL_AUPLL, 600 * MHZ).unwrap();
+        let aupll = get_pll(PllId::AUPLL);
+        assert_eq!(
+            sim.read(aupll.con_offset as usize + 0x8) & pllcon2::K_MASK,
+            0
+        );
+        assert_eq!(cru.clk_get_rate(PLL_AUPLL).unwrap(), 600 * MHZ);
+    }
+
+    #[test]
+    fn test_pll_slow_mode_rate() {
+        let (sim, cru) = sim_cru();
+
+        // NPLL 已配置参数但处于 SLOW 模式，输出为晶振频率
+        sim.set_pll(PllId::NPLL, 3, 425, 2, 0);
+        let npll = get_pll(PllId::NPLL);
+        sim.write(
+            npll.mode_offset as usize,
+            PLL_MODE_MASK << (npll.mode_shift + 16),
+        );
+        assert_eq!(cru.pll_get_rate(PllId::NPLL).unwrap(), OSC_HZ);
+    }
+
+    /// 设置频率后，时钟树读回的频率应接近请求值
+    ///
+    /// 公共时钟源由 GPLL 整数分频得到 (如 clk_200m_src = 198MHz)，允许 1% 误差
+    fn check_set_rate(cru: &mut Cru, id: ClkId, rate_hz: u64) {
+        let set = cru.clk_set_rate(id, rate_hz).unwrap();
+        let actual = cru.clk_get_rate(id).unwrap();
+        assert!(
+            actual.abs_diff(rate_hz) <= rate_hz / 100,
+            "{id}: set_rate({rate_hz}) returned {set}, read back {actual}"
+        );
+    }
+
+    #[test]
+    fn test_src_defaults() {
+        let (_, cru) = sim_cru();
+
+        assert_eq!(cru.clk_get_rate(CLK_200M_SRC).unwrap(), GPLL_HZ / 6);
+        assert_eq!(cru.clk_get_rate(CLK_100M_SRC).unwrap(), GPLL_HZ / 12);
+        assert_eq!(cru.clk_get_rate(CLK_1200M_SRC).unwrap(), GPLL_HZ);
+        assert_eq!(cru.clk_get_rate(CLK_450M_SRC).unwrap(), GPLL_HZ * 2 / 5);
+    }
+
+    #[test]
+    fn test_peripheral_set_rate_readback() {
+        let (_, mut cru) = sim_cru();
+
+        check_set_rate(&mut cru, CLK_I2C1, 200 * MHZ);
+        check_set_rate(&mut cru, CLK_I2C2, 100 * MHZ);
+        check_set_rate(&mut cru, CLK_I2C0, 200 * MHZ);
+        check_set_rate(&mut cru, CLK_SPI0, 200 * MHZ);
+        check_set_rate(&mut cru, CLK_SPI1, OSC_HZ);
+        check_set_rate(&mut cru, CLK_PWM1, 100 * MHZ);
+        check_set_rate(&mut cru, CLK_SARADC, MHZ);
+        check_set_rate(&mut cru, CLK_TSADC, 2 * MHZ);
+        check_set_rate(&mut cru, SCLK_UART2, OSC_HZ);
+        check_set_rate(&mut cru, SCLK_UART3, GPLL_HZ / 11);
+        check_set_rate(&mut cru, CCLK_EMMC, 200 * MHZ);
+        check_set_rate(&mut cru, CCLK_SRC_SDIO, 50 * MHZ);
+        check_set_rate(&mut cru, ACLK_USB_ROOT, 300 * MHZ);
+    }
+}
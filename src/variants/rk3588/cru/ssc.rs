@@ -0,0 +1,238 @@
+//! RK3588 PLL 扩频调制 (Spread Spectrum Clocking)
+//!
+//! RK3588 的 PLL 通过 PLLCON3/PLLCON4 支持三角波扩频调制，用于降低 EMI：
+//!
+//! - 调制频率: Fmod = Fref / (P * MFR * 32)
+//! - 调制深度: MFR * MRR / (M * 64)
+//! - 扩频方式: SEL_PF 选择向下、向上或中心扩频
+//!
+//! 扩频参数与 P/M 相关，修改 PLL 频率后需要重新设置。
+//! `clk_get_rate` 返回未调制的标称频率
+
+use super::consts::*;
+use super::pll::*;
+use super::*;
+use crate::clock::{
+    ClockError, ClockResult,
+    pll::{PllSsc, SscSpread},
+};
+
+/// 深度换算的基数 (ppm)
+const PPM: u64 = 1_000_000;
+
+/// 由扩频配置计算 (SEL_PF, MFR, MRR)
+///
+/// MFR 和 MRR 取最接近的值，超出范围时取边界值
+fn ssc_to_raw(ssc: &PllSsc, p: u32, m: u32) -> (u32, u32, u32) {
+    let sel_pf = match ssc.spread {
+        SscSpread::Down => pllcon3::SEL_PF_DOWN,
+        SscSpread::Up => pllcon3::SEL_PF_UP,
+        SscSpread::Center => pllcon3::SEL_PF_CENTER,
+    };
+
+    let step = p as u64 * ssc.mod_freq_hz as u64 * 32;
+    let mfr = ((OSC_HZ + step / 2) / step).clamp(1, 0xff);
+
+    let unit = mfr * PPM;
+    let mrr = ((ssc.depth_ppm as u64 * m as u64 * 64 + unit / 2) / unit).clamp(1, 0x3f);
+
+    (sel_pf, mfr as u32, mrr as u32)
+}
+
+/// 由 (SEL_PF, MFR, MRR) 计算扩频配置
+fn ssc_from_raw(sel_pf: u32, mfr: u32, mrr: u32, p: u32, m: u32) -> PllSsc {
+    let spread = match sel_pf {
+        pllcon3::SEL_PF_DOWN => SscSpread::Down,
+        pllcon3::SEL_PF_UP => SscSpread::Up,
+        _ => SscSpread::Center,
+    };
+    let mod_freq_hz = OSC_HZ / (p as u64 * mfr as u64 * 32).max(1);
+    let depth_ppm = mfr as u64 * mrr as u64 * PPM / (m as u64 * 64).max(1);
+    PllSsc {
+        spread,
+        depth_ppm: depth_ppm as u32,
+        mod_freq_hz: mod_freq_hz as u32,
+    }
+}
+
+impl Cru {
+    /// 读取 PLL 的 P/M 分频系数，PLL 未配置时返回错误
+    fn pll_pm(&self, pll_id: PllId) -> ClockResult<(u32, u32)> {
+        let pll_cfg = get_pll(pll_id);
+        let m = (self.read(pll_cfg.con_offset) & pllcon0::M_MASK) >> pllcon0::M_SHIFT;
+        let p = (self.read(pll_cfg.con_offset + pll_con(1)) & pllcon1::P_MASK) >> pllcon1::P_SHIFT;
+        if p == 0 || m == 0 {
+            return Err(ClockError::pll_config_error(
+                pll_id.into(),
+                "PLL is not configured",
+            ));
+        }
+        Ok((p, m))
+    }
+
+    /// 获取 PLL 的扩频配置
+    ///
+    /// # 参数
+    ///
+    /// * `pll_id` - PLL ID
+    ///
+    /// # 返回
+    ///
+    /// 返回按寄存器值换算的实际扩频配置，未使能扩频时返回 `None`
+    ///
+    /// # Errors
+    ///
+    /// PLL 未配置 (P 或 M 为 0) 时返回 `ClockError::PllConfigError`
+    pub fn pll_get_ssc(&self, pll_id: PllId) -> ClockResult<Option<PllSsc>> {
+        let pll_cfg = get_pll(pll_id);
+        if self.read(pll_cfg.con_offset + pll_con(4)) & pllcon4::SSCG_EN == 0 {
+            return Ok(None);
+        }
+
+        let (p, m) = self.pll_pm(pll_id)?;
+        let con3 = self.read(pll_cfg.con_offset + pll_con(3));
+        Ok(Some(ssc_from_raw(
+            (con3 & pllcon3::SEL_PF_MASK) >> pllcon3::SEL_PF_SHIFT,
+            (con3 & pllcon3::MFR_MASK) >> pllcon3::MFR_SHIFT,
+            (con3 & pllcon3::MRR_MASK) >> pllcon3::MRR_SHIFT,
+            p,
+            m,
+        )))
+    }
+
+    /// 设置 PLL 的扩频配置
+    ///
+    /// 按 PLL 当前的 P/M 换算 MFR/MRR，先写入调制参数再使能扩频。
+    /// 修改 PLL 频率后需要重新设置
+    ///
+    /// # 参数
+    ///
+    /// * `pll_id` - PLL ID
+    /// * `ssc` - 扩频配置，`None` 表示关闭扩频
+    ///
+    /// # 返回
+    ///
+    /// 返回设置后实际的扩频配置，关闭时返回 `None`
+    ///
+    /// # Errors
+    ///
+    /// PLL 未配置，或深度/调制频率为 0 时返回 `ClockError::PllConfigError`
+    pub fn pll_set_ssc(
+        &mut self,
+        pll_id: PllId,
+        ssc: Option<PllSsc>,
+    ) -> ClockResult<Option<PllSsc>> {
+        let pll_cfg = get_pll(pll_id);
+        let con4 = pll_cfg.con_offset + pll_con(4);
+
+        let Some(ssc) = ssc else {
+            self.clrreg(con4, pllcon4::SSCG_EN);
+            debug!("{}: SSC disabled", pll_id.name());
+            return Ok(None);
+        };
+        if ssc.depth_ppm == 0 || ssc.mod_freq_hz == 0 {
+            return Err(ClockError::pll_config_error(
+                pll_id.into(),
+                "SSC depth and modulation frequency must be non-zero",
+            ));
+        }
+
+        let (p, m) = self.pll_pm(pll_id)?;
+        let (sel_pf, mfr, mrr) = ssc_to_raw(&ssc, p, m);
+        self.clrsetreg(
+            pll_cfg.con_offset + pll_con(3),
+            pllcon3::SEL_PF_MASK | pllcon3::MFR_MASK | pllcon3::MRR_MASK,
+            (sel_pf << pllcon3::SEL_PF_SHIFT)
+                | (mfr << pllcon3::MFR_SHIFT)
+                | (mrr << pllcon3::MRR_SHIFT),
+        );
+        self.setreg(con4, pllcon4::SSCG_EN);
+
+        let actual = ssc_from_raw(sel_pf, mfr, mrr, p, m);
+        debug!(
+            "{}: SSC {:?} (mfr {}, mrr {}), requested {:?}",
+            pll_id.name(),
+            actual,
+            mfr,
+            mrr,
+            ssc
+        );
+        Ok(Some(actual))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variants::rk3588::cru::sim::sim_cru;
+
+    #[test]
+    fn test_ssc_raw() {
+        // GPLL 1188MHz: P = 2, M = 198
+        let ssc = PllSsc {
+            spread: SscSpread::Down,
+            depth_ppm: 5000,
+            mod_freq_hz: 31_250,
+        };
+        let (sel_pf, mfr, mrr) = ssc_to_raw(&ssc, 2, 198);
+        assert_eq!((sel_pf, mfr, mrr), (pllcon3::SEL_PF_DOWN, 12, 5));
+
+        // 12 * 5 / (198 * 64) = 0.4735%
+        let actual = ssc_from_raw(sel_pf, mfr, mrr, 2, 198);
+        assert_eq!(actual.mod_freq_hz, 31_250);
+        assert_eq!(actual.depth_ppm, 4734);
+        assert_eq!(actual.spread, SscSpread::Down);
+
+        // 超出范围时取边界值
+        let ssc = PllSsc {
+            spread: SscSpread::Center,
+            depth_ppm: 1,
+            mod_freq_hz: 1,
+        };
+        let (sel_pf, mfr, mrr) = ssc_to_raw(&ssc, 2, 198);
+        assert_eq!((sel_pf, mfr, mrr), (pllcon3::SEL_PF_CENTER, 0xff, 1));
+    }
+
+    #[test]
+    fn test_pll_ssc() {
+        let (sim, mut cru) = sim_cru();
+        let ssc = PllSsc {
+            spread: SscSpread::Center,
+            depth_ppm: 5000,
+            mod_freq_hz: 32_000,
+        };
+
+        // V0PLL 未配置时无法换算扩频参数
+        assert!(cru.pll_set_ssc(PllId::V0PLL, Some(ssc)).is_err());
+        assert_eq!(cru.pll_get_ssc(PllId::V0PLL).unwrap(), None);
+
+        cru.clk_set_rate(PLL_V0PLL, 1188 * MHZ).unwrap();
+        let actual = cru.pll_set_ssc(PllId::V0PLL, Some(ssc)).unwrap().unwrap();
+        assert_eq!(actual.spread, SscSpread::Center);
+        assert!(actual.depth_ppm.abs_diff(5000) < 1000);
+        assert!(actual.mod_freq_hz.abs_diff(32_000) < 3000);
+        assert_eq!(cru.pll_get_ssc(PllId::V0PLL).unwrap(), Some(actual));
+
+        // 扩频不改变标称频率
+        assert_eq!(cru.clk_get_rate(PLL_V0PLL).unwrap(), 1188 * MHZ);
+
+        // PPLL 在 PMU CRU 中
+        let down = PllSsc {
+            spread: SscSpread::Down,
+            ..ssc
+        };
+        cru.pll_set_ssc(PllId::PPLL, Some(down)).unwrap();
+        let ppll = get_pll(PllId::PPLL);
+        assert_eq!(
+            sim.read(ppll.con_offset as usize + 0x10) & pllcon4::SSCG_EN,
+            pllcon4::SSCG_EN
+        );
+        assert_eq!(
+            cru.pll_get_ssc(PllId::PPLL).unwrap().unwrap().spread,
+            SscSpread::Down
+        );
+
+        assert_eq!(cru.pll_set_ssc(PllId::PPLL, None).unwrap(), None);
+        assert_eq!(cru.pll_get_ssc(PllId::PPLL).unwrap(), None);
+    }
+}
@@ -0,0 +1,3122 @@
+//! RK3588 时钟树
+//!
+//! 参考 Linux: drivers/clk/rockchip/clk-rk3588.c
+//!
+//! 以节点表描述 RK3588 CRU 的父子拓扑，时钟频率通过沿当前选中的
+//! 父时钟链向上遍历计算：
+//!
+//! ```text
+//! xin24m ─┬─ GPLL ─┬─ clk_200m_src ─┬─ clk_i2c1
+//!         │        │                └─ clk_spi0
+//!         └─ CPLL ─┘
+//! ```
+//!
+//! 节点按子系统分组，[`CLK_TREE`] 汇总所有分组。
+
+use alloc::vec::Vec;
+
+use super::clock::*;
+use super::consts::*;
+use super::*;
+use crate::clock::tree::{ClkFlags, ClkKind, ClkNode, RegField, rational_best_approximation};
+
+// =============================================================================
+// 父时钟列表 (命名参考 Linux PNAME)
+// =============================================================================
+
+const XIN24M_P: &[ClkId] = &[XIN24M];
+const GPLL_CPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL];
+const GPLL_SPLL_P: &[ClkId] = &[PLL_GPLL, SPLL];
+const GPLL_SPLL_24M_P: &[ClkId] = &[PLL_GPLL, SPLL, XIN24M];
+const GPLL_LPLL_P: &[ClkId] = &[PLL_GPLL, PLL_LPLL];
+const GPLL_AUPLL_P: &[ClkId] = &[PLL_GPLL, PLL_AUPLL];
+const GPLL_24M_P: &[ClkId] = &[PLL_GPLL, XIN24M];
+const GPLL_CPLL_24M_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, XIN24M];
+const GPLL_CPLL_AUPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_AUPLL];
+const GPLL_CPLL_NPLL_V0PLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_NPLL, PLL_V0PLL];
+const B0PLL_B1PLL_LPLL_GPLL_P: &[ClkId] = &[PLL_B0PLL, PLL_B1PLL, PLL_LPLL, PLL_GPLL];
+const MUX_ARMCLKL_P: &[ClkId] = &[XIN24M, PLL_GPLL, PLL_LPLL];
+const MUX_ARMCLKB01_P: &[ClkId] = &[XIN24M, PLL_GPLL, PLL_B0PLL];
+const MUX_ARMCLKB23_P: &[ClkId] = &[XIN24M, PLL_GPLL, PLL_B1PLL];
+const GPLL_CPLL_V0PLL_AUPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_V0PLL, PLL_AUPLL];
+const GPLL_CPLL_NPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_NPLL];
+const GPLL_CPLL_AUPLL_SPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_AUPLL, SPLL];
+const GPLL_CPLL_AUPLL_NPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_AUPLL, PLL_NPLL];
+const GPLL_CPLL_NPLL_1000M_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_NPLL, CLK_1000M_SRC];
+const GPLL_CPLL_NPLL_AUPLL_SPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_NPLL, PLL_AUPLL, SPLL];
+const GPLL_CPLL_24M_SPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, XIN24M, SPLL];
+const MUX_24M_SPLL_GPLL_CPLL_P: &[ClkId] = &[XIN24M, SPLL, PLL_GPLL, PLL_CPLL];
+const GPLL_CPLL_AUPLL_NPLL_SPLL_P: &[ClkId] = &[PLL_GPLL, PLL_CPLL, PLL_AUPLL, PLL_NPLL, SPLL];
+const GPU_P: &[ClkId] = &[CLK_GPU_SRC, CLK_GPU_PVTPLL];
+const NPU_MUX_P: &[ClkId] = &[CLK_NPU_DSU0, CLK_NPU_PVTPLL];
+const ACLK_VOP_SUB_SRC_P: &[ClkId] = &[ACLK_VOP_ROOT, ACLK_VOP_DIV2_SRC];
+const DCLK_VOP0_P: &[ClkId] = &[DCLK_VOP0_SRC, CLK_HDMIPHY_PIXEL0, CLK_HDMIPHY_PIXEL1];
+const DCLK_VOP1_P: &[ClkId] = &[DCLK_VOP1_SRC, CLK_HDMIPHY_PIXEL0, CLK_HDMIPHY_PIXEL1];
+const DCLK_VOP2_P: &[ClkId] = &[DCLK_VOP2_SRC, CLK_HDMIPHY_PIXEL0, CLK_HDMIPHY_PIXEL1];
+const MUX_200M_100M_P: &[ClkId] = &[CLK_200M_SRC, CLK_100M_SRC];
+const MUX_100M_24M_P: &[ClkId] = &[CLK_100M_SRC, XIN24M];
+const MUX_200M_150M_24M_P: &[ClkId] = &[CLK_200M_SRC, CLK_150M_SRC, XIN24M];
+const MUX_100M_50M_24M_P: &[ClkId] = &[CLK_100M_SRC, CLK_50M_SRC, XIN24M];
+const MUX_150M_50M_24M_P: &[ClkId] = &[CLK_150M_SRC, CLK_50M_SRC, XIN24M];
+const MUX_150M_100M_50M_24M_P: &[ClkId] = &[CLK_150M_SRC, CLK_100M_SRC, CLK_50M_SRC, XIN24M];
+const MUX_200M_100M_50M_24M_P: &[ClkId] = &[CLK_200M_SRC, CLK_100M_SRC, CLK_50M_SRC, XIN24M];
+const MUX_400M_200M_100M_24M_P: &[ClkId] = &[CLK_400M_SRC, CLK_200M_SRC, CLK_100M_SRC, XIN24M];
+const MUX_500M_250M_100M_24M_P: &[ClkId] = &[CLK_500M_SRC, CLK_250M_SRC, CLK_100M_SRC, XIN24M];
+const MUX_700M_400M_200M_24M_P: &[ClkId] = &[CLK_700M_SRC, CLK_400M_SRC, CLK_200M_SRC, XIN24M];
+
+/// 无标志位
+const NONE: ClkFlags = ClkFlags::empty();
+/// 关键时钟
+const CRITICAL: ClkFlags = ClkFlags::IS_CRITICAL;
+
+// =============================================================================
+// 时钟树节点定义
+// =============================================================================
+
+/// 外部时钟源与 PLL
+const PLL_NODES: &[ClkNode] = &[
+    ClkNode::fixed(XIN24M, "xin24m", OSC_HZ),
+    // SPLL 由安全域 (SCMI) 管理，固定 702MHz
+    ClkNode::fixed(SPLL, "spll", 702 * MHZ),
+    ClkNode::pll(PLL_B0PLL, "b0pll", XIN24M_P),
+    ClkNode::pll(PLL_B1PLL, "b1pll", XIN24M_P),
+    ClkNode::pll(PLL_LPLL, "lpll", XIN24M_P),
+    ClkNode::pll(PLL_V0PLL, "v0pll", XIN24M_P),
+    ClkNode::pll(PLL_AUPLL, "aupll", XIN24M_P),
+    ClkNode::pll(PLL_CPLL, "cpll", XIN24M_P),
+    ClkNode::pll(PLL_GPLL, "gpll", XIN24M_P),
+    ClkNode::pll(PLL_NPLL, "npll", XIN24M_P),
+    ClkNode::pll(PLL_PPLL, "ppll", XIN24M_P),
+];
+
+/// CPU 集群与 DSU 时钟
+///
+/// 集群时钟的分频器为第一个核的分频器 (Linux: rockchip_cpuclk_recalc_rate)，
+/// 其他核的分频器见 `cpu.rs`
+const CPU_NODES: &[ClkNode] = &[
+    ClkNode::composite(
+        ARMCLK_L,
+        "armclk_l",
+        MUX_ARMCLKL_P,
+        RegField::new(dsu_clksel_con(5), 14, 2),
+        RegField::new(dsu_clksel_con(6), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        ARMCLK_B01,
+        "armclk_b01",
+        MUX_ARMCLKB01_P,
+        RegField::new(bigcore0_clksel_con(0), 6, 2),
+        RegField::new(bigcore0_clksel_con(0), 8, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        ARMCLK_B23,
+        "armclk_b23",
+        MUX_ARMCLKB23_P,
+        RegField::new(bigcore1_clksel_con(0), 6, 2),
+        RegField::new(bigcore1_clksel_con(0), 8, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        SCLK_DSU,
+        "sclk_dsu",
+        B0PLL_B1PLL_LPLL_GPLL_P,
+        RegField::new(dsu_clksel_con(0), 12, 2),
+        RegField::new(dsu_clksel_con(0), 0, 5),
+        CRITICAL,
+    ),
+];
+
+/// 公共时钟源 (CLKSEL_CON(0) ~ CLKSEL_CON(7))
+///
+/// u-boot 将这些时钟视为固定频率 (如 200MHz)，实际由 GPLL/CPLL 分频得到，
+/// GPLL=1188MHz 时 clk_200m_src 为 1188/6 = 198MHz
+const SRC_NODES: &[ClkNode] = &[
+    ClkNode::composite(
+        CLK_50M_SRC,
+        "clk_50m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(0), 5, 1),
+        RegField::new(clksel_con(0), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_100M_SRC,
+        "clk_100m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(0), 11, 1),
+        RegField::new(clksel_con(0), 6, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_150M_SRC,
+        "clk_150m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(1), 5, 1),
+        RegField::new(clksel_con(1), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_200M_SRC,
+        "clk_200m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(1), 11, 1),
+        RegField::new(clksel_con(1), 6, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_250M_SRC,
+        "clk_250m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(2), 5, 1),
+        RegField::new(clksel_con(2), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_300M_SRC,
+        "clk_300m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(2), 11, 1),
+        RegField::new(clksel_con(2), 6, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_350M_SRC,
+        "clk_350m_src",
+        GPLL_SPLL_P,
+        RegField::new(clksel_con(3), 5, 1),
+        RegField::new(clksel_con(3), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_400M_SRC,
+        "clk_400m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(3), 11, 1),
+        RegField::new(clksel_con(3), 6, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite_halfdiv(
+        CLK_450M_SRC,
+        "clk_450m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(4), 5, 1),
+        RegField::new(clksel_con(4), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_500M_SRC,
+        "clk_500m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(4), 11, 1),
+        RegField::new(clksel_con(4), 6, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_600M_SRC,
+        "clk_600m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(5), 5, 1),
+        RegField::new(clksel_con(5), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_650M_SRC,
+        "clk_650m_src",
+        GPLL_LPLL_P,
+        RegField::new(clksel_con(5), 11, 1),
+        RegField::new(clksel_con(5), 6, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_700M_SRC,
+        "clk_700m_src",
+        GPLL_SPLL_P,
+        RegField::new(clksel_con(6), 5, 1),
+        RegField::new(clksel_con(6), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_800M_SRC,
+        "clk_800m_src",
+        GPLL_AUPLL_P,
+        RegField::new(clksel_con(6), 11, 1),
+        RegField::new(clksel_con(6), 6, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite_halfdiv(
+        CLK_1000M_SRC,
+        "clk_1000m_src",
+        GPLL_CPLL_NPLL_V0PLL_P,
+        RegField::new(clksel_con(7), 5, 2),
+        RegField::new(clksel_con(7), 0, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_1200M_SRC,
+        "clk_1200m_src",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(7), 12, 1),
+        RegField::new(clksel_con(7), 7, 5),
+        CRITICAL,
+    ),
+];
+
+/// 总线根时钟
+const ROOT_NODES: &[ClkNode] = &[
+    ClkNode::composite(
+        ACLK_BUS_ROOT,
+        "aclk_bus_root",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(38), clk_sel38::ACLK_BUS_ROOT_SEL_SHIFT, 1),
+        RegField::new(clksel_con(38), clk_sel38::ACLK_BUS_ROOT_DIV_SHIFT, 5),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        ACLK_TOP_ROOT,
+        "aclk_top_root",
+        GPLL_CPLL_AUPLL_P,
+        RegField::new(clksel_con(8), clk_sel8::ACLK_TOP_ROOT_SRC_SEL_SHIFT, 2),
+        RegField::new(clksel_con(8), clk_sel8::ACLK_TOP_ROOT_DIV_SHIFT, 5),
+        CRITICAL,
+    ),
+    ClkNode::mux(
+        PCLK_TOP_ROOT,
+        "pclk_top_root",
+        MUX_100M_50M_24M_P,
+        RegField::new(clksel_con(8), clk_sel8::PCLK_TOP_ROOT_SEL_SHIFT, 2),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        ACLK_LOW_TOP_ROOT,
+        "aclk_low_top_root",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(8), clk_sel8::ACLK_LOW_TOP_ROOT_SRC_SEL_SHIFT, 1),
+        RegField::new(clksel_con(8), clk_sel8::ACLK_LOW_TOP_ROOT_DIV_SHIFT, 5),
+        CRITICAL,
+    ),
+    ClkNode::mux(
+        ACLK_TOP_S400,
+        "aclk_top_s400",
+        MUX_400M_200M_100M_24M_P,
+        RegField::new(clksel_con(9), clk_sel9::ACLK_TOP_S400_SEL_SHIFT, 2),
+        CRITICAL,
+    ),
+    ClkNode::mux(
+        ACLK_TOP_S200,
+        "aclk_top_s200",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(9), clk_sel9::ACLK_TOP_S200_SEL_SHIFT, 2),
+        CRITICAL,
+    ),
+    ClkNode::mux(
+        ACLK_CENTER_ROOT,
+        "aclk_center_root",
+        MUX_700M_400M_200M_24M_P,
+        RegField::new(clksel_con(165), clk_sel165::ACLK_CENTER_ROOT_SEL_SHIFT, 2),
+        CRITICAL,
+    ),
+    ClkNode::mux(
+        ACLK_CENTER_LOW_ROOT,
+        "aclk_center_low_root",
+        MUX_500M_250M_100M_24M_P,
+        RegField::new(
+            clksel_con(165),
+            clk_sel165::ACLK_CENTER_LOW_ROOT_SEL_SHIFT,
+            2,
+        ),
+        CRITICAL,
+    ),
+    ClkNode::mux(
+        HCLK_CENTER_ROOT,
+        "hclk_center_root",
+        MUX_400M_200M_100M_24M_P,
+        RegField::new(clksel_con(165), clk_sel165::HCLK_CENTER_ROOT_SEL_SHIFT, 2),
+        CRITICAL,
+    ),
+    ClkNode::mux(
+        PCLK_CENTER_ROOT,
+        "pclk_center_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(165), clk_sel165::PCLK_CENTER_ROOT_SEL_SHIFT, 2),
+        CRITICAL,
+    ),
+    // PMU CRU 的源时钟和总线根时钟
+    ClkNode::mux(
+        CLK_PMU1_300M_SRC,
+        "clk_pmu1_300m_src",
+        &[CLK_300M_SRC, XIN24M],
+        RegField::new(pmu_clksel_con(0), 15, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_PMU1_400M_SRC,
+        "clk_pmu1_400m_src",
+        &[CLK_400M_SRC, XIN24M],
+        RegField::new(pmu_clksel_con(1), 0, 1),
+        NONE,
+    ),
+    ClkNode::div(
+        CLK_PMU1_50M_SRC,
+        "clk_pmu1_50m_src",
+        &[CLK_PMU1_400M_SRC],
+        RegField::new(pmu_clksel_con(0), 0, 4),
+        NONE,
+    ),
+    ClkNode::div(
+        CLK_PMU1_100M_SRC,
+        "clk_pmu1_100m_src",
+        &[CLK_PMU1_400M_SRC],
+        RegField::new(pmu_clksel_con(0), 4, 3),
+        NONE,
+    ),
+    ClkNode::div(
+        CLK_PMU1_200M_SRC,
+        "clk_pmu1_200m_src",
+        &[CLK_PMU1_400M_SRC],
+        RegField::new(pmu_clksel_con(0), 7, 3),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_PMU1_ROOT,
+        "hclk_pmu1_root",
+        &[
+            CLK_PMU1_200M_SRC,
+            CLK_PMU1_100M_SRC,
+            CLK_PMU1_50M_SRC,
+            XIN24M,
+        ],
+        RegField::new(pmu_clksel_con(1), 2, 2),
+        CRITICAL,
+    ),
+    ClkNode::mux(
+        PCLK_PMU1_ROOT,
+        "pclk_pmu1_root",
+        &[CLK_PMU1_100M_SRC, CLK_PMU1_50M_SRC, XIN24M],
+        RegField::new(pmu_clksel_con(1), 4, 2),
+        CRITICAL,
+    ),
+    ClkNode::gate(
+        PCLK_PMU0_ROOT,
+        "pclk_pmu0_root",
+        &[PCLK_PMU1_ROOT],
+        CRITICAL,
+    ),
+];
+
+/// I2C 时钟
+const I2C_NODES: &[ClkNode] = &[
+    // I2C0 在 PMU CRU 中
+    ClkNode::gate(PCLK_I2C0, "pclk_i2c0", &[PCLK_PMU0_ROOT], NONE),
+    ClkNode::mux(
+        CLK_I2C0,
+        "clk_i2c0",
+        &[CLK_PMU1_200M_SRC, CLK_PMU1_100M_SRC],
+        RegField::new(pmu_clksel_con(3), pmu_clk_sel3::CLK_I2C0_SEL_SHIFT, 1),
+        NONE,
+    ),
+    ClkNode::gate(PCLK_I2C1, "pclk_i2c1", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_I2C2, "pclk_i2c2", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_I2C3, "pclk_i2c3", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_I2C4, "pclk_i2c4", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_I2C5, "pclk_i2c5", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_I2C6, "pclk_i2c6", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_I2C7, "pclk_i2c7", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_I2C8, "pclk_i2c8", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::mux(
+        CLK_I2C1,
+        "clk_i2c1",
+        MUX_200M_100M_P,
+        RegField::new(clksel_con(38), clk_sel38::CLK_I2C1_SEL_SHIFT, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2C2,
+        "clk_i2c2",
+        MUX_200M_100M_P,
+        RegField::new(clksel_con(38), clk_sel38::CLK_I2C2_SEL_SHIFT, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2C3,
+        "clk_i2c3",
+        MUX_200M_100M_P,
+        RegField::new(clksel_con(38), clk_sel38::CLK_I2C3_SEL_SHIFT, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2C4,
+        "clk_i2c4",
+        MUX_200M_100M_P,
+        RegField::new(clksel_con(38), clk_sel38::CLK_I2C4_SEL_SHIFT, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2C5,
+        "clk_i2c5",
+        MUX_200M_100M_P,
+        RegField::new(clksel_con(38), clk_sel38::CLK_I2C5_SEL_SHIFT, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2C6,
+        "clk_i2c6",
+        MUX_200M_100M_P,
+        RegField::new(clksel_con(38), clk_sel38::CLK_I2C6_SEL_SHIFT, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2C7,
+        "clk_i2c7",
+        MUX_200M_100M_P,
+        RegField::new(clksel_con(38), clk_sel38::CLK_I2C7_SEL_SHIFT, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2C8,
+        "clk_i2c8",
+        MUX_200M_100M_P,
+        RegField::new(clksel_con(38), clk_sel38::CLK_I2C8_SEL_SHIFT, 1),
+        NONE,
+    ),
+];
+
+/// SPI 时钟
+const SPI_NODES: &[ClkNode] = &[
+    ClkNode::gate(PCLK_SPI0, "pclk_spi0", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_SPI1, "pclk_spi1", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_SPI2, "pclk_spi2", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_SPI3, "pclk_spi3", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_SPI4, "pclk_spi4", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::mux(
+        CLK_SPI0,
+        "clk_spi0",
+        MUX_200M_150M_24M_P,
+        RegField::new(clksel_con(59), clk_sel59::CLK_SPI0_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_SPI1,
+        "clk_spi1",
+        MUX_200M_150M_24M_P,
+        RegField::new(clksel_con(59), clk_sel59::CLK_SPI1_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_SPI2,
+        "clk_spi2",
+        MUX_200M_150M_24M_P,
+        RegField::new(clksel_con(59), clk_sel59::CLK_SPI2_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_SPI3,
+        "clk_spi3",
+        MUX_200M_150M_24M_P,
+        RegField::new(clksel_con(59), clk_sel59::CLK_SPI3_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_SPI4,
+        "clk_spi4",
+        MUX_200M_150M_24M_P,
+        RegField::new(clksel_con(59), clk_sel59::CLK_SPI4_SEL_SHIFT, 2),
+        NONE,
+    ),
+];
+
+/// PWM 时钟
+const PWM_NODES: &[ClkNode] = &[
+    ClkNode::gate(PCLK_PWM1, "pclk_pwm1", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::mux(
+        CLK_PWM1,
+        "clk_pwm1",
+        MUX_100M_50M_24M_P,
+        RegField::new(clksel_con(59), clk_sel59::CLK_PWM1_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::gate(CLK_PWM1_CAPTURE, "clk_pwm1_capture", XIN24M_P, NONE),
+    ClkNode::gate(PCLK_PWM2, "pclk_pwm2", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::mux(
+        CLK_PWM2,
+        "clk_pwm2",
+        MUX_100M_50M_24M_P,
+        RegField::new(clksel_con(59), clk_sel59::CLK_PWM2_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::gate(CLK_PWM2_CAPTURE, "clk_pwm2_capture", XIN24M_P, NONE),
+    ClkNode::gate(PCLK_PWM3, "pclk_pwm3", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::mux(
+        CLK_PWM3,
+        "clk_pwm3",
+        MUX_100M_50M_24M_P,
+        RegField::new(clksel_con(60), clk_sel60::CLK_PWM3_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::gate(CLK_PWM3_CAPTURE, "clk_pwm3_capture", XIN24M_P, NONE),
+    // PMU PWM
+    ClkNode::gate(PCLK_PMU1PWM, "pclk_pmu1pwm", &[PCLK_PMU0_ROOT], NONE),
+    ClkNode::mux(
+        CLK_PMU1PWM,
+        "clk_pmu1pwm",
+        MUX_100M_50M_24M_P,
+        RegField::new(pmu_clksel_con(2), pmu_clk_sel2::CLK_PMU1PWM_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::gate(CLK_PMU1PWM_CAPTURE, "clk_pmu1pwm_capture", XIN24M_P, NONE),
+];
+
+/// ADC 时钟
+const ADC_NODES: &[ClkNode] = &[
+    ClkNode::gate(PCLK_SARADC, "pclk_saradc", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::composite(
+        CLK_SARADC,
+        "clk_saradc",
+        GPLL_24M_P,
+        RegField::new(clksel_con(40), clk_sel40::CLK_SARADC_SEL_SHIFT, 1),
+        RegField::new(clksel_con(40), clk_sel40::CLK_SARADC_DIV_SHIFT, 8),
+        NONE,
+    ),
+    ClkNode::gate(PCLK_TSADC, "pclk_tsadc", &[PCLK_TOP_ROOT], NONE),
+    // 与 u-boot 一致，TSADC 的非 24M 时钟源按 100MHz 计算
+    ClkNode::composite(
+        CLK_TSADC,
+        "clk_tsadc",
+        MUX_100M_24M_P,
+        RegField::new(clksel_con(41), clk_sel41::CLK_TSADC_SEL_SHIFT, 1),
+        RegField::new(clksel_con(41), clk_sel41::CLK_TSADC_DIV_SHIFT, 8),
+        NONE,
+    ),
+];
+
+/// 生成一组 UART 时钟节点: src (GPLL/CPLL 分频) -> frac -> mux -> 门控
+///
+/// 寄存器布局参考 u-boot rk3588_uart_get_rate():
+/// - clksel_con(reg): src 选择 [14], 分频 [13:9]
+/// - clksel_con(reg + 1): 小数分频
+/// - clksel_con(reg + 2): uart 选择 [1:0] (src/frac/xin24m)
+macro_rules! uart_nodes {
+    ($n:literal, $src:ident, $frac:ident, $clk:ident, $sclk:ident, $reg:expr) => {
+        [
+            ClkNode::composite(
+                $src,
+                concat!("clk_uart", $n, "_src"),
+                GPLL_CPLL_P,
+                RegField::new(clksel_con($reg), clk_sel41::CLK_UART_SRC_SEL_SHIFT, 1),
+                RegField::new(clksel_con($reg), clk_sel41::CLK_UART_SRC_DIV_SHIFT, 5),
+                NONE,
+            ),
+            ClkNode::frac(
+                $frac,
+                concat!("clk_uart", $n, "_frac"),
+                &[$src],
+                clksel_con($reg + 1),
+                NONE,
+            ),
+            ClkNode::mux(
+                $clk,
+                concat!("clk_uart", $n),
+                &[$src, $frac, XIN24M],
+                RegField::new(clksel_con($reg + 2), clk_sel43::CLK_UART_SEL_SHIFT, 2),
+                NONE,
+            ),
+            ClkNode::gate($sclk, concat!("sclk_uart", $n), &[$clk], NONE),
+        ]
+    };
+}
+
+const UART1_NODES: [ClkNode; 4] =
+    uart_nodes!(1, CLK_UART1_SRC, CLK_UART1_FRAC, CLK_UART1, SCLK_UART1, 41);
+const UART2_NODES: [ClkNode; 4] =
+    uart_nodes!(2, CLK_UART2_SRC, CLK_UART2_FRAC, CLK_UART2, SCLK_UART2, 43);
+const UART3_NODES: [ClkNode; 4] =
+    uart_nodes!(3, CLK_UART3_SRC, CLK_UART3_FRAC, CLK_UART3, SCLK_UART3, 45);
+const UART4_NODES: [ClkNode; 4] =
+    uart_nodes!(4, CLK_UART4_SRC, CLK_UART4_FRAC, CLK_UART4, SCLK_UART4, 47);
+const UART5_NODES: [ClkNode; 4] =
+    uart_nodes!(5, CLK_UART5_SRC, CLK_UART5_FRAC, CLK_UART5, SCLK_UART5, 49);
+const UART6_NODES: [ClkNode; 4] =
+    uart_nodes!(6, CLK_UART6_SRC, CLK_UART6_FRAC, CLK_UART6, SCLK_UART6, 51);
+const UART7_NODES: [ClkNode; 4] =
+    uart_nodes!(7, CLK_UART7_SRC, CLK_UART7_FRAC, CLK_UART7, SCLK_UART7, 53);
+const UART8_NODES: [ClkNode; 4] =
+    uart_nodes!(8, CLK_UART8_SRC, CLK_UART8_FRAC, CLK_UART8, SCLK_UART8, 55);
+const UART9_NODES: [ClkNode; 4] =
+    uart_nodes!(9, CLK_UART9_SRC, CLK_UART9_FRAC, CLK_UART9, SCLK_UART9, 57);
+
+/// UART 总线时钟与 PMU UART0
+const UART_NODES: &[ClkNode] = &[
+    ClkNode::gate(PCLK_UART1, "pclk_uart1", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_UART2, "pclk_uart2", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_UART3, "pclk_uart3", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_UART4, "pclk_uart4", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_UART5, "pclk_uart5", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_UART6, "pclk_uart6", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_UART7, "pclk_uart7", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_UART8, "pclk_uart8", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_UART9, "pclk_uart9", &[PCLK_TOP_ROOT], NONE),
+    // UART0 在 PMU CRU 中，src 固定来自 CPLL
+    ClkNode::div(
+        CLK_UART0_SRC,
+        "clk_uart0_src",
+        &[PLL_CPLL],
+        RegField::new(pmu_clksel_con(3), 7, 5),
+        NONE,
+    ),
+    ClkNode::frac(
+        CLK_UART0_FRAC,
+        "clk_uart0_frac",
+        &[CLK_UART0_SRC],
+        pmu_clksel_con(4),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_UART0,
+        "clk_uart0",
+        &[CLK_UART0_SRC, CLK_UART0_FRAC, XIN24M],
+        RegField::new(pmu_clksel_con(5), 0, 2),
+        NONE,
+    ),
+    ClkNode::gate(SCLK_UART0, "sclk_uart0", &[CLK_UART0], NONE),
+    ClkNode::gate(PCLK_UART0, "pclk_uart0", &[PCLK_PMU0_ROOT], NONE),
+];
+
+/// eMMC/SDIO/SDMMC/SFC 时钟
+const MMC_NODES: &[ClkNode] = &[
+    ClkNode::composite(
+        CCLK_EMMC,
+        "cclk_emmc",
+        GPLL_CPLL_24M_P,
+        RegField::new(clksel_con(77), clk_sel77::CCLK_EMMC_SEL_SHIFT, 2),
+        RegField::new(clksel_con(77), clk_sel77::CCLK_EMMC_DIV_SHIFT, 6),
+        NONE,
+    ),
+    ClkNode::composite(
+        BCLK_EMMC,
+        "bclk_emmc",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(78), clk_sel78::BCLK_EMMC_SEL_SHIFT, 1),
+        RegField::new(clksel_con(78), clk_sel78::BCLK_EMMC_DIV_SHIFT, 5),
+        NONE,
+    ),
+    ClkNode::gate(TMCLK_EMMC, "tmclk_emmc", XIN24M_P, NONE),
+    ClkNode::composite(
+        SCLK_SFC,
+        "sclk_sfc",
+        GPLL_CPLL_24M_P,
+        RegField::new(clksel_con(78), clk_sel78::SCLK_SFC_SEL_SHIFT, 2),
+        RegField::new(clksel_con(78), clk_sel78::SCLK_SFC_DIV_SHIFT, 6),
+        NONE,
+    ),
+    ClkNode::composite(
+        CCLK_SRC_SDIO,
+        "cclk_src_sdio",
+        GPLL_CPLL_24M_P,
+        RegField::new(clksel_con(172), clk_sel172::CCLK_SDIO_SRC_SEL_SHIFT, 2),
+        RegField::new(clksel_con(172), clk_sel172::CCLK_SDIO_SRC_DIV_SHIFT, 6),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_NVM_ROOT,
+        "hclk_nvm_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(77), 0, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        ACLK_NVM_ROOT,
+        "aclk_nvm_root",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(77), 7, 1),
+        RegField::new(clksel_con(77), 2, 5),
+        NONE,
+    ),
+    ClkNode::gate(HCLK_EMMC, "hclk_emmc", &[HCLK_NVM_ROOT], NONE),
+    ClkNode::gate(ACLK_EMMC, "aclk_emmc", &[ACLK_NVM_ROOT], NONE),
+    ClkNode::gate(HCLK_SFC, "hclk_sfc", &[HCLK_NVM_ROOT], NONE),
+    ClkNode::gate(HCLK_SFC_XIP, "hclk_sfc_xip", &[HCLK_NVM_ROOT], NONE),
+    ClkNode::mux(
+        HCLK_SDIO_ROOT,
+        "hclk_sdio_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(172), 0, 2),
+        NONE,
+    ),
+    ClkNode::gate(HCLK_SDIO, "hclk_sdio", &[HCLK_SDIO_ROOT], NONE),
+    // 相位时钟: 控制器内部对 cclk 二分频 (Linux: RK3288_MMC_CLKGEN_DIV)
+    ClkNode::factor(SCLK_SDIO_DRV, "sdio_drv", &[CCLK_SRC_SDIO], 1, 2, NONE),
+    ClkNode::factor(
+        SCLK_SDIO_SAMPLE,
+        "sdio_sample",
+        &[CCLK_SRC_SDIO],
+        1,
+        2,
+        NONE,
+    ),
+    // SD 卡控制器的时钟在 SECURE CRU 中 (Linux 经 SCMI 设置，u-boot 直接写寄存器)
+    ClkNode::composite(
+        CCLK_SRC_SDMMC,
+        "cclk_src_sdmmc",
+        GPLL_SPLL_24M_P,
+        RegField::new(secure_clksel_con(3), 12, 2),
+        RegField::new(secure_clksel_con(3), 6, 6),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_SDMMC,
+        "hclk_sdmmc",
+        MUX_150M_100M_50M_24M_P,
+        RegField::new(secure_clksel_con(1), 2, 2),
+        NONE,
+    ),
+    ClkNode::factor(SCLK_SDMMC_DRV, "sdmmc_drv", &[CCLK_SRC_SDMMC], 1, 2, NONE),
+    ClkNode::factor(
+        SCLK_SDMMC_SAMPLE,
+        "sdmmc_sample",
+        &[CCLK_SRC_SDMMC],
+        1,
+        2,
+        NONE,
+    ),
+];
+
+/// USB 时钟
+const USB_NODES: &[ClkNode] = &[
+    ClkNode::composite(
+        ACLK_USB_ROOT,
+        "aclk_usb_root",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(96), clk_sel96::ACLK_USB_ROOT_SEL_SHIFT, 1),
+        RegField::new(clksel_con(96), clk_sel96::ACLK_USB_ROOT_DIV_SHIFT, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_USB_ROOT,
+        "hclk_usb_root",
+        MUX_150M_100M_50M_24M_P,
+        RegField::new(clksel_con(96), clk_sel96::HCLK_USB_ROOT_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_UTMI_OTG2,
+        "clk_utmi_otg2",
+        MUX_150M_50M_24M_P,
+        RegField::new(clksel_con(84), clk_sel84::CLK_UTMI_OTG2_SEL_SHIFT, 2),
+        RegField::new(clksel_con(84), clk_sel84::CLK_UTMI_OTG2_DIV_SHIFT, 4),
+        NONE,
+    ),
+    ClkNode::gate(HCLK_HOST0, "hclk_host0", &[HCLK_USB_ROOT], NONE),
+    ClkNode::gate(HCLK_HOST_ARB0, "hclk_host_arb0", &[HCLK_USB_ROOT], NONE),
+    ClkNode::gate(HCLK_HOST1, "hclk_host1", &[HCLK_USB_ROOT], NONE),
+    ClkNode::gate(HCLK_HOST_ARB1, "hclk_host_arb1", &[HCLK_USB_ROOT], NONE),
+    ClkNode::gate(ACLK_USB3OTG0, "aclk_usb3otg0", &[ACLK_USB_ROOT], NONE),
+    ClkNode::gate(SUSPEND_CLK_USB3OTG0, "suspend_clk_usb3otg0", XIN24M_P, NONE),
+    ClkNode::gate(REF_CLK_USB3OTG0, "ref_clk_usb3otg0", XIN24M_P, NONE),
+    ClkNode::gate(ACLK_USB3OTG1, "aclk_usb3otg1", &[ACLK_USB_ROOT], NONE),
+    ClkNode::gate(SUSPEND_CLK_USB3OTG1, "suspend_clk_usb3otg1", XIN24M_P, NONE),
+    ClkNode::gate(REF_CLK_USB3OTG1, "ref_clk_usb3otg1", XIN24M_P, NONE),
+    // USB3OTG2 挂在 PHP 总线上
+    ClkNode::gate(ACLK_USB3OTG2, "aclk_usb3otg2", &[ACLK_MMU_PHP], NONE),
+    ClkNode::gate(SUSPEND_CLK_USB3OTG2, "suspend_clk_usb3otg2", XIN24M_P, NONE),
+    ClkNode::gate(REF_CLK_USB3OTG2, "ref_clk_usb3otg2", XIN24M_P, NONE),
+    // 48MHz 时钟由 USB PHY 提供
+    ClkNode::gate(UTMI_OHCI_CLK48_HOST0, "utmi_ohci_clk48_host0", &[], NONE),
+    ClkNode::gate(UTMI_OHCI_CLK48_HOST1, "utmi_ohci_clk48_host1", &[], NONE),
+    ClkNode::gate(PCLK_USBDPPHY0, "pclk_usbdpphy0", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(PCLK_USBDPPHY1, "pclk_usbdpphy1", &[PCLK_TOP_ROOT], NONE),
+    ClkNode::gate(USBDP_PHY0_IMMORTAL, "usbdp_phy0_immortal", XIN24M_P, NONE),
+    ClkNode::gate(USBDP_PHY1_IMMORTAL, "usbdp_phy1_immortal", XIN24M_P, NONE),
+    ClkNode::gate(
+        USBDPPHY_MIPIDCPPHY_REF,
+        "usbdpphy_mipidcpphy_ref",
+        XIN24M_P,
+        NONE,
+    ),
+];
+
+/// 视频编解码时钟
+///
+/// RKVDEC/RKVENC 各有两个实例，VPU121 和 JPEG 编解码器共享 VDPU 域的根时钟
+const VIDEO_NODES: &[ClkNode] = &[
+    // RKVDEC
+    ClkNode::mux(
+        HCLK_RKVDEC0_ROOT,
+        "hclk_rkvdec0_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(89), 0, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        ACLK_RKVDEC0_ROOT,
+        "aclk_rkvdec0_root",
+        GPLL_CPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(89), 7, 2),
+        RegField::new(clksel_con(89), 2, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        ACLK_RKVDEC_CCU,
+        "aclk_rkvdec_ccu",
+        GPLL_CPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(89), 14, 2),
+        RegField::new(clksel_con(89), 9, 5),
+        NONE,
+    ),
+    ClkNode::gate(HCLK_RKVDEC0, "hclk_rkvdec0", &[HCLK_RKVDEC0_ROOT], NONE),
+    ClkNode::gate(ACLK_RKVDEC0, "aclk_rkvdec0", &[ACLK_RKVDEC0_ROOT], NONE),
+    ClkNode::composite(
+        CLK_RKVDEC0_CA,
+        "clk_rkvdec0_ca",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(90), 5, 1),
+        RegField::new(clksel_con(90), 0, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_RKVDEC0_HEVC_CA,
+        "clk_rkvdec0_hevc_ca",
+        GPLL_CPLL_NPLL_1000M_P,
+        RegField::new(clksel_con(90), 11, 2),
+        RegField::new(clksel_con(90), 6, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_RKVDEC0_CORE,
+        "clk_rkvdec0_core",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(91), 5, 1),
+        RegField::new(clksel_con(91), 0, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_RKVDEC1_ROOT,
+        "hclk_rkvdec1_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(93), 0, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        ACLK_RKVDEC1_ROOT,
+        "aclk_rkvdec1_root",
+        GPLL_CPLL_AUPLL_NPLL_P,
+        RegField::new(clksel_con(93), 7, 2),
+        RegField::new(clksel_con(93), 2, 5),
+        NONE,
+    ),
+    ClkNode::gate(HCLK_RKVDEC1, "hclk_rkvdec1", &[HCLK_RKVDEC1_ROOT], NONE),
+    ClkNode::gate(ACLK_RKVDEC1, "aclk_rkvdec1", &[ACLK_RKVDEC1_ROOT], NONE),
+    ClkNode::composite(
+        CLK_RKVDEC1_CA,
+        "clk_rkvdec1_ca",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(93), 14, 1),
+        RegField::new(clksel_con(93), 9, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_RKVDEC1_HEVC_CA,
+        "clk_rkvdec1_hevc_ca",
+        GPLL_CPLL_NPLL_1000M_P,
+        RegField::new(clksel_con(94), 5, 2),
+        RegField::new(clksel_con(94), 0, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_RKVDEC1_CORE,
+        "clk_rkvdec1_core",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(94), 12, 1),
+        RegField::new(clksel_con(94), 7, 5),
+        NONE,
+    ),
+    // RKVENC
+    ClkNode::mux(
+        HCLK_RKVENC0_ROOT,
+        "hclk_rkvenc0_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(102), 0, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        ACLK_RKVENC0_ROOT,
+        "aclk_rkvenc0_root",
+        GPLL_CPLL_NPLL_P,
+        RegField::new(clksel_con(102), 7, 2),
+        RegField::new(clksel_con(102), 2, 5),
+        NONE,
+    ),
+    ClkNode::gate(HCLK_RKVENC0, "hclk_rkvenc0", &[HCLK_RKVENC0_ROOT], NONE),
+    ClkNode::gate(ACLK_RKVENC0, "aclk_rkvenc0", &[ACLK_RKVENC0_ROOT], NONE),
+    ClkNode::composite(
+        CLK_RKVENC0_CORE,
+        "clk_rkvenc0_core",
+        GPLL_CPLL_AUPLL_NPLL_P,
+        RegField::new(clksel_con(102), 14, 2),
+        RegField::new(clksel_con(102), 9, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_RKVENC1_ROOT,
+        "hclk_rkvenc1_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(104), 0, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        ACLK_RKVENC1_ROOT,
+        "aclk_rkvenc1_root",
+        GPLL_CPLL_NPLL_P,
+        RegField::new(clksel_con(104), 7, 2),
+        RegField::new(clksel_con(104), 2, 5),
+        NONE,
+    ),
+    ClkNode::gate(HCLK_RKVENC1, "hclk_rkvenc1", &[HCLK_RKVENC1_ROOT], NONE),
+    ClkNode::gate(ACLK_RKVENC1, "aclk_rkvenc1", &[ACLK_RKVENC1_ROOT], NONE),
+    ClkNode::composite(
+        CLK_RKVENC1_CORE,
+        "clk_rkvenc1_core",
+        GPLL_CPLL_AUPLL_NPLL_P,
+        RegField::new(clksel_con(104), 14, 2),
+        RegField::new(clksel_con(104), 9, 5),
+        NONE,
+    ),
+    // VDPU
+    ClkNode::composite(
+        ACLK_VDPU_ROOT,
+        "aclk_vdpu_root",
+        GPLL_CPLL_AUPLL_P,
+        RegField::new(clksel_con(98), 5, 2),
+        RegField::new(clksel_con(98), 0, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        ACLK_VDPU_LOW_ROOT,
+        "aclk_vdpu_low_root",
+        MUX_400M_200M_100M_24M_P,
+        RegField::new(clksel_con(98), 7, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_VDPU_ROOT,
+        "hclk_vdpu_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(98), 9, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        ACLK_JPEG_DECODER_ROOT,
+        "aclk_jpeg_decoder_root",
+        GPLL_CPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(99), 5, 2),
+        RegField::new(clksel_con(99), 0, 5),
+        NONE,
+    ),
+    ClkNode::gate(ACLK_VPU, "aclk_vpu", &[ACLK_VDPU_LOW_ROOT], NONE),
+    ClkNode::gate(HCLK_VPU, "hclk_vpu", &[HCLK_VDPU_ROOT], NONE),
+    ClkNode::gate(
+        ACLK_JPEG_ENCODER0,
+        "aclk_jpeg_encoder0",
+        &[ACLK_VDPU_LOW_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        HCLK_JPEG_ENCODER0,
+        "hclk_jpeg_encoder0",
+        &[HCLK_VDPU_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_JPEG_ENCODER1,
+        "aclk_jpeg_encoder1",
+        &[ACLK_VDPU_LOW_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        HCLK_JPEG_ENCODER1,
+        "hclk_jpeg_encoder1",
+        &[HCLK_VDPU_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_JPEG_ENCODER2,
+        "aclk_jpeg_encoder2",
+        &[ACLK_VDPU_LOW_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        HCLK_JPEG_ENCODER2,
+        "hclk_jpeg_encoder2",
+        &[HCLK_VDPU_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_JPEG_ENCODER3,
+        "aclk_jpeg_encoder3",
+        &[ACLK_VDPU_LOW_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        HCLK_JPEG_ENCODER3,
+        "hclk_jpeg_encoder3",
+        &[HCLK_VDPU_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_JPEG_DECODER,
+        "aclk_jpeg_decoder",
+        &[ACLK_JPEG_DECODER_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        HCLK_JPEG_DECODER,
+        "hclk_jpeg_decoder",
+        &[HCLK_VDPU_ROOT],
+        NONE,
+    ),
+    // AV1
+    ClkNode::composite(
+        ACLK_AV1_ROOT,
+        "aclk_av1_root",
+        GPLL_CPLL_AUPLL_P,
+        RegField::new(clksel_con(163), 5, 2),
+        RegField::new(clksel_con(163), 0, 5),
+        NONE,
+    ),
+    ClkNode::gate(ACLK_AV1, "aclk_av1", &[ACLK_AV1_ROOT], NONE),
+    ClkNode::mux(
+        PCLK_AV1_ROOT,
+        "pclk_av1_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(163), 7, 2),
+        NONE,
+    ),
+    ClkNode::gate(PCLK_AV1, "pclk_av1", &[PCLK_AV1_ROOT], NONE),
+];
+
+/// 摄像头 (VI/ISP) 时钟
+///
+/// VICAP、ISP0、FISHEYE 和 MIPI CSI host 在 VI 域，ISP1 有独立的电源域和根时钟。
+/// 摄像头主时钟 (MCLK) 输出使用 8 位分频，可以从 24M/SPLL/GPLL/CPLL 中
+/// 精确得到常用的传感器频率 (如 GPLL / 32 = 37.125MHz)
+const CAMERA_NODES: &[ClkNode] = &[
+    // VI
+    ClkNode::composite(
+        ACLK_VI_ROOT,
+        "aclk_vi_root",
+        GPLL_CPLL_NPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(106), 5, 3),
+        RegField::new(clksel_con(106), 0, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_VI_ROOT,
+        "hclk_vi_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(106), 8, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        PCLK_VI_ROOT,
+        "pclk_vi_root",
+        MUX_100M_50M_24M_P,
+        RegField::new(clksel_con(106), 10, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        DCLK_VICAP,
+        "dclk_vicap",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(107), 5, 1),
+        RegField::new(clksel_con(107), 0, 5),
+        NONE,
+    ),
+    ClkNode::gate(ACLK_VICAP, "aclk_vicap", &[ACLK_VI_ROOT], NONE),
+    ClkNode::gate(HCLK_VICAP, "hclk_vicap", &[HCLK_VI_ROOT], NONE),
+    ClkNode::composite(
+        CLK_ISP0_CORE,
+        "clk_isp0_core",
+        GPLL_CPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(107), 11, 2),
+        RegField::new(clksel_con(107), 6, 5),
+        NONE,
+    ),
+    ClkNode::gate(
+        CLK_ISP0_CORE_MARVIN,
+        "clk_isp0_core_marvin",
+        &[CLK_ISP0_CORE],
+        NONE,
+    ),
+    ClkNode::gate(
+        CLK_ISP0_CORE_VICAP,
+        "clk_isp0_core_vicap",
+        &[CLK_ISP0_CORE],
+        NONE,
+    ),
+    ClkNode::gate(ACLK_ISP0, "aclk_isp0", &[ACLK_VI_ROOT], NONE),
+    ClkNode::gate(HCLK_ISP0, "hclk_isp0", &[HCLK_VI_ROOT], NONE),
+    ClkNode::gate(ACLK_FISHEYE0, "aclk_fisheye0", &[ACLK_VI_ROOT], NONE),
+    ClkNode::gate(HCLK_FISHEYE0, "hclk_fisheye0", &[HCLK_VI_ROOT], NONE),
+    ClkNode::composite(
+        CLK_FISHEYE0_CORE,
+        "clk_fisheye0_core",
+        GPLL_CPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(108), 5, 2),
+        RegField::new(clksel_con(108), 0, 5),
+        NONE,
+    ),
+    ClkNode::gate(ACLK_FISHEYE1, "aclk_fisheye1", &[ACLK_VI_ROOT], NONE),
+    ClkNode::gate(HCLK_FISHEYE1, "hclk_fisheye1", &[HCLK_VI_ROOT], NONE),
+    ClkNode::composite(
+        CLK_FISHEYE1_CORE,
+        "clk_fisheye1_core",
+        GPLL_CPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(108), 12, 2),
+        RegField::new(clksel_con(108), 7, 5),
+        NONE,
+    ),
+    ClkNode::gate(PCLK_CSI_HOST_0, "pclk_csi_host_0", &[PCLK_VI_ROOT], NONE),
+    ClkNode::gate(PCLK_CSI_HOST_1, "pclk_csi_host_1", &[PCLK_VI_ROOT], NONE),
+    ClkNode::gate(PCLK_CSI_HOST_2, "pclk_csi_host_2", &[PCLK_VI_ROOT], NONE),
+    ClkNode::gate(PCLK_CSI_HOST_3, "pclk_csi_host_3", &[PCLK_VI_ROOT], NONE),
+    ClkNode::gate(PCLK_CSI_HOST_4, "pclk_csi_host_4", &[PCLK_VI_ROOT], NONE),
+    ClkNode::gate(PCLK_CSI_HOST_5, "pclk_csi_host_5", &[PCLK_VI_ROOT], NONE),
+    // ISP1
+    ClkNode::composite(
+        ACLK_ISP1_ROOT,
+        "aclk_isp1_root",
+        GPLL_CPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(67), 5, 2),
+        RegField::new(clksel_con(67), 0, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_ISP1_ROOT,
+        "hclk_isp1_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(67), 7, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_ISP1_CORE,
+        "clk_isp1_core",
+        GPLL_CPLL_AUPLL_SPLL_P,
+        RegField::new(clksel_con(67), 14, 2),
+        RegField::new(clksel_con(67), 9, 5),
+        NONE,
+    ),
+    ClkNode::gate(
+        CLK_ISP1_CORE_MARVIN,
+        "clk_isp1_core_marvin",
+        &[CLK_ISP1_CORE],
+        NONE,
+    ),
+    ClkNode::gate(
+        CLK_ISP1_CORE_VICAP,
+        "clk_isp1_core_vicap",
+        &[CLK_ISP1_CORE],
+        NONE,
+    ),
+    ClkNode::gate(ACLK_ISP1, "aclk_isp1", &[ACLK_ISP1_ROOT], NONE),
+    ClkNode::gate(HCLK_ISP1, "hclk_isp1", &[HCLK_ISP1_ROOT], NONE),
+    // MCLK 输出
+    ClkNode::composite(
+        CLK_CIFOUT_OUT,
+        "clk_cifout_out",
+        GPLL_CPLL_24M_SPLL_P,
+        RegField::new(clksel_con(17), 8, 2),
+        RegField::new(clksel_con(17), 0, 8),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_MIPI_CAMARAOUT_M0,
+        "clk_mipi_camaraout_m0",
+        MUX_24M_SPLL_GPLL_CPLL_P,
+        RegField::new(clksel_con(18), 8, 2),
+        RegField::new(clksel_con(18), 0, 8),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_MIPI_CAMARAOUT_M1,
+        "clk_mipi_camaraout_m1",
+        MUX_24M_SPLL_GPLL_CPLL_P,
+        RegField::new(clksel_con(19), 8, 2),
+        RegField::new(clksel_con(19), 0, 8),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_MIPI_CAMARAOUT_M2,
+        "clk_mipi_camaraout_m2",
+        MUX_24M_SPLL_GPLL_CPLL_P,
+        RegField::new(clksel_con(20), 8, 2),
+        RegField::new(clksel_con(20), 0, 8),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_MIPI_CAMARAOUT_M3,
+        "clk_mipi_camaraout_m3",
+        MUX_24M_SPLL_GPLL_CPLL_P,
+        RegField::new(clksel_con(21), 8, 2),
+        RegField::new(clksel_con(21), 0, 8),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_MIPI_CAMARAOUT_M4,
+        "clk_mipi_camaraout_m4",
+        MUX_24M_SPLL_GPLL_CPLL_P,
+        RegField::new(clksel_con(22), 8, 2),
+        RegField::new(clksel_con(22), 0, 8),
+        NONE,
+    ),
+];
+
+/// GPU (Mali-G610) 时钟
+///
+/// GPU 核心时钟来自 clk_gpu_src 或 GPU PVTPLL (CLKSEL_CON(158) bit14)。
+/// PVTPLL 的频率由 GPU_GRF 中的环形振荡器配置决定，这里作为孤儿时钟建模
+const GPU_NODES: &[ClkNode] = &[
+    ClkNode::gate(CLK_GPU_PVTPLL, "clk_gpu_pvtpll", &[], NONE),
+    ClkNode::composite(
+        CLK_GPU_SRC,
+        "clk_gpu_src",
+        GPLL_CPLL_AUPLL_NPLL_SPLL_P,
+        RegField::new(clksel_con(158), 5, 3),
+        RegField::new(clksel_con(158), 0, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_GPU,
+        "clk_gpu",
+        GPU_P,
+        RegField::new(clksel_con(158), 14, 1),
+        NONE,
+    ),
+    ClkNode::gate(CLK_GPU_COREGROUP, "clk_gpu_coregroup", &[CLK_GPU_SRC], NONE),
+    ClkNode::div(
+        CLK_GPU_STACKS,
+        "clk_gpu_stacks",
+        &[CLK_GPU_SRC],
+        RegField::new(clksel_con(159), 0, 5),
+        NONE,
+    ),
+    ClkNode::div(
+        ACLK_S_GPU_BIU,
+        "aclk_s_gpu_biu",
+        &[CLK_GPU_SRC],
+        RegField::new(clksel_con(159), 5, 5),
+        NONE,
+    ),
+    ClkNode::div(
+        ACLK_M0_GPU_BIU,
+        "aclk_m0_gpu_biu",
+        &[CLK_GPU_SRC],
+        RegField::new(clksel_con(159), 10, 5),
+        NONE,
+    ),
+    ClkNode::div(
+        ACLK_M1_GPU_BIU,
+        "aclk_m1_gpu_biu",
+        &[CLK_GPU_SRC],
+        RegField::new(clksel_con(160), 0, 5),
+        NONE,
+    ),
+    ClkNode::div(
+        ACLK_M2_GPU_BIU,
+        "aclk_m2_gpu_biu",
+        &[CLK_GPU_SRC],
+        RegField::new(clksel_con(160), 5, 5),
+        NONE,
+    ),
+    ClkNode::div(
+        ACLK_M3_GPU_BIU,
+        "aclk_m3_gpu_biu",
+        &[CLK_GPU_SRC],
+        RegField::new(clksel_con(160), 10, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        PCLK_GPU_ROOT,
+        "pclk_gpu_root",
+        MUX_100M_50M_24M_P,
+        RegField::new(clksel_con(161), 0, 2),
+        NONE,
+    ),
+    ClkNode::gate(PCLK_GPU_GRF, "pclk_gpu_grf", &[PCLK_GPU_ROOT], NONE),
+    ClkNode::gate(CLK_GPU_PVTM, "clk_gpu_pvtm", XIN24M_P, NONE),
+    ClkNode::gate(CLK_CORE_GPU_PVTM, "clk_core_gpu_pvtm", &[CLK_GPU_SRC], NONE),
+];
+
+/// NPU 时钟
+///
+/// 三个 NPU 核的 ACLK 来自 clk_npu_dsu0 或 NPU PVTPLL (CLKSEL_CON(74) bit0)。
+/// PVTPLL 的频率由 NPU_GRF 中的环形振荡器配置决定，这里作为孤儿时钟建模
+const NPU_NODES: &[ClkNode] = &[
+    ClkNode::gate(CLK_NPU_PVTPLL, "clk_npu_pvtpll", &[], NONE),
+    ClkNode::mux(
+        HCLK_NPU_ROOT,
+        "hclk_npu_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(73), 0, 2),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        CLK_NPU_DSU0,
+        "clk_npu_dsu0",
+        GPLL_CPLL_AUPLL_NPLL_SPLL_P,
+        RegField::new(clksel_con(73), 7, 3),
+        RegField::new(clksel_con(73), 2, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_NPU_MUX,
+        "clk_npu_mux",
+        NPU_MUX_P,
+        RegField::new(clksel_con(74), 0, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        PCLK_NPU_ROOT,
+        "pclk_npu_root",
+        MUX_100M_50M_24M_P,
+        RegField::new(clksel_con(74), 1, 2),
+        CRITICAL,
+    ),
+    ClkNode::gate(ACLK_NPU0, "aclk_npu0", &[CLK_NPU_MUX], NONE),
+    ClkNode::gate(ACLK_NPU1, "aclk_npu1", &[CLK_NPU_MUX], NONE),
+    ClkNode::gate(ACLK_NPU2, "aclk_npu2", &[CLK_NPU_MUX], NONE),
+    ClkNode::gate(HCLK_NPU0, "hclk_npu0", &[HCLK_NPU_ROOT], NONE),
+    ClkNode::gate(HCLK_NPU1, "hclk_npu1", &[HCLK_NPU_ROOT], NONE),
+    ClkNode::gate(HCLK_NPU2, "hclk_npu2", &[HCLK_NPU_ROOT], NONE),
+    ClkNode::gate(PCLK_NPU_PVTM, "pclk_npu_pvtm", &[PCLK_NPU_ROOT], NONE),
+    ClkNode::gate(PCLK_NPU_GRF, "pclk_npu_grf", &[PCLK_NPU_ROOT], NONE),
+    ClkNode::gate(CLK_NPU_PVTM, "clk_npu_pvtm", XIN24M_P, NONE),
+    ClkNode::gate(
+        CLK_CORE_NPU_PVTM,
+        "clk_core_npu_pvtm",
+        &[CLK_NPU_DSU0],
+        NONE,
+    ),
+];
+
+/// PHP 域时钟 (PCIe、SATA、GMAC 和 USB3OTG2 的总线)
+///
+/// PCIe 控制器的 PIPE 时钟来自 PCIe3 PHY 或 COMBPHY 的输出，频率由 PHY 决定，
+/// 这里作为孤儿时钟建模。PCIe3 PHY 的参考时钟门控在 PHP CRU 中
+const PHP_NODES: &[ClkNode] = &[
+    ClkNode::mux(
+        PCLK_PHP_ROOT,
+        "pclk_php_root",
+        MUX_150M_50M_24M_P,
+        RegField::new(clksel_con(80), 0, 2),
+        CRITICAL,
+    ),
+    ClkNode::composite(
+        ACLK_PCIE_ROOT,
+        "aclk_pcie_root",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(80), 7, 1),
+        RegField::new(clksel_con(80), 2, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        ACLK_PHP_ROOT,
+        "aclk_php_root",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(80), 13, 1),
+        RegField::new(clksel_con(80), 8, 5),
+        CRITICAL,
+    ),
+    ClkNode::gate(
+        ACLK_PHP_GIC_ITS,
+        "aclk_php_gic_its",
+        &[ACLK_PCIE_ROOT],
+        CRITICAL,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_BRIDGE,
+        "aclk_pcie_bridge",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(ACLK_MMU_PCIE, "aclk_mmu_pcie", &[ACLK_PCIE_BRIDGE], NONE),
+    ClkNode::gate(ACLK_MMU_PHP, "aclk_mmu_php", &[ACLK_PHP_ROOT], NONE),
+    // PCIe 控制器
+    ClkNode::gate(
+        ACLK_PCIE_4L_DBI,
+        "aclk_pcie_4l_dbi",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_2L_DBI,
+        "aclk_pcie_2l_dbi",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L0_DBI,
+        "aclk_pcie_1l0_dbi",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L1_DBI,
+        "aclk_pcie_1l1_dbi",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L2_DBI,
+        "aclk_pcie_1l2_dbi",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_4L_MSTR,
+        "aclk_pcie_4l_mstr",
+        &[ACLK_MMU_PCIE],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_2L_MSTR,
+        "aclk_pcie_2l_mstr",
+        &[ACLK_MMU_PCIE],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L0_MSTR,
+        "aclk_pcie_1l0_mstr",
+        &[ACLK_MMU_PCIE],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L1_MSTR,
+        "aclk_pcie_1l1_mstr",
+        &[ACLK_MMU_PCIE],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L2_MSTR,
+        "aclk_pcie_1l2_mstr",
+        &[ACLK_MMU_PCIE],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_4L_SLV,
+        "aclk_pcie_4l_slv",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_2L_SLV,
+        "aclk_pcie_2l_slv",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L0_SLV,
+        "aclk_pcie_1l0_slv",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L1_SLV,
+        "aclk_pcie_1l1_slv",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(
+        ACLK_PCIE_1L2_SLV,
+        "aclk_pcie_1l2_slv",
+        &[ACLK_PCIE_ROOT],
+        NONE,
+    ),
+    ClkNode::gate(PCLK_PCIE_4L, "pclk_pcie_4l", &[PCLK_PHP_ROOT], NONE),
+    ClkNode::gate(PCLK_PCIE_2L, "pclk_pcie_2l", &[PCLK_PHP_ROOT], NONE),
+    ClkNode::gate(PCLK_PCIE_1L0, "pclk_pcie_1l0", &[PCLK_PHP_ROOT], NONE),
+    ClkNode::gate(PCLK_PCIE_1L1, "pclk_pcie_1l1", &[PCLK_PHP_ROOT], NONE),
+    ClkNode::gate(PCLK_PCIE_1L2, "pclk_pcie_1l2", &[PCLK_PHP_ROOT], NONE),
+    ClkNode::gate(CLK_PCIE_AUX0, "clk_pcie_aux0", XIN24M_P, NONE),
+    ClkNode::gate(CLK_PCIE_AUX1, "clk_pcie_aux1", XIN24M_P, NONE),
+    ClkNode::gate(CLK_PCIE_AUX2, "clk_pcie_aux2", XIN24M_P, NONE),
+    ClkNode::gate(CLK_PCIE_AUX3, "clk_pcie_aux3", XIN24M_P, NONE),
+    ClkNode::gate(CLK_PCIE_AUX4, "clk_pcie_aux4", XIN24M_P, NONE),
+    ClkNode::gate(CLK_PCIE4L_PIPE, "clk_pcie4l_pipe", &[], NONE),
+    ClkNode::gate(CLK_PCIE2L_PIPE, "clk_pcie2l_pipe", &[], NONE),
+    ClkNode::gate(CLK_PCIE1L0_PIPE, "clk_pcie1l0_pipe", &[], NONE),
+    ClkNode::gate(CLK_PCIE1L1_PIPE, "clk_pcie1l1_pipe", &[], NONE),
+    ClkNode::gate(CLK_PCIE1L2_PIPE, "clk_pcie1l2_pipe", &[], NONE),
+    // SATA
+    ClkNode::gate(ACLK_SATA0, "aclk_sata0", &[ACLK_MMU_PHP], NONE),
+    ClkNode::gate(ACLK_SATA1, "aclk_sata1", &[ACLK_MMU_PHP], NONE),
+    ClkNode::gate(ACLK_SATA2, "aclk_sata2", &[ACLK_MMU_PHP], NONE),
+    ClkNode::composite(
+        CLK_RXOOB0,
+        "clk_rxoob0",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(82), 7, 1),
+        RegField::new(clksel_con(82), 0, 7),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_RXOOB1,
+        "clk_rxoob1",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(82), 15, 1),
+        RegField::new(clksel_con(82), 8, 7),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_RXOOB2,
+        "clk_rxoob2",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(83), 7, 1),
+        RegField::new(clksel_con(83), 0, 7),
+        NONE,
+    ),
+    // PHP CRU
+    ClkNode::gate(CLK_PHY0_REF_ALT_P, "clk_phy0_ref_alt_p", &[PLL_PPLL], NONE),
+    ClkNode::gate(CLK_PHY0_REF_ALT_M, "clk_phy0_ref_alt_m", &[PLL_PPLL], NONE),
+    ClkNode::gate(CLK_PHY1_REF_ALT_P, "clk_phy1_ref_alt_p", &[PLL_PPLL], NONE),
+    ClkNode::gate(CLK_PHY1_REF_ALT_M, "clk_phy1_ref_alt_m", &[PLL_PPLL], NONE),
+];
+
+/// GMAC 时钟
+///
+/// PTP 参考时钟的另一个父时钟 clk_gmacN_ptpref_io 来自外部引脚，未建模
+const GMAC_NODES: &[ClkNode] = &[
+    ClkNode::gate(PCLK_GMAC0, "pclk_gmac0", &[PCLK_PHP_ROOT], NONE),
+    ClkNode::gate(PCLK_GMAC1, "pclk_gmac1", &[PCLK_PHP_ROOT], NONE),
+    ClkNode::gate(ACLK_GMAC0, "aclk_gmac0", &[ACLK_MMU_PHP], NONE),
+    ClkNode::gate(ACLK_GMAC1, "aclk_gmac1", &[ACLK_MMU_PHP], NONE),
+    ClkNode::composite(
+        CLK_GMAC0_PTP_REF,
+        "clk_gmac0_ptp_ref",
+        &[PLL_CPLL],
+        RegField::new(clksel_con(81), clk_sel81::CLK_GMAC0_PTP_SEL_SHIFT, 1),
+        RegField::new(clksel_con(81), clk_sel81::CLK_GMAC0_PTP_DIV_SHIFT, 6),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_GMAC1_PTP_REF,
+        "clk_gmac1_ptp_ref",
+        &[PLL_CPLL],
+        RegField::new(clksel_con(81), clk_sel81::CLK_GMAC1_PTP_SEL_SHIFT, 1),
+        RegField::new(clksel_con(81), clk_sel81::CLK_GMAC1_PTP_DIV_SHIFT, 6),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_GMAC_125M,
+        "clk_gmac_125m",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(83), clk_sel83::CLK_GMAC_125M_SEL_SHIFT, 1),
+        RegField::new(clksel_con(83), clk_sel83::CLK_GMAC_125M_DIV_SHIFT, 7),
+        NONE,
+    ),
+    ClkNode::composite(
+        CLK_GMAC_50M,
+        "clk_gmac_50m",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(84), clk_sel84::CLK_GMAC_50M_SEL_SHIFT, 1),
+        RegField::new(clksel_con(84), clk_sel84::CLK_GMAC_50M_DIV_SHIFT, 7),
+        NONE,
+    ),
+    // 以太网 PHY 的 25MHz 参考时钟输出
+    ClkNode::composite(
+        REFCLKO25M_ETH0_OUT,
+        "refclko25m_eth0_out",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(15), 7, 1),
+        RegField::new(clksel_con(15), 0, 7),
+        NONE,
+    ),
+    ClkNode::composite(
+        REFCLKO25M_ETH1_OUT,
+        "refclko25m_eth1_out",
+        GPLL_CPLL_P,
+        RegField::new(clksel_con(15), 15, 1),
+        RegField::new(clksel_con(15), 8, 7),
+        NONE,
+    ),
+];
+
+/// 生成一组音频时钟节点: src (GPLL/AUPLL 分频) -> frac -> mux -> mclk 门控
+///
+/// 参考 Linux: clk-rk3588.c 中 I2S/SPDIF 的 COMPOSITE + COMPOSITE_FRACMUX
+///
+/// - clksel_con(reg): src 选择 [sel_shift], 分频 [div_shift + 4:div_shift]
+/// - clksel_con(reg + 1): 小数分频
+/// - clksel_con(reg + 2): mux 选择 [1:0] (src/frac/额外父时钟)
+macro_rules! audio_nodes {
+    ($n:literal, $src:ident, $frac:ident, $clk:ident, $mclk:ident,
+     $reg:expr, $sel_shift:expr, $div_shift:expr $(, $ext:ident)*) => {
+        [
+            ClkNode::composite(
+                $src,
+                concat!("clk_", $n, "_src"),
+                GPLL_AUPLL_P,
+                RegField::new(clksel_con($reg), $sel_shift, 1),
+                RegField::new(clksel_con($reg), $div_shift, 5),
+                NONE,
+            ),
+            ClkNode::frac(
+                $frac,
+                concat!("clk_", $n, "_frac"),
+                &[$src],
+                clksel_con($reg + 1),
+                NONE,
+            ),
+            ClkNode::mux(
+                $clk,
+                concat!("clk_", $n),
+                &[$src, $frac $(, $ext)*],
+                RegField::new(clksel_con($reg + 2), 0, 2),
+                NONE,
+            ),
+            ClkNode::gate($mclk, concat!("mclk_", $n), &[$clk], NONE),
+        ]
+    };
+}
+
+const I2S0_TX_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s0_8ch_tx",
+    CLK_I2S0_8CH_TX_SRC,
+    CLK_I2S0_8CH_TX_FRAC,
+    CLK_I2S0_8CH_TX,
+    MCLK_I2S0_8CH_TX,
+    24,
+    9,
+    4,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S0_RX_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s0_8ch_rx",
+    CLK_I2S0_8CH_RX_SRC,
+    CLK_I2S0_8CH_RX_FRAC,
+    CLK_I2S0_8CH_RX,
+    MCLK_I2S0_8CH_RX,
+    26,
+    7,
+    2,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S2_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s2_2ch",
+    CLK_I2S2_2CH_SRC,
+    CLK_I2S2_2CH_FRAC,
+    CLK_I2S2_2CH,
+    MCLK_I2S2_2CH,
+    28,
+    9,
+    4,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S3_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s3_2ch",
+    CLK_I2S3_2CH_SRC,
+    CLK_I2S3_2CH_FRAC,
+    CLK_I2S3_2CH,
+    MCLK_I2S3_2CH,
+    30,
+    8,
+    3,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S4_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s4_8ch_tx",
+    CLK_I2S4_8CH_TX_SRC,
+    CLK_I2S4_8CH_TX_FRAC,
+    CLK_I2S4_8CH_TX,
+    MCLK_I2S4_8CH_TX,
+    118,
+    5,
+    0,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S5_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s5_8ch_tx",
+    CLK_I2S5_8CH_TX_SRC,
+    CLK_I2S5_8CH_TX_FRAC,
+    CLK_I2S5_8CH_TX,
+    MCLK_I2S5_8CH_TX,
+    140,
+    10,
+    5,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S6_TX_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s6_8ch_tx",
+    CLK_I2S6_8CH_TX_SRC,
+    CLK_I2S6_8CH_TX_FRAC,
+    CLK_I2S6_8CH_TX,
+    MCLK_I2S6_8CH_TX,
+    144,
+    8,
+    3,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S6_RX_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s6_8ch_rx",
+    CLK_I2S6_8CH_RX_SRC,
+    CLK_I2S6_8CH_RX_FRAC,
+    CLK_I2S6_8CH_RX,
+    MCLK_I2S6_8CH_RX,
+    146,
+    7,
+    2,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S7_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s7_8ch_rx",
+    CLK_I2S7_8CH_RX_SRC,
+    CLK_I2S7_8CH_RX_FRAC,
+    CLK_I2S7_8CH_RX,
+    MCLK_I2S7_8CH_RX,
+    129,
+    11,
+    6,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S8_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s8_8ch_tx",
+    CLK_I2S8_8CH_TX_SRC,
+    CLK_I2S8_8CH_TX_FRAC,
+    CLK_I2S8_8CH_TX,
+    MCLK_I2S8_8CH_TX,
+    120,
+    8,
+    3,
+    I2S_MCLKIN,
+    XIN12M
+);
+const I2S9_NODES: [ClkNode; 4] = audio_nodes!(
+    "i2s9_8ch_rx",
+    CLK_I2S9_8CH_RX_SRC,
+    CLK_I2S9_8CH_RX_FRAC,
+    CLK_I2S9_8CH_RX,
+    MCLK_I2S9_8CH_RX,
+    155,
+    5,
+    0,
+    I2S_MCLKIN,
+    XIN12M
+);
+const SPDIF0_NODES: [ClkNode; 4] = audio_nodes!(
+    "spdif0",
+    CLK_SPDIF0_SRC,
+    CLK_SPDIF0_FRAC,
+    CLK_SPDIF0,
+    MCLK_SPDIF0,
+    32,
+    8,
+    3,
+    XIN12M
+);
+const SPDIF1_NODES: [ClkNode; 4] = audio_nodes!(
+    "spdif1",
+    CLK_SPDIF1_SRC,
+    CLK_SPDIF1_FRAC,
+    CLK_SPDIF1,
+    MCLK_SPDIF1,
+    34,
+    7,
+    2,
+    XIN12M
+);
+
+/// 音频公共时钟: MCLK 输出、SPDIF RX、PDM 和 PMU CRU 中的 I2S1
+///
+/// I2S_MCLKIN 代表外部 i2sN_mclkin 输入，频率由板级决定，作为孤儿时钟建模。
+/// I2Sx_MCLKOUT_TO_IO 的门控位于 SYS_GRF
+const AUDIO_NODES: &[ClkNode] = &[
+    ClkNode::factor(XIN12M, "xin12m", XIN24M_P, 1, 2, NONE),
+    ClkNode::gate(I2S_MCLKIN, "i2s_mclkin", &[], NONE),
+    ClkNode::mux(
+        I2S0_8CH_MCLKOUT,
+        "i2s0_8ch_mclkout",
+        &[MCLK_I2S0_8CH_TX, MCLK_I2S0_8CH_RX, XIN12M],
+        RegField::new(clksel_con(28), 2, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        I2S2_2CH_MCLKOUT,
+        "i2s2_2ch_mclkout",
+        &[MCLK_I2S2_2CH, XIN12M],
+        RegField::new(clksel_con(30), 2, 1),
+        NONE,
+    ),
+    ClkNode::mux(
+        I2S3_2CH_MCLKOUT,
+        "i2s3_2ch_mclkout",
+        &[MCLK_I2S3_2CH, XIN12M],
+        RegField::new(clksel_con(32), 2, 1),
+        NONE,
+    ),
+    ClkNode::composite(
+        MCLK_PDM1,
+        "mclk_pdm1",
+        GPLL_CPLL_AUPLL_P,
+        RegField::new(clksel_con(36), 7, 2),
+        RegField::new(clksel_con(36), 2, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        MCLK_SPDIFRX0,
+        "mclk_spdifrx0",
+        GPLL_CPLL_AUPLL_P,
+        RegField::new(clksel_con(148), 9, 2),
+        RegField::new(clksel_con(148), 4, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        MCLK_SPDIFRX1,
+        "mclk_spdifrx1",
+        GPLL_CPLL_AUPLL_P,
+        RegField::new(clksel_con(149), 7, 2),
+        RegField::new(clksel_con(149), 2, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        MCLK_SPDIFRX2,
+        "mclk_spdifrx2",
+        GPLL_CPLL_AUPLL_P,
+        RegField::new(clksel_con(150), 5, 2),
+        RegField::new(clksel_con(150), 0, 5),
+        NONE,
+    ),
+    // I2S1 在 PMU CRU 中，src 固定来自 CPLL
+    ClkNode::div(
+        CLK_I2S1_8CH_TX_SRC,
+        "clk_i2s1_8ch_tx_src",
+        &[PLL_CPLL],
+        RegField::new(pmu_clksel_con(5), 2, 5),
+        NONE,
+    ),
+    ClkNode::frac(
+        CLK_I2S1_8CH_TX_FRAC,
+        "clk_i2s1_8ch_tx_frac",
+        &[CLK_I2S1_8CH_TX_SRC],
+        pmu_clksel_con(6),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2S1_8CH_TX,
+        "clk_i2s1_8ch_tx",
+        &[
+            CLK_I2S1_8CH_TX_SRC,
+            CLK_I2S1_8CH_TX_FRAC,
+            I2S_MCLKIN,
+            XIN12M,
+        ],
+        RegField::new(pmu_clksel_con(7), 0, 2),
+        NONE,
+    ),
+    ClkNode::gate(
+        MCLK_I2S1_8CH_TX,
+        "mclk_i2s1_8ch_tx",
+        &[CLK_I2S1_8CH_TX],
+        NONE,
+    ),
+    ClkNode::div(
+        CLK_I2S1_8CH_RX_SRC,
+        "clk_i2s1_8ch_rx_src",
+        &[PLL_CPLL],
+        RegField::new(pmu_clksel_con(7), 2, 5),
+        NONE,
+    ),
+    ClkNode::frac(
+        CLK_I2S1_8CH_RX_FRAC,
+        "clk_i2s1_8ch_rx_frac",
+        &[CLK_I2S1_8CH_RX_SRC],
+        pmu_clksel_con(8),
+        NONE,
+    ),
+    ClkNode::mux(
+        CLK_I2S1_8CH_RX,
+        "clk_i2s1_8ch_rx",
+        &[
+            CLK_I2S1_8CH_RX_SRC,
+            CLK_I2S1_8CH_RX_FRAC,
+            I2S_MCLKIN,
+            XIN12M,
+        ],
+        RegField::new(pmu_clksel_con(9), 0, 2),
+        NONE,
+    ),
+    ClkNode::gate(
+        MCLK_I2S1_8CH_RX,
+        "mclk_i2s1_8ch_rx",
+        &[CLK_I2S1_8CH_RX],
+        NONE,
+    ),
+    ClkNode::mux(
+        I2S1_8CH_MCLKOUT,
+        "i2s1_8ch_mclkout",
+        &[MCLK_I2S1_8CH_TX, MCLK_I2S1_8CH_RX, XIN12M],
+        RegField::new(pmu_clksel_con(9), 2, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        MCLK_PDM0,
+        "mclk_pdm0",
+        &[CLK_PMU1_300M_SRC, CLK_PMU1_400M_SRC],
+        RegField::new(pmu_clksel_con(9), 9, 1),
+        RegField::new(pmu_clksel_con(9), 4, 5),
+        NONE,
+    ),
+    // MCLK 输出到 IO，门控在 SYS_GRF_SOC_CON6
+    ClkNode::gate(
+        I2S0_8CH_MCLKOUT_TO_IO,
+        "i2s0_8ch_mclkout_to_io",
+        &[I2S0_8CH_MCLKOUT],
+        NONE,
+    ),
+    ClkNode::gate(
+        I2S1_8CH_MCLKOUT_TO_IO,
+        "i2s1_8ch_mclkout_to_io",
+        &[I2S1_8CH_MCLKOUT],
+        NONE,
+    ),
+    ClkNode::gate(
+        I2S2_2CH_MCLKOUT_TO_IO,
+        "i2s2_2ch_mclkout_to_io",
+        &[I2S2_2CH_MCLKOUT],
+        NONE,
+    ),
+    ClkNode::gate(
+        I2S3_2CH_MCLKOUT_TO_IO,
+        "i2s3_2ch_mclkout_to_io",
+        &[I2S3_2CH_MCLKOUT],
+        NONE,
+    ),
+];
+
+/// VOP (显示控制器) 时钟
+///
+/// DCLK_VOP0~2 可以选择 HDMI PHY 的像素时钟 (clk_hdmiphy_pixelN)，
+/// 其频率由 HDMI PHY 决定，这里作为孤儿时钟建模
+const VOP_NODES: &[ClkNode] = &[
+    ClkNode::gate(CLK_HDMIPHY_PIXEL0, "clk_hdmiphy_pixel0", &[], NONE),
+    ClkNode::gate(CLK_HDMIPHY_PIXEL1, "clk_hdmiphy_pixel1", &[], NONE),
+    ClkNode::composite(
+        ACLK_VOP_ROOT,
+        "aclk_vop_root",
+        GPLL_CPLL_AUPLL_NPLL_SPLL_P,
+        RegField::new(clksel_con(110), clk_sel110::ACLK_VOP_ROOT_SEL_SHIFT, 3),
+        RegField::new(clksel_con(110), clk_sel110::ACLK_VOP_ROOT_DIV_SHIFT, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        ACLK_VOP_LOW_ROOT,
+        "aclk_vop_low_root",
+        MUX_400M_200M_100M_24M_P,
+        RegField::new(clksel_con(110), clk_sel110::ACLK_VOP_LOW_ROOT_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        HCLK_VOP_ROOT,
+        "hclk_vop_root",
+        MUX_200M_100M_50M_24M_P,
+        RegField::new(clksel_con(110), clk_sel110::HCLK_VOP_ROOT_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        PCLK_VOP_ROOT,
+        "pclk_vop_root",
+        MUX_100M_50M_24M_P,
+        RegField::new(clksel_con(110), 12, 2),
+        NONE,
+    ),
+    ClkNode::factor(
+        ACLK_VOP_DIV2_SRC,
+        "aclk_vop_div2_src",
+        &[ACLK_VOP_ROOT],
+        1,
+        2,
+        NONE,
+    ),
+    ClkNode::mux(
+        ACLK_VOP_SUB_SRC,
+        "aclk_vop_sub_src",
+        ACLK_VOP_SUB_SRC_P,
+        RegField::new(clksel_con(115), 9, 1),
+        NONE,
+    ),
+    ClkNode::gate(ACLK_VOP, "aclk_vop", &[ACLK_VOP_SUB_SRC], NONE),
+    ClkNode::gate(HCLK_VOP, "hclk_vop", &[HCLK_VOP_ROOT], NONE),
+    ClkNode::composite(
+        DCLK_VOP0_SRC,
+        "dclk_vop0_src",
+        GPLL_CPLL_V0PLL_AUPLL_P,
+        RegField::new(clksel_con(111), clk_sel111::DCLK0_VOP_SRC_SEL_SHIFT, 2),
+        RegField::new(clksel_con(111), clk_sel111::DCLK0_VOP_SRC_DIV_SHIFT, 7),
+        NONE,
+    ),
+    ClkNode::composite(
+        DCLK_VOP1_SRC,
+        "dclk_vop1_src",
+        GPLL_CPLL_V0PLL_AUPLL_P,
+        RegField::new(clksel_con(111), clk_sel111::DCLK1_VOP_SRC_SEL_SHIFT, 2),
+        RegField::new(clksel_con(111), clk_sel111::DCLK1_VOP_SRC_DIV_SHIFT, 5),
+        NONE,
+    ),
+    ClkNode::composite(
+        DCLK_VOP2_SRC,
+        "dclk_vop2_src",
+        GPLL_CPLL_V0PLL_AUPLL_P,
+        RegField::new(clksel_con(112), clk_sel112::DCLK2_VOP_SRC_SEL_SHIFT, 2),
+        RegField::new(clksel_con(112), clk_sel112::DCLK2_VOP_SRC_DIV_SHIFT, 5),
+        NONE,
+    ),
+    ClkNode::mux(
+        DCLK_VOP0,
+        "dclk_vop0",
+        DCLK_VOP0_P,
+        RegField::new(clksel_con(112), clk_sel112::DCLK0_VOP_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        DCLK_VOP1,
+        "dclk_vop1",
+        DCLK_VOP1_P,
+        RegField::new(clksel_con(112), clk_sel112::DCLK1_VOP_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::mux(
+        DCLK_VOP2,
+        "dclk_vop2",
+        DCLK_VOP2_P,
+        RegField::new(clksel_con(112), clk_sel112::DCLK2_VOP_SEL_SHIFT, 2),
+        NONE,
+    ),
+    ClkNode::composite(
+        DCLK_VOP3,
+        "dclk_vop3",
+        GPLL_CPLL_V0PLL_AUPLL_P,
+        RegField::new(clksel_con(113), clk_sel113::DCLK3_VOP_SRC_SEL_SHIFT, 2),
+        RegField::new(clksel_con(113), clk_sel113::DCLK3_VOP_SRC_DIV_SHIFT, 7),
+        NONE,
+    ),
+];
+
+/// RK3588 完整时钟树 (按子系统分组)
+pub(crate) const CLK_TREE: &[&[ClkNode]] = &[
+    PLL_NODES,
+    CPU_NODES,
+    SRC_NODES,
+    ROOT_NODES,
+    I2C_NODES,
+    SPI_NODES,
+    PWM_NODES,
+    ADC_NODES,
+    UART_NODES,
+    &UART1_NODES,
+    &UART2_NODES,
+    &UART3_NODES,
+    &UART4_NODES,
+    &UART5_NODES,
+    &UART6_NODES,
+    &UART7_NODES,
+    &UART8_NODES,
+    &UART9_NODES,
+    MMC_NODES,
+    USB_NODES,
+    VIDEO_NODES,
+    CAMERA_NODES,
+    GPU_NODES,
+    NPU_NODES,
+    PHP_NODES,
+    GMAC_NODES,
+    AUDIO_NODES,
+    &I2S0_TX_NODES,
+    &I2S0_RX_NODES,
+    &I2S2_NODES,
+    &I2S3_NODES,
+    &I2S4_NODES,
+    &I2S5_NODES,
+    &I2S6_TX_NODES,
+    &I2S6_RX_NODES,
+    &I2S7_NODES,
+    &I2S8_NODES,
+    &I2S9_NODES,
+    &SPDIF0_NODES,
+    &SPDIF1_NODES,
+    VOP_NODES,
+];
+
+/// 时钟依赖关系: (功能时钟, 依赖的总线时钟)
+///
+/// 外设的功能时钟工作时需要对应的 APB 总线时钟，使能前者时自动使能后者
+const CLK_LINKS: &[(ClkId, ClkId)] = &[
+    (CLK_I2C0, PCLK_I2C0),
+    (CLK_I2C1, PCLK_I2C1),
+    (CLK_I2C2, PCLK_I2C2),
+    (CLK_I2C3, PCLK_I2C3),
+    (CLK_I2C4, PCLK_I2C4),
+    (CLK_I2C5, PCLK_I2C5),
+    (CLK_I2C6, PCLK_I2C6),
+    (CLK_I2C7, PCLK_I2C7),
+    (CLK_I2C8, PCLK_I2C8),
+    (CLK_SPI0, PCLK_SPI0),
+    (CLK_SPI1, PCLK_SPI1),
+    (CLK_SPI2, PCLK_SPI2),
+    (CLK_SPI3, PCLK_SPI3),
+    (CLK_SPI4, PCLK_SPI4),
+    (SCLK_UART0, PCLK_UART0),
+    (SCLK_UART1, PCLK_UART1),
+    (SCLK_UART2, PCLK_UART2),
+    (SCLK_UART3, PCLK_UART3),
+    (SCLK_UART4, PCLK_UART4),
+    (SCLK_UART5, PCLK_UART5),
+    (SCLK_UART6, PCLK_UART6),
+    (SCLK_UART7, PCLK_UART7),
+    (SCLK_UART8, PCLK_UART8),
+    (SCLK_UART9, PCLK_UART9),
+    (CLK_PWM1, PCLK_PWM1),
+    (CLK_PWM2, PCLK_PWM2),
+    (CLK_PWM3, PCLK_PWM3),
+    (CLK_PMU1PWM, PCLK_PMU1PWM),
+    (CLK_SARADC, PCLK_SARADC),
+    (CLK_TSADC, PCLK_TSADC),
+    (ACLK_PCIE_4L_MSTR, ACLK_PCIE_4L_SLV),
+    (ACLK_PCIE_4L_MSTR, ACLK_PCIE_4L_DBI),
+    (ACLK_PCIE_4L_MSTR, PCLK_PCIE_4L),
+    (ACLK_PCIE_4L_MSTR, CLK_PCIE_AUX0),
+    (ACLK_PCIE_2L_MSTR, ACLK_PCIE_2L_SLV),
+    (ACLK_PCIE_2L_MSTR, ACLK_PCIE_2L_DBI),
+    (ACLK_PCIE_2L_MSTR, PCLK_PCIE_2L),
+    (ACLK_PCIE_2L_MSTR, CLK_PCIE_AUX1),
+    (ACLK_PCIE_1L0_MSTR, ACLK_PCIE_1L0_SLV),
+    (ACLK_PCIE_1L0_MSTR, ACLK_PCIE_1L0_DBI),
+    (ACLK_PCIE_1L0_MSTR, PCLK_PCIE_1L0),
+    (ACLK_PCIE_1L0_MSTR, CLK_PCIE_AUX2),
+    (ACLK_PCIE_1L1_MSTR, ACLK_PCIE_1L1_SLV),
+    (ACLK_PCIE_1L1_MSTR, ACLK_PCIE_1L1_DBI),
+    (ACLK_PCIE_1L1_MSTR, PCLK_PCIE_1L1),
+    (ACLK_PCIE_1L1_MSTR, CLK_PCIE_AUX3),
+    (ACLK_PCIE_1L2_MSTR, ACLK_PCIE_1L2_SLV),
+    (ACLK_PCIE_1L2_MSTR, ACLK_PCIE_1L2_DBI),
+    (ACLK_PCIE_1L2_MSTR, PCLK_PCIE_1L2),
+    (ACLK_PCIE_1L2_MSTR, CLK_PCIE_AUX4),
+    (CLK_RXOOB0, ACLK_SATA0),
+    (CLK_RXOOB1, ACLK_SATA1),
+    (CLK_RXOOB2, ACLK_SATA2),
+    (ACLK_RKVDEC0, HCLK_RKVDEC0),
+    (ACLK_RKVDEC1, HCLK_RKVDEC1),
+    (ACLK_RKVENC0, HCLK_RKVENC0),
+    (ACLK_RKVENC1, HCLK_RKVENC1),
+    (ACLK_VPU, HCLK_VPU),
+    (ACLK_JPEG_ENCODER0, HCLK_JPEG_ENCODER0),
+    (ACLK_JPEG_ENCODER1, HCLK_JPEG_ENCODER1),
+    (ACLK_JPEG_ENCODER2, HCLK_JPEG_ENCODER2),
+    (ACLK_JPEG_ENCODER3, HCLK_JPEG_ENCODER3),
+    (ACLK_JPEG_DECODER, HCLK_JPEG_DECODER),
+    (ACLK_AV1, PCLK_AV1),
+    (ACLK_VICAP, HCLK_VICAP),
+    (ACLK_ISP0, HCLK_ISP0),
+    (ACLK_ISP1, HCLK_ISP1),
+    (ACLK_FISHEYE0, HCLK_FISHEYE0),
+    (ACLK_FISHEYE1, HCLK_FISHEYE1),
+    (CLK_GPU, PCLK_GPU_ROOT),
+    (ACLK_NPU0, HCLK_NPU0),
+    (ACLK_NPU1, HCLK_NPU1),
+    (ACLK_NPU2, HCLK_NPU2),
+    (ACLK_GMAC0, PCLK_GMAC0),
+    (ACLK_GMAC1, PCLK_GMAC1),
+];
+
+/// 获取时钟依赖的总线时钟
+pub(crate) fn clk_links(id: ClkId) -> impl Iterator<Item = ClkId> {
+    CLK_LINKS
+        .iter()
+        .filter(move |(clk, _)| *clk == id)
+        .map(|(_, link)| *link)
+}
+
+/// 时钟树节点标志之外的关键时钟
+///
+/// 参考 Linux: drivers/clk/rockchip/clk.c:rockchip_clk_protect_critical()
+///
+/// PMU 总线根时钟的时钟源，关闭后 PMU 总线失去时钟，只能断电恢复
+const CRITICAL_CLKS: &[ClkId] = &[CLK_PMU1_300M_SRC, CLK_PMU1_400M_SRC];
+
+/// 是否在关键时钟列表中
+pub(crate) fn is_critical_clk(id: ClkId) -> bool {
+    CRITICAL_CLKS.contains(&id)
+}
+
+/// 遍历时钟树中的所有节点
+pub(crate) fn clk_nodes() -> impl Iterator<Item = &'static ClkNode> {
+    CLK_TREE.iter().flat_map(|group| group.iter())
+}
+
+// =============================================================================
+// 时钟树遍历
+// =============================================================================
+
+impl Cru {
+    /// 查找时钟树节点
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回节点描述，不在时钟树中则返回 None
+    pub fn find_clk_node(&self, id: ClkId) -> Option<&'static ClkNode> {
+        clk_nodes().find(|node| node.id == id)
+    }
+
+    /// 读取节点当前选中的父时钟索引
+    ///
+    /// 没有 mux 的节点固定返回 0
+    pub(crate) fn clk_parent_index(&self, node: &ClkNode) -> usize {
+        match node.mux_field() {
+            Some(mux) => mux.get(self.read(mux.reg)) as usize,
+            None => 0,
+        }
+    }
+
+    /// 获取节点当前的父时钟
+    ///
+    /// # Errors
+    ///
+    /// 节点没有父时钟 (固定时钟源或孤儿时钟) 或 mux 选择值超出父时钟列表时返回错误
+    pub(crate) fn clk_current_parent(&self, node: &ClkNode) -> ClockResult<ClkId> {
+        let index = self.clk_parent_index(node);
+        node.parents.get(index).copied().ok_or_else(|| {
+            if node.parents.is_empty() {
+                ClockError::rate_read_failed(node.id, "clock has no modeled parent")
+            } else {
+                ClockError::rate_read_failed(node.id, "Invalid parent clock source")
+            }
+        })
+    }
+
+    /// 获取通过 [`Cru::clk_set_parent`] 固定的父时钟
+    ///
+    /// # 返回
+    ///
+    /// 时钟已固定父时钟时返回当前的父时钟，否则返回 None
+    fn clk_pinned_parent(&self, node: &ClkNode) -> Option<ClkId> {
+        if !self.pinned.contains(&node.id) {
+            return None;
+        }
+        self.clk_current_parent(node).ok()
+    }
+
+    /// 沿时钟树计算时钟频率
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回时钟频率 (Hz)
+    ///
+    /// # Errors
+    ///
+    /// 时钟不在时钟树中、父时钟未建模或寄存器配置无效时返回错误
+    pub(crate) fn clk_tree_get_rate(&self, id: ClkId) -> ClockResult<u64> {
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+
+        match node.kind {
+            ClkKind::Fixed(rate) => Ok(rate),
+            ClkKind::Pll => {
+                let pll_id = PllId::try_from(id).map_err(|_| ClockError::unsupported(id))?;
+                self.pll_get_rate(pll_id)
+            }
+            ClkKind::Composite { div, .. } => {
+                let parent_rate = self.clk_tree_get_rate(self.clk_current_parent(node)?)?;
+                Ok(match div {
+                    Some(div) => div.rate(parent_rate, div.field.get(self.read(div.field.reg))),
+                    None => parent_rate,
+                })
+            }
+            ClkKind::Factor { mult, div } => {
+                let parent_rate = self.clk_tree_get_rate(self.clk_current_parent(node)?)?;
+                Ok(parent_rate * mult as u64 / div as u64)
+            }
+            ClkKind::Frac { reg } => {
+                let parent_rate = self.clk_tree_get_rate(self.clk_current_parent(node)?)?;
+                let fracdiv = self.read(reg);
+                let n = (fracdiv >> 16) as u64;
+                let m = (fracdiv & 0xffff) as u64;
+                if m == 0 {
+                    return Err(ClockError::rate_read_failed(
+                        id,
+                        "fractional divider denominator is zero",
+                    ));
+                }
+                Ok(parent_rate * n / m)
+            }
+        }
+    }
+
+    /// 按时钟树描述设置组合时钟的频率
+    ///
+    /// 参考 Linux: drivers/clk/clk-composite.c:clk_composite_determine_rate()
+    ///
+    /// 遍历可选的父时钟，优先选择输出不超过目标频率且误差最小的父时钟和分频值，
+    /// 父时钟本身的频率不会被修改。频率无法读取的父时钟 (如未建模的外部输入) 会被跳过。
+    /// 通过 [`Cru::clk_set_parent`] 固定了父时钟的时钟只调整分频
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    /// * `rate_hz` - 目标频率 (Hz)
+    ///
+    /// # 返回
+    ///
+    /// 返回设置后实际的时钟频率
+    ///
+    /// # Errors
+    ///
+    /// 时钟不在时钟树中或不是组合时钟返回 `ClockError::UnsupportedClock`，
+    /// 没有可用的父时钟返回 `ClockError::InvalidRate`
+    pub(crate) fn clk_tree_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        if !matches!(node.kind, ClkKind::Composite { .. }) {
+            return Err(ClockError::unsupported(id));
+        }
+        if rate_hz == 0 {
+            return Err(ClockError::invalid_rate(id, rate_hz));
+        }
+
+        let (parent, div_val, rate) = self
+            .clk_composite_determine_rate(node, rate_hz)
+            .ok_or(ClockError::invalid_rate(id, rate_hz))?;
+
+        debug!(
+            "{}: set rate {}Hz -> parent {}, div {}, {}Hz",
+            node.name, rate_hz, parent, div_val, rate
+        );
+
+        self.clk_composite_apply(node, parent, div_val)?;
+        self.clk_tree_get_rate(id)
+    }
+
+    /// 为组合时钟选择父时钟和分频值，不修改寄存器
+    ///
+    /// 参考 Linux: drivers/clk/clk-composite.c:clk_composite_determine_rate()
+    ///
+    /// # 返回
+    ///
+    /// 返回 (父时钟, 分频值, 输出频率)，没有可用的父时钟时返回 None
+    fn clk_composite_determine_rate(
+        &self,
+        node: &ClkNode,
+        rate_hz: u64,
+    ) -> Option<(ClkId, u32, u64)> {
+        let ClkKind::Composite { div, .. } = node.kind else {
+            return None;
+        };
+
+        let pinned = self.clk_pinned_parent(node);
+        let mut best: Option<(ClkId, u32, u64)> = None;
+        for &parent in node.parents {
+            if pinned.is_some_and(|p| p != parent) {
+                continue;
+            }
+            let Ok(parent_rate) = self.clk_tree_get_rate(parent) else {
+                continue;
+            };
+            let (div_val, rate) = match div {
+                Some(div) => {
+                    let val = div.div_for_rate(parent_rate, rate_hz);
+                    (val, div.rate(parent_rate, val))
+                }
+                None => (0, parent_rate),
+            };
+
+            let score = |rate: u64| (rate > rate_hz, rate.abs_diff(rate_hz));
+            if best.is_none_or(|(_, _, best_rate)| score(rate) < score(best_rate)) {
+                best = Some((parent, div_val, rate));
+            }
+        }
+        best
+    }
+
+    /// 按 [`Cru::clk_composite_determine_rate`] 的结果写入组合时钟的父时钟和分频值
+    fn clk_composite_apply(
+        &mut self,
+        node: &ClkNode,
+        parent: ClkId,
+        div_val: u32,
+    ) -> ClockResult<()> {
+        let ClkKind::Composite { div, .. } = node.kind else {
+            return Err(ClockError::unsupported(node.id));
+        };
+
+        // 分频值变大时先改分频再切换父时钟，避免中间状态频率过高
+        let old_div = div.map(|div| div.field.get(self.read(div.field.reg)));
+        let div_first = old_div.is_some_and(|old| div_val > old);
+        if div_first && let Some(div) = div {
+            self.clrsetreg(div.field.reg, div.field.mask, div_val << div.field.shift);
+        }
+        if self.clk_current_parent(node).ok() != Some(parent) {
+            self.clk_core_set_parent(node.id, parent)?;
+        }
+        if !div_first && let Some(div) = div {
+            self.clrsetreg(div.field.reg, div.field.mask, div_val << div.field.shift);
+        }
+        Ok(())
+    }
+
+    /// 设置 "src -> frac -> mux" 结构时钟的频率
+    ///
+    /// 参考 Linux: drivers/clk/rockchip/clk.c:rockchip_fractional_approximation()
+    ///
+    /// mux 的第一个父时钟为整数分频的 src，第二个为小数分频器，其余为固定输入 (如 xin12m)：
+    ///
+    /// 1. src 整数分频能精确得到目标频率时选择 src
+    /// 2. 固定输入等于目标频率时直接选择
+    /// 3. 否则 src 不分频，在频率不低于目标频率 20 倍的父时钟中用小数分频逼近，
+    ///    选择误差最小的一组
+    ///
+    /// 通过 [`Cru::clk_set_parent`] 固定了父时钟的 mux 或 src 只在当前父时钟上调整：
+    /// mux 固定为 src 时选择最接近的整数分频，固定为固定输入时只能精确匹配
+    ///
+    /// # 参数
+    ///
+    /// * `id` - mux 时钟 ID
+    /// * `rate_hz` - 目标频率 (Hz)
+    ///
+    /// # 返回
+    ///
+    /// 返回设置后实际的时钟频率
+    ///
+    /// # Errors
+    ///
+    /// 时钟不是 "src -> frac -> mux" 结构返回 `ClockError::UnsupportedClock`，
+    /// 没有满足 20 倍关系的父时钟返回 `ClockError::InvalidRate`
+    pub(crate) fn clk_frac_mux_set_rate(&mut self, id: ClkId, rate_hz: u64) -> ClockResult<u64> {
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        let &[src, frac, ref others @ ..] = node.parents else {
+            return Err(ClockError::unsupported(id));
+        };
+        let Some(&ClkNode {
+            kind: ClkKind::Frac { reg },
+            ..
+        }) = self.find_clk_node(frac)
+        else {
+            return Err(ClockError::unsupported(id));
+        };
+        let src_node = self
+            .find_clk_node(src)
+            .ok_or(ClockError::unsupported(src))?;
+        if rate_hz == 0 {
+            return Err(ClockError::invalid_rate(id, rate_hz));
+        }
+
+        let mux_pinned = self.clk_pinned_parent(node);
+        let mux_allowed = |parent: ClkId| mux_pinned.is_none_or(|p| p == parent);
+
+        // 1. 整数分频: 先计算，能精确得到目标频率时才修改 src
+        if mux_allowed(src)
+            && let Some((parent, div_val, rate)) =
+                self.clk_composite_determine_rate(src_node, rate_hz)
+            && (rate == rate_hz || mux_pinned == Some(src))
+        {
+            debug!(
+                "{}: set rate {}Hz -> {} parent {}, div {}",
+                node.name, rate_hz, src, parent, div_val
+            );
+            self.clk_composite_apply(src_node, parent, div_val)?;
+            self.clk_core_set_parent(id, src)?;
+            return self.clk_tree_get_rate(id);
+        }
+
+        // 2. 固定输入
+        for &other in others {
+            if mux_allowed(other) && self.clk_tree_get_rate(other).ok() == Some(rate_hz) {
+                self.clk_core_set_parent(id, other)?;
+                return self.clk_tree_get_rate(id);
+            }
+        }
+        if !mux_allowed(frac) {
+            return Err(ClockError::invalid_rate(id, rate_hz));
+        }
+
+        // 3. 小数分频: (src 父时钟, 分子, 分母, 输出频率)
+        let src_pinned = self.clk_pinned_parent(src_node);
+        let mut best: Option<(ClkId, u64, u64, u64)> = None;
+        for &parent in src_node.parents {
+            if src_pinned.is_some_and(|p| p != parent) {
+                continue;
+            }
+            let Ok(parent_rate) = self.clk_tree_get_rate(parent) else {
+                continue;
+            };
+            if parent_rate < rate_hz * 20 {
+                continue;
+            }
+            let (n, m) = rational_best_approximation(rate_hz, parent_rate, 0xffff, 0xffff);
+            if n == 0 || m == 0 {
+                continue;
+            }
+            let rate = parent_rate * n / m;
+            if best
+                .is_none_or(|(.., best_rate)| rate.abs_diff(rate_hz) < best_rate.abs_diff(rate_hz))
+            {
+                best = Some((parent, n, m, rate));
+            }
+        }
+        let (parent, n, m, rate) = best.ok_or(ClockError::invalid_rate(id, rate_hz))?;
+
+        debug!(
+            "{}: set rate {}Hz -> parent {}, frac {}/{}, {}Hz",
+            node.name, rate_hz, parent, n, m, rate
+        );
+
+        self.clk_core_set_parent(src, parent)?;
+        if let Some(div) = src_node.divider() {
+            self.clrreg(div.field.reg, div.field.mask);
+        }
+        self.write(reg, ((n as u32) << 16) | m as u32);
+        self.clk_core_set_parent(id, frac)?;
+
+        self.clk_tree_get_rate(id)
+    }
+
+    /// 获取当前以 `id` 为父时钟的所有子时钟
+    ///
+    /// 按 mux 的当前选择计算，用于评估修改某个时钟 (如共享 PLL) 的影响范围
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 父时钟 ID
+    pub fn clk_children(&self, id: ClkId) -> Vec<ClkId> {
+        clk_nodes()
+            .filter(|node| node.parents.contains(&id))
+            .filter(|node| self.clk_current_parent(node).ok() == Some(id))
+            .map(|node| node.id)
+            .collect()
+    }
+
+    /// 获取时钟当前的父时钟
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回 mux 当前选中的父时钟 ID
+    ///
+    /// # Errors
+    ///
+    /// 时钟不在时钟树中或没有父时钟时返回 `ClockError::UnsupportedClock`，
+    /// mux 选择值超出父时钟列表时返回 `ClockError::InvalidClockSource`
+    pub fn clk_get_parent(&self, id: ClkId) -> ClockResult<ClkId> {
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        if node.parents.is_empty() {
+            return Err(ClockError::unsupported(id));
+        }
+
+        let index = self.clk_parent_index(node);
+        node.parents
+            .get(index)
+            .copied()
+            .ok_or(ClockError::invalid_clock_source(id, index as u32))
+    }
+
+    /// 切换时钟的父时钟并固定
+    ///
+    /// 参考 Linux: drivers/clk/clk-mux.c:clk_mux_set_parent()
+    ///
+    /// 只修改 mux 选择位，分频系数保持不变。之后的 `clk_set_rate` 只在该父时钟上
+    /// 调整分频，不会再切换回其他父时钟 (如把 UART/SDIO 固定在 CPLL 上避免抖动)
+    ///
+    /// # 参数
+    ///
+    /// * `id` - 时钟 ID
+    /// * `parent` - 目标父时钟 ID
+    ///
+    /// # Errors
+    ///
+    /// 时钟不在时钟树中返回 `ClockError::UnsupportedClock`，
+    /// 父时钟不在可选列表中返回 `ClockError::InvalidParent`
+    pub fn clk_set_parent(&mut self, id: ClkId, parent: ClkId) -> ClockResult<()> {
+        self.clk_core_set_parent(id, parent)?;
+        self.pinned.insert(id);
+        Ok(())
+    }
+
+    /// 切换时钟的父时钟，不固定
+    ///
+    /// 供各 `*_set_rate` 内部选择父时钟使用，错误同 [`Cru::clk_set_parent`]
+    pub(crate) fn clk_core_set_parent(&mut self, id: ClkId, parent: ClkId) -> ClockResult<()> {
+        let node = self.find_clk_node(id).ok_or(ClockError::unsupported(id))?;
+        let index = node
+            .parents
+            .iter()
+            .position(|&p| p == parent)
+            .ok_or(ClockError::invalid_parent(id, parent))?;
+
+        // 没有 mux 的时钟只有一个父时钟，无需切换
+        let Some(mux) = node.mux_field() else {
+            return Ok(());
+        };
+
+        // 已使能的时钟切换父时钟时，把使能计数从旧父时钟转移到新父时钟
+        // 参考 Linux: drivers/clk/clk.c:__clk_set_parent_before()
+        let old_parent = self.clk_current_parent(node).ok();
+        let enabled = self.enable_count.contains_key(&id);
+        if enabled && old_parent != Some(parent) {
+            self.clk_enable(parent)?;
+        }
+
+        debug!("{}: set parent to {} (sel={})", node.name, parent, index);
+        self.clrsetreg(mux.reg, mux.mask, (index as u32) << mux.shift);
+
+        if enabled
+            && let Some(old) = old_parent
+            && old != parent
+        {
+            self.clk_disable(old)?;
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// 单元测试
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use super::*;
+    use crate::{delay::FakeDelay, regmap::RegFile, variants::rk3588::cru::sim::sim_cru};
+
+    /// CRU 寄存器空间大小 (覆盖 DSU CRU)
+    const CRU_SIZE: usize = 0x5c000;
+
+    /// 使用内存寄存器文件代替 CRU 寄存器构造 Cru (不执行 init)
+    fn test_cru() -> Cru {
+        let mut cru = Cru::from_access(
+            0,
+            Arc::new(RegFile::new(CRU_SIZE)),
+            Arc::new(RegFile::new(0x1000)),
+            Arc::new(FakeDelay::new()),
+        );
+        cru.cpll_hz = CPLL_HZ;
+        cru.gpll_hz = GPLL_HZ;
+        cru.ppll_hz = PPLL_HZ;
+        cru
+    }
+
+    /// 按 u-boot 默认值配置 GPLL (1188MHz) 和 CPLL (1500MHz)
+    fn setup_plls(cru: &Cru) {
+        let gpll = get_pll(PllId::GPLL);
+        cru.write(gpll.con_offset, 198);
+        cru.write(gpll.con_offset + pll_con(1), (1 << 6) | 2);
+        let cpll = get_pll(PllId::CPLL);
+        cru.write(cpll.con_offset, 250);
+        cru.write(cpll.con_offset + pll_con(1), (1 << 6) | 2);
+        for pll in [gpll, cpll] {
+            let mode = cru.read(pll.mode_offset);
+            cru.write(
+                pll.mode_offset,
+                mode | (pll_mode::PLL_MODE_NORMAL << pll.mode_shift),
+            );
+        }
+    }
+
+    #[test]
+    fn test_clk_tree_unique_ids() {
+        let mut ids = clk_nodes().map(|node| node.id.value()).collect::<Vec<_>>();
+        let total = ids.len();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), total, "CLK_TREE should not have duplicate ids");
+    }
+
+    #[test]
+    fn test_clk_tree_parents_exist() {
+        for node in clk_nodes() {
+            for parent in node.parents {
+                assert!(
+                    clk_nodes().any(|n| n.id == *parent),
+                    "{}: parent {} is not in CLK_TREE",
+                    node.name,
+                    parent
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_clk_tree_mux_width() {
+        for node in clk_nodes() {
+            if let Some(mux) = node.mux_field() {
+                assert!(
+                    node.parents.len() <= mux.max() as usize + 1,
+                    "{}: {} parents do not fit the mux field",
+                    node.name,
+                    node.parents.len()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_clk_node() {
+        let cru = test_cru();
+        for node in clk_nodes() {
+            assert_eq!(cru.find_clk_node(node.id), Some(node));
+        }
+        assert!(cru.find_clk_node(ClkId::new(0xffff)).is_none());
+    }
+
+    #[test]
+    fn test_clk_tree_get_rate_pll_and_src() {
+        let cru = test_cru();
+        setup_plls(&cru);
+
+        assert_eq!(cru.clk_tree_get_rate(XIN24M).unwrap(), OSC_HZ);
+        assert_eq!(cru.clk_tree_get_rate(PLL_GPLL).unwrap(), GPLL_HZ);
+        assert_eq!(cru.clk_tree_get_rate(PLL_CPLL).unwrap(), CPLL_HZ);
+
+        // clk_200m_src: GPLL / (5 + 1) = 198MHz
+        cru.write(clksel_con(1), 5 << 6);
+        assert_eq!(cru.clk_tree_get_rate(CLK_200M_SRC).unwrap(), 198 * MHZ);
+
+        // 切换到 CPLL: 1500 / (6 + 1)
+        cru.write(clksel_con(1), (1 << 11) | (6 << 6));
+        assert_eq!(cru.clk_tree_get_rate(CLK_200M_SRC).unwrap(), CPLL_HZ / 7);
+
+        // clk_450m_src 半分频: GPLL * 2 / (1 * 2 + 3) = 475.2MHz
+        cru.write(clksel_con(4), 1);
+        assert_eq!(
+            cru.clk_tree_get_rate(CLK_450M_SRC).unwrap(),
+            GPLL_HZ * 2 / 5
+        );
+    }
+
+    #[test]
+    fn test_clk_tree_get_rate_peripherals() {
+        let cru = test_cru();
+        setup_plls(&cru);
+
+        // clk_200m_src = 198MHz, clk_100m_src = 99MHz
+        cru.write(clksel_con(0), 11 << 6);
+        cru.write(clksel_con(1), 5 << 6);
+
+        // CLK_I2C1 选择 200M, CLK_I2C2 选择 100M
+        cru.write(clksel_con(38), clk_sel38::CLK_I2C2_SEL_MASK);
+        assert_eq!(cru.clk_tree_get_rate(CLK_I2C1).unwrap(), 198 * MHZ);
+        assert_eq!(cru.clk_tree_get_rate(CLK_I2C2).unwrap(), 99 * MHZ);
+
+        // PCLK_I2C1 -> pclk_top_root (选择 100M)
+        assert_eq!(cru.clk_tree_get_rate(PCLK_I2C1).unwrap(), 99 * MHZ);
+
+        // CLK_SPI0 选择 24M
+        cru.write(clksel_con(59), clk_sel59::CLK_SPI_SEL_24M << 2);
+        assert_eq!(cru.clk_tree_get_rate(CLK_SPI0).unwrap(), OSC_HZ);
+    }
+
+    #[test]
+    fn test_clk_tree_get_rate_uart_frac() {
+        let cru = test_cru();
+        setup_plls(&cru);
+
+        // clk_uart2_src: GPLL / 2 = 594MHz
+        cru.write(clksel_con(43), 1 << 9);
+        // clk_uart2_frac: 594MHz * 1 / 5 = 118.8MHz
+        cru.write(clksel_con(44), (1 << 16) | 5);
+        // clk_uart2 选择 frac
+        cru.write(clksel_con(45), clk_sel43::CLK_UART_SEL_FRAC);
+
+        assert_eq!(cru.clk_tree_get_rate(CLK_UART2_SRC).unwrap(), 594 * MHZ);
+        assert_eq!(cru.clk_tree_get_rate(SCLK_UART2).unwrap(), 594 * MHZ / 5);
+
+        // 分母为 0 时返回错误
+        cru.write(clksel_con(44), 1 << 16);
+        assert!(cru.clk_tree_get_rate(SCLK_UART2).is_err());
+    }
+
+    #[test]
+    fn test_clk_frac_mux_set_rate_keeps_src() {
+        let mut cru = test_cru();
+        setup_plls(&cru);
+
+        // clk_uart2 选择 clk_uart2_src: GPLL / 2
+        cru.write(clksel_con(43), 1 << 9);
+
+        // 整数分频无法精确得到 24MHz，选择 xin24m 时不修改 src
+        assert_eq!(
+            cru.clk_frac_mux_set_rate(CLK_UART2, OSC_HZ).unwrap(),
+            OSC_HZ
+        );
+        assert_eq!(cru.clk_get_parent(CLK_UART2).unwrap(), XIN24M);
+        assert_eq!(cru.read(clksel_con(43)), 1 << 9);
+
+        // 整数分频精确时才写入 src: GPLL / 12
+        assert_eq!(
+            cru.clk_frac_mux_set_rate(CLK_UART2, GPLL_HZ / 12).unwrap(),
+            GPLL_HZ / 12
+        );
+        assert_eq!(cru.clk_get_parent(CLK_UART2).unwrap(), CLK_UART2_SRC);
+        assert_eq!(cru.read(clksel_con(43)) & 0xffff, 11 << 9);
+    }
+
+    #[test]
+    fn test_clk_tree_orphan_and_unknown() {
+        let cru = test_cru();
+        setup_plls(&cru);
+
+        assert!(matches!(
+            cru.clk_tree_get_rate(CLK_PCIE4L_PIPE),
+            Err(ClockError::RateReadFailed { .. })
+        ));
+        assert!(matches!(
+            cru.clk_tree_get_rate(ClkId::new(0xffff)),
+            Err(ClockError::UnsupportedClock { .. })
+        ));
+    }
+
+    #[test]
+    fn test_clk_get_set_parent() {
+        let mut cru = test_cru();
+        setup_plls(&cru);
+
+        assert_eq!(cru.clk_get_parent(CLK_UART2_SRC).unwrap(), PLL_GPLL);
+        cru.clk_set_parent(CLK_UART2_SRC, PLL_CPLL).unwrap();
+        assert_eq!(cru.clk_get_parent(CLK_UART2_SRC).unwrap(), PLL_CPLL);
+        assert_eq!(
+            cru.read(clksel_con(43)) & 0xffff,
+            clk_sel41::CLK_UART_SRC_SEL_MASK
+        );
+
+        cru.clk_set_parent(CCLK_SRC_SDIO, XIN24M).unwrap();
+        assert_eq!(cru.clk_get_parent(CCLK_SRC_SDIO).unwrap(), XIN24M);
+        assert_eq!(cru.clk_get_rate(CCLK_SRC_SDIO).unwrap(), OSC_HZ);
+
+        // 门控时钟只有一个父时钟
+        assert_eq!(cru.clk_get_parent(SCLK_UART2).unwrap(), CLK_UART2);
+        assert!(cru.clk_set_parent(SCLK_UART2, CLK_UART2).is_ok());
+    }
+
+    #[test]
+    fn test_clk_set_parent_invalid() {
+        let mut cru = test_cru();
+
+        assert!(matches!(
+            cru.clk_set_parent(CLK_UART2_SRC, PLL_NPLL),
+            Err(ClockError::InvalidParent { .. })
+        ));
+        assert!(matches!(
+            cru.clk_set_parent(ClkId::new(0xffff), PLL_GPLL),
+            Err(ClockError::UnsupportedClock { .. })
+        ));
+        assert!(matches!(
+            cru.clk_get_parent(XIN24M),
+            Err(ClockError::UnsupportedClock { .. })
+        ));
+
+        // CLK_SPI0 只有 3 个父时钟，选择值 3 无效
+        cru.write(clksel_con(59), 3 << clk_sel59::CLK_SPI0_SEL_SHIFT);
+        assert!(matches!(
+            cru.clk_get_parent(CLK_SPI0),
+            Err(ClockError::InvalidClockSource { src: 3, .. })
+        ));
+    }
+
+    /// 读取门控寄存器中的硬件使能状态
+    fn gate_on(cru: &Cru, id: ClkId) -> bool {
+        cru.clk_is_enabled(id).unwrap()
+    }
+
+    #[test]
+    fn test_clk_enable_refcount() {
+        let mut cru = test_cru();
+
+        cru.clk_enable(ACLK_USB3OTG0).unwrap();
+        cru.clk_enable(ACLK_USB3OTG1).unwrap();
+        assert_eq!(cru.clk_enable_count(ACLK_USB_ROOT).unwrap(), 2);
+        assert_eq!(cru.clk_enable_count(PLL_GPLL).unwrap(), 1);
+
+        // 一个使用者释放后共享的根时钟保持打开
+        cru.clk_disable(ACLK_USB3OTG0).unwrap();
+        assert_eq!(cru.clk_enable_count(ACLK_USB_ROOT).unwrap(), 1);
+
+        cru.clk_disable(ACLK_USB3OTG1).unwrap();
+        assert_eq!(cru.clk_enable_count(ACLK_USB_ROOT).unwrap(), 0);
+        assert_eq!(cru.clk_enable_count(PLL_GPLL).unwrap(), 0);
+
+        // 不平衡的 disable
+        assert!(matches!(
+            cru.clk_disable(ACLK_USB3OTG1),
+            Err(ClockError::DisableFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_clk_enable_links_and_gates() {
+        let mut cru = test_cru();
+
+        cru.clk_enable(CLK_I2C1).unwrap();
+        assert_eq!(cru.clk_enable_count(PCLK_I2C1).unwrap(), 1);
+        assert_eq!(cru.clk_enable_count(PCLK_TOP_ROOT).unwrap(), 1);
+        assert_eq!(cru.clk_enable_count(CLK_200M_SRC).unwrap(), 1);
+        assert!(gate_on(&cru, CLK_I2C1));
+        assert!(gate_on(&cru, PCLK_I2C1));
+
+        cru.clk_disable(CLK_I2C1).unwrap();
+        assert_eq!(cru.clk_enable_count(PCLK_I2C1).unwrap(), 0);
+        assert!(!gate_on(&cru, CLK_I2C1));
+        assert!(!gate_on(&cru, PCLK_I2C1));
+        // 关键时钟计数归零后仍保持打开
+        assert!(gate_on(&cru, CLK_200M_SRC));
+        assert!(gate_on(&cru, PCLK_TOP_ROOT));
+    }
+
+    #[test]
+    fn test_clk_disable_releases_parent_on_link_error() {
+        let mut cru = test_cru();
+
+        cru.clk_enable(CLK_I2C1).unwrap();
+        // 依赖的总线时钟被提前释放，禁止 CLK_I2C1 时释放依赖失败
+        cru.clk_disable(PCLK_I2C1).unwrap();
+        assert!(matches!(
+            cru.clk_disable(CLK_I2C1),
+            Err(ClockError::DisableFailed {
+                clk_id: PCLK_I2C1,
+                ..
+            })
+        ));
+        assert_eq!(cru.clk_enable_count(CLK_I2C1).unwrap(), 0);
+        assert_eq!(cru.clk_enable_count(CLK_200M_SRC).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_clk_set_parent_moves_enable_count() {
+        let mut cru = test_cru();
+
+        // SCLK_UART2 -> clk_uart2_src -> GPLL，PCLK_UART2 经 clk_100m_src 也用到 GPLL
+        cru.clk_enable(SCLK_UART2).unwrap();
+        assert_eq!(cru.clk_enable_count(CLK_UART2_SRC).unwrap(), 1);
+        assert_eq!(cru.clk_enable_count(PLL_GPLL).unwrap(), 2);
+        assert_eq!(cru.clk_enable_count(PLL_CPLL).unwrap(), 0);
+
+        cru.clk_set_parent(CLK_UART2_SRC, PLL_CPLL).unwrap();
+        assert_eq!(cru.clk_enable_count(PLL_GPLL).unwrap(), 1);
+        assert_eq!(cru.clk_enable_count(PLL_CPLL).unwrap(), 1);
+
+        // 关键时钟 PCLK_TOP_ROOT 继续持有 clk_100m_src -> GPLL
+        cru.clk_disable(SCLK_UART2).unwrap();
+        assert_eq!(cru.clk_enable_count(PLL_GPLL).unwrap(), 1);
+        assert_eq!(cru.clk_enable_count(PLL_CPLL).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_clk_set_rate_moves_enable_count() {
+        let mut cru = test_cru();
+        setup_plls(&cru);
+        // clk_200m_src = 198MHz, clk_100m_src = 99MHz
+        cru.write(clksel_con(0), 11 << 6);
+        cru.write(clksel_con(1), 5 << 6);
+
+        // clk_i2c1 默认选择 clk_200m_src，pclk_i2c1 经 pclk_top_root 用到 clk_100m_src
+        cru.clk_enable(CLK_I2C1).unwrap();
+        assert_eq!(cru.clk_enable_count(CLK_200M_SRC).unwrap(), 1);
+        let count_100m = cru.clk_enable_count(CLK_100M_SRC).unwrap();
+
+        // 切换到 clk_100m_src 时引用计数随之转移
+        assert_eq!(cru.clk_set_rate(CLK_I2C1, 100 * MHZ).unwrap(), 99 * MHZ);
+        assert_eq!(cru.clk_get_parent(CLK_I2C1).unwrap(), CLK_100M_SRC);
+        assert_eq!(cru.clk_enable_count(CLK_200M_SRC).unwrap(), 0);
+        assert_eq!(cru.clk_enable_count(CLK_100M_SRC).unwrap(), count_100m + 1);
+
+        cru.clk_disable(CLK_I2C1).unwrap();
+        assert_eq!(cru.clk_enable_count(CLK_200M_SRC).unwrap(), 0);
+        assert_eq!(cru.clk_enable_count(CLK_100M_SRC).unwrap(), count_100m);
+    }
+
+    #[test]
+    fn test_clk_set_rate_keeps_pinned_parent() {
+        // clk_uart2_src 与分频值共用寄存器，需要模拟写掩码
+        let (_, mut cru) = sim_cru();
+        cru.clk_set_parent(CLK_UART2_SRC, PLL_CPLL).unwrap();
+
+        // 小数分频只使用固定的 CPLL，返回读回的频率
+        let rate = cru.clk_set_rate(SCLK_UART2, 1_843_200).unwrap();
+        assert_eq!(rate, cru.clk_get_rate(SCLK_UART2).unwrap());
+        assert!(rate.abs_diff(1_843_200) < 100);
+        assert_eq!(cru.clk_get_parent(CLK_UART2_SRC).unwrap(), PLL_CPLL);
+        assert_eq!(cru.clk_get_parent(CLK_UART2).unwrap(), CLK_UART2_FRAC);
+        assert_eq!(cru.read(clksel_con(44)), (76 << 16) | 61849);
+
+        // mux 也固定在 src 时选择 CPLL 上最接近的整数分频，不切换到 GPLL
+        cru.clk_set_parent(CLK_UART2, CLK_UART2_SRC).unwrap();
+        assert_eq!(
+            cru.clk_set_rate(SCLK_UART2, GPLL_HZ / 12).unwrap(),
+            CPLL_HZ / 16
+        );
+        assert_eq!(cru.clk_get_parent(CLK_UART2_SRC).unwrap(), PLL_CPLL);
+        assert_eq!(cru.clk_get_parent(CLK_UART2).unwrap(), CLK_UART2_SRC);
+    }
+
+    #[test]
+    fn test_clk_children() {
+        let cru = test_cru();
+        setup_plls(&cru);
+
+        // 所有 mux 默认选择 0，GPLL 的子时钟包含各公共时钟源
+        let children = cru.clk_children(PLL_GPLL);
+        assert!(children.contains(&CLK_200M_SRC));
+        assert!(children.contains(&ACLK_BUS_ROOT));
+        assert!(!children.contains(&CLK_UART0_SRC));
+
+        // clk_200m_src 切换到 CPLL 后不再是 GPLL 的子时钟
+        cru.write(clksel_con(1), 1 << 11);
+        assert!(!cru.clk_children(PLL_GPLL).contains(&CLK_200M_SRC));
+        assert!(cru.clk_children(PLL_CPLL).contains(&CLK_200M_SRC));
+    }
+
+    #[test]
+    fn test_bus_clock_rates() {
+        let (_sim, mut cru) = sim_cru();
+
+        // 主 CRU 的 APB 时钟来自 pclk_top_root
+        let pclk_top = cru.clk_get_rate(PCLK_TOP_ROOT).unwrap();
+        assert_eq!(pclk_top, cru.clk_get_rate(CLK_100M_SRC).unwrap());
+        assert_eq!(cru.clk_get_rate(PCLK_I2C1).unwrap(), pclk_top);
+        assert_eq!(cru.clk_get_rate(PCLK_UART3).unwrap(), pclk_top);
+
+        // PMU 的 APB 时钟来自 pclk_pmu0_root: 396MHz / 4
+        assert_eq!(cru.clk_get_rate(PCLK_PMU1_ROOT).unwrap(), 99 * MHZ);
+        assert_eq!(cru.clk_get_rate(PCLK_I2C0).unwrap(), 99 * MHZ);
+        assert_eq!(cru.clk_get_rate(PCLK_UART0).unwrap(), 99 * MHZ);
+        assert_eq!(cru.clk_get_rate(PCLK_PMU1PWM).unwrap(), 99 * MHZ);
+
+        // PMU 的 AHB 时钟和 I2C0 使用 PMU CRU 自己的源时钟
+        assert_eq!(
+            cru.clk_get_parent(HCLK_PMU1_ROOT).unwrap(),
+            CLK_PMU1_200M_SRC
+        );
+        assert_eq!(cru.clk_get_rate(HCLK_PMU1_ROOT).unwrap(), 198 * MHZ);
+        cru.clk_set_parent(CLK_I2C0, CLK_PMU1_100M_SRC).unwrap();
+        assert_eq!(cru.clk_get_rate(CLK_I2C0).unwrap(), 99 * MHZ);
+
+        // 切换 PMU 根时钟后总线时钟随之变化
+        cru.clk_set_parent(PCLK_PMU1_ROOT, CLK_PMU1_50M_SRC)
+            .unwrap();
+        assert_eq!(cru.clk_get_rate(PCLK_I2C0).unwrap(), 49_500_000);
+        assert_eq!(cru.clk_get_rate(MCLK_PDM0).unwrap(), 297 * MHZ);
+    }
+}
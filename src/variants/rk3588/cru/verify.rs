@@ -0,0 +1,266 @@
+//! RK3588 CRU 初始化配置验证
+//!
+//! 参考 u-boot: drivers/clk/rockchip/clk_rk3588.c:rk3588_clk_init()
+//!
+//! 按预期配置 ([`InitProfile`]) 检查 PLL 和总线根时钟的频率，
+//! 结果以 [`VerifyReport`] 返回，便于板级测试直接判断而不必解析日志
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use super::clock::*;
+use super::consts::*;
+use super::*;
+
+/// 初始化验证的预期配置
+///
+/// 默认值 [`InitProfile::UBOOT`] 与 u-boot `rk3588_clk_init()` 执行后的状态一致，
+/// 板级配置不同时可以自行构造
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitProfile {
+    /// CPLL 频率 (Hz)
+    pub cpll_hz: u64,
+    /// GPLL 频率 (Hz)
+    pub gpll_hz: u64,
+    /// PPLL 频率 (Hz)，`None` 表示不检查 (u-boot 只在启用 PCI 时配置)
+    pub ppll_hz: Option<u64>,
+    /// ACLK_BUS_ROOT 频率 (Hz)
+    pub aclk_bus_root_hz: u64,
+    /// ACLK_TOP_S400 频率 (Hz)
+    pub aclk_top_s400_hz: u64,
+    /// ACLK_TOP_S200 频率 (Hz)
+    pub aclk_top_s200_hz: u64,
+}
+
+impl InitProfile {
+    /// u-boot 默认配置
+    ///
+    /// - CPLL: 1500MHz, GPLL: 1188MHz, PPLL: 1100MHz
+    /// - ACLK_BUS_ROOT: GPLL / 4 (clksel_con[38])
+    /// - ACLK_TOP_S400/S200: clk_400m_src/clk_200m_src，即 GPLL / 3 和 GPLL / 6 (clksel_con[9])
+    pub const UBOOT: Self = Self {
+        cpll_hz: CPLL_HZ,
+        gpll_hz: GPLL_HZ,
+        ppll_hz: Some(PPLL_HZ),
+        aclk_bus_root_hz: GPLL_HZ / 4,
+        aclk_top_s400_hz: GPLL_HZ / 3,
+        aclk_top_s200_hz: GPLL_HZ / 6,
+    };
+}
+
+impl Default for InitProfile {
+    fn default() -> Self {
+        Self::UBOOT
+    }
+}
+
+/// 单个检查项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyItem {
+    /// 检查的时钟名
+    pub name: &'static str,
+    /// 预期频率 (Hz)
+    pub expected_hz: u64,
+    /// 实际频率 (Hz)，无法读取时为 `None`
+    pub actual_hz: Option<u64>,
+    /// 实际频率是否在允许误差 (0.1%) 内
+    pub passed: bool,
+}
+
+impl VerifyItem {
+    fn new(name: &'static str, expected_hz: u64, actual_hz: Option<u64>) -> Self {
+        // 允许 0.1% 的误差
+        let tolerance = expected_hz / 1000;
+        let passed = actual_hz.is_some_and(|actual| actual.abs_diff(expected_hz) <= tolerance);
+        Self {
+            name,
+            expected_hz,
+            actual_hz,
+            passed,
+        }
+    }
+}
+
+impl fmt::Display for VerifyItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mark = if self.passed { "✓" } else { "⚠" };
+        write!(
+            f,
+            "{} {}: expected {}Hz, ",
+            mark, self.name, self.expected_hz
+        )?;
+        match self.actual_hz {
+            Some(actual) => write!(f, "actual {}Hz", actual),
+            None => write!(f, "actual unreadable"),
+        }
+    }
+}
+
+/// 初始化验证结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// 按检查顺序排列的检查项
+    pub items: Vec<VerifyItem>,
+}
+
+impl VerifyReport {
+    /// 所有检查项是否都通过
+    pub fn passed(&self) -> bool {
+        self.items.iter().all(|item| item.passed)
+    }
+
+    /// 未通过的检查项
+    pub fn failures(&self) -> impl Iterator<Item = &VerifyItem> {
+        self.items.iter().filter(|item| !item.passed)
+    }
+
+    /// 按名称查找检查项
+    pub fn item(&self, name: &str) -> Option<&VerifyItem> {
+        self.items.iter().find(|item| item.name == name)
+    }
+}
+
+impl Cru {
+    /// 按预期配置检查 CRU 的 PLL 和总线根时钟频率
+    ///
+    /// 只读取寄存器，不修改任何配置
+    ///
+    /// # 参数
+    ///
+    /// * `profile` - 预期配置，u-boot 默认配置见 [`InitProfile::UBOOT`]
+    ///
+    /// # 返回
+    ///
+    /// 返回包含每个检查项预期值、实际值和结果的报告
+    pub fn verify(&self, profile: &InitProfile) -> VerifyReport {
+        let mut items = vec![
+            VerifyItem::new(
+                "ACLK_BUS_ROOT",
+                profile.aclk_bus_root_hz,
+                self.clk_get_rate(ACLK_BUS_ROOT).ok(),
+            ),
+            VerifyItem::new(
+                "ACLK_TOP_S400",
+                profile.aclk_top_s400_hz,
+                self.clk_get_rate(ACLK_TOP_S400).ok(),
+            ),
+            VerifyItem::new(
+                "ACLK_TOP_S200",
+                profile.aclk_top_s200_hz,
+                self.clk_get_rate(ACLK_TOP_S200).ok(),
+            ),
+            VerifyItem::new("CPLL", profile.cpll_hz, self.pll_get_rate(PllId::CPLL).ok()),
+            VerifyItem::new("GPLL", profile.gpll_hz, self.pll_get_rate(PllId::GPLL).ok()),
+        ];
+        if let Some(ppll_hz) = profile.ppll_hz {
+            items.push(VerifyItem::new(
+                "PPLL",
+                ppll_hz,
+                self.pll_get_rate(PllId::PPLL).ok(),
+            ));
+        }
+        VerifyReport { items }
+    }
+
+    /// 最近一次初始化的验证报告
+    ///
+    /// 构造函数按初始化策略执行验证，结果保存在这里，不需要再次调用 [`Cru::verify`]
+    ///
+    /// # 返回
+    ///
+    /// 还没有初始化或使用 [`InitPolicy::NoTouch`] 初始化时返回 `None`
+    pub fn init_report(&self) -> Option<&VerifyReport> {
+        self.init_report.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{delay::FakeDelay, regmap::RegFile, variants::rk3588::cru::sim::sim_cru};
+
+    #[test]
+    fn test_verify_item() {
+        // 允许 0.1% 的误差
+        assert!(VerifyItem::new("GPLL", GPLL_HZ, Some(GPLL_HZ + GPLL_HZ / 1000)).passed);
+        assert!(!VerifyItem::new("GPLL", GPLL_HZ, Some(GPLL_HZ + GPLL_HZ / 500)).passed);
+        assert!(!VerifyItem::new("GPLL", GPLL_HZ, None).passed);
+
+        let item = VerifyItem::new("CPLL", CPLL_HZ, Some(OSC_HZ));
+        assert_eq!(
+            format!("{}", item),
+            "⚠ CPLL: expected 1500000000Hz, actual 24000000Hz"
+        );
+    }
+
+    #[test]
+    fn test_init_report_on_public_cru() {
+        use crate::{InitPolicy, SocType};
+
+        let new_cru = |policy| {
+            crate::Cru::new_with_access_and_policy(
+                SocType::Rk3588,
+                0,
+                Arc::new(SimCru::with_uboot_defaults()),
+                Arc::new(RegFile::new(0x1000)),
+                policy,
+            )
+        };
+
+        // 构造时的验证结果保存下来，不需要解构再验证一次
+        let cru = new_cru(InitPolicy::Verify);
+        let report = cru.init_report().unwrap();
+        assert!(report.passed(), "{:?}", report);
+        assert_eq!(report.items.len(), 6);
+
+        let profile = InitProfile {
+            gpll_hz: 1000 * MHZ,
+            ..InitProfile::UBOOT
+        };
+        let failures: Vec<_> = cru
+            .verify(&profile)
+            .failures()
+            .map(|item| item.name)
+            .collect();
+        assert_eq!(failures, ["GPLL"]);
+
+        assert!(new_cru(InitPolicy::NoTouch).init_report().is_none());
+    }
+
+    #[test]
+    fn test_verify_report() {
+        let (_sim, mut cru) = sim_cru();
+
+        let report = cru.init();
+        assert!(report.passed(), "{:?}", report);
+        assert_eq!(report.items.len(), 6);
+        assert_eq!(
+            report.item("ACLK_BUS_ROOT").unwrap().actual_hz,
+            Some(GPLL_HZ / 4)
+        );
+
+        // 板级配置: CPLL 1200MHz，不检查 PPLL
+        let profile = InitProfile {
+            cpll_hz: 1200 * MHZ,
+            ppll_hz: None,
+            ..InitProfile::UBOOT
+        };
+        let report = cru.verify(&profile);
+        assert_eq!(report.items.len(), 5);
+        let failures: Vec<_> = report.failures().map(|item| item.name).collect();
+        assert_eq!(failures, ["CPLL"]);
+        assert_eq!(report.item("CPLL").unwrap().actual_hz, Some(CPLL_HZ));
+
+        // 复位状态下 PLL 处于 SLOW 模式
+        let mut cru = Cru::from_access(
+            0,
+            Arc::new(SimCru::new()),
+            Arc::new(RegFile::new(0x1000)),
+            Arc::new(FakeDelay::new()),
+        );
+        let report = cru.init_with_policy(InitPolicy::Verify).unwrap();
+        assert!(!report.passed());
+        assert_eq!(report.item("GPLL").unwrap().actual_hz, Some(OSC_HZ));
+        assert!(cru.init_with_policy(InitPolicy::NoTouch).is_none());
+    }
+}
@@ -3,8 +3,11 @@ pub(crate) mod pinctrl;
 mod syscon;
 
 pub use cru::clock::*;
+pub use cru::reset::*;
 // =============================================================================
 // 公开导出
 // =============================================================================
 
+pub use cru::SimCru;
+pub use cru::{InitProfile, VerifyItem, VerifyReport};
 pub use pinctrl::PinCtrl;
@@ -2,9 +2,12 @@
 //!
 //! 提供引脚复用和引脚配置功能。
 
+use alloc::sync::Arc;
+
 use crate::{
     GpioDirection, Mmio, PinConfig, PinId,
     pinctrl::{Iomux, PinCtrlOp, PinctrlResult, gpio::GpioBank},
+    regmap::{MmioAccess, RegAccess},
 };
 
 mod pinconf_regs;
@@ -20,8 +23,6 @@ pub struct PinCtrl {
     gpio_banks: [GpioBank; 5],
 }
 
-unsafe impl Send for PinCtrl {}
-
 impl PinCtrl {
     /// 创建新的 PinManager
     ///
@@ -35,16 +36,26 @@ impl PinCtrl {
             panic!("RK3588 PinCtrl requires 5 GPIO banks");
         }
 
+        Self::new_with_access(
+            Arc::new(unsafe { MmioAccess::new(ioc) }),
+            core::array::from_fn(|i| {
+                Arc::new(unsafe { MmioAccess::new(gpio[i]) }) as Arc<dyn RegAccess>
+            }),
+        )
+    }
+
+    /// 使用自定义寄存器访问后端创建 PinCtrl
+    ///
+    /// # 参数
+    ///
+    /// * `ioc` - IOC 寄存器访问
+    /// * `gpio` - GPIO0-4 寄存器访问
+    pub fn new_with_access(ioc: Arc<dyn RegAccess>, gpio: [Arc<dyn RegAccess>; 5]) -> Self {
         let iomux = [Iomux::WIDTH_4BIT; 4];
+        // GPIO0 (Pin 0-31) - PMU1_IOC, GPIO1-4 (Pin 32-159) - BUS_IOC
         Self {
-            pinctrl: unsafe { PinctrlReg::new(ioc) },
-            gpio_banks: [
-                GpioBank::new(gpio[0], iomux), // GPIO0 (Pin 0-31) - PMU1_IOC
-                GpioBank::new(gpio[1], iomux), // GPIO1 (Pin 32-63) - BUS_IOC
-                GpioBank::new(gpio[2], iomux), // GPIO2 (Pin 64-95) - BUS_IOC
-                GpioBank::new(gpio[3], iomux), // GPIO3 (Pin 96-127) - BUS_IOC
-                GpioBank::new(gpio[4], iomux), // GPIO4 (Pin 128-159) - BUS_IOC
-            ],
+            pinctrl: PinctrlReg::new(ioc),
+            gpio_banks: gpio.map(|regs| GpioBank::new(regs, iomux)),
         }
     }
 
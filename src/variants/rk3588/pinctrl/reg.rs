@@ -1,30 +1,25 @@
-use core::ptr::NonNull;
+use alloc::sync::Arc;
 
 use super::super::syscon::IocBase;
 use crate::{
-    Mmio, PinId, PinctrlResult, Pull,
+    PinId, PinctrlResult, Pull,
     pinctrl::{Iomux, PinctrlError, gpio::IomuxReg},
+    regmap::RegAccess,
 };
 
 pub(crate) struct PinctrlReg {
-    /// IOC 基地址
-    ioc_base: NonNull<u8>,
+    /// IOC 寄存器访问
+    regs: Arc<dyn RegAccess>,
 }
 
-unsafe impl Send for PinctrlReg {}
-
 impl PinctrlReg {
     /// 创建新的 pinctrl 实例
     ///
     /// # 参数
     ///
-    /// * `ioc_base` - IOC 寄存器基地址
-    ///
-    /// # Safety
-    ///
-    /// `ioc_base` 必须是有效的 IOC 寄存器基地址，并且在整个生命周期内保持有效。
-    pub unsafe fn new(ioc_base: Mmio) -> Self {
-        Self { ioc_base }
+    /// * `regs` - IOC 寄存器访问
+    pub fn new(regs: Arc<dyn RegAccess>) -> Self {
+        Self { regs }
     }
 
     /// 设置引脚功能（pinmux）
@@ -60,45 +55,30 @@ impl PinctrlReg {
                     data = mask << (bit + 16);
                     data |= mux << bit;
 
-                    unsafe {
-                        let reg_ptr = self.ioc_base.as_ptr().add(reg0) as *mut u32;
-                        reg_ptr.write_volatile(data);
-                    }
+                    self.regs.write(reg0, data);
 
                     // 写 BUS_IOC 寄存器（只写掩码，不写 mux 值）
                     // 参考 u-boot: drivers/pinctrl/rockchip/pinctrl-rk3588.c:58-60
                     let reg1 = reg + IocBase::Bus.offset();
                     data = mask << (bit + 16);
 
-                    unsafe {
-                        let reg_ptr = self.ioc_base.as_ptr().add(reg1) as *mut u32;
-                        reg_ptr.write_volatile(data);
-                    }
+                    self.regs.write(reg1, data);
                 } else {
                     let reg0 = reg + IocBase::Pmu2.offset() - 0xC;
                     data = mask << (bit + 16);
                     data |= 8 << bit;
-                    unsafe {
-                        let reg_ptr = self.ioc_base.as_ptr().add(reg0) as *mut u32;
-                        reg_ptr.write_volatile(data);
-                    }
+                    self.regs.write(reg0, data);
 
                     let reg1 = reg + IocBase::Bus.offset();
                     data = mask << (bit + 16);
                     data |= mux << bit;
-                    unsafe {
-                        let reg_ptr = self.ioc_base.as_ptr().add(reg1) as *mut u32;
-                        reg_ptr.write_volatile(data);
-                    }
+                    self.regs.write(reg1, data);
                 }
             } else {
                 data = mask << (bit + 16);
                 data |= (mux & mask) << bit;
 
-                unsafe {
-                    let reg_ptr = self.ioc_base.as_ptr().add(reg) as *mut u32;
-                    reg_ptr.write_volatile(data);
-                }
+                self.regs.write(reg, data);
             }
             return Ok(());
         } else {
@@ -108,10 +88,7 @@ impl PinctrlReg {
         data = mask << (bit + 16);
         data |= (mux & mask) << bit;
 
-        unsafe {
-            let reg_ptr = self.ioc_base.as_ptr().add(reg) as *mut u32;
-            reg_ptr.write_volatile(data);
-        }
+        self.regs.write(reg, data);
 
         Ok(())
     }
@@ -139,10 +116,7 @@ impl PinctrlReg {
         let mask = 0x3u32 << bit_offset;
         let value = (pull as u32) << bit_offset;
 
-        unsafe {
-            let reg_ptr = self.ioc_base.as_ptr().add(reg_offset) as *mut u32;
-            reg_ptr.write_volatile((mask << 16) | value);
-        }
+        self.regs.write(reg_offset, (mask << 16) | value);
 
         Ok(())
     }
@@ -170,10 +144,7 @@ impl PinctrlReg {
         let mask = 0x3u32 << bit_offset;
         let value = drive << bit_offset;
 
-        unsafe {
-            let reg_ptr = self.ioc_base.as_ptr().add(reg_offset) as *mut u32;
-            reg_ptr.write_volatile((mask << 16) | value);
-        }
+        self.regs.write(reg_offset, (mask << 16) | value);
 
         Ok(())
     }
@@ -208,10 +179,7 @@ impl PinctrlReg {
         // GPIO0 的特殊处理
         if id.bank().raw() == 0 {
             // GPIO0: 直接使用组内偏移（不加基地址）
-            let reg_value = unsafe {
-                let reg_ptr = self.ioc_base.as_ptr().add(reg) as *const u32;
-                reg_ptr.read_volatile()
-            };
+            let reg_value = self.regs.read(reg);
 
             debug!(
                 "get_mux: pin={id}, reg_offset={:#x}, bit={}, reg_value={:#x}",
@@ -224,12 +192,11 @@ impl PinctrlReg {
             // GPIO1-4: 加上 BUS_IOC 基地址
             reg += IocBase::Bus.offset();
         }
-        let reg_ptr = unsafe { self.ioc_base.as_ptr().add(reg) as *const u32 };
         // 读取寄存器值
-        let reg_value = unsafe { reg_ptr.read_volatile() };
+        let reg_value = self.regs.read(reg);
 
         debug!(
-            "get_mux: pin={id}, reg: {reg_ptr:#p} reg_offset={:#x}, bit={}, reg_value={:#x}",
+            "get_mux: pin={id}, reg_offset={:#x}, bit={}, reg_value={:#x}",
             reg, bit, reg_value
         );
 
@@ -256,10 +223,7 @@ impl PinctrlReg {
             find_pull_entry(pin).ok_or(PinctrlError::InvalidPinId(pin))?;
 
         // 读取寄存器值
-        let reg_value = unsafe {
-            let reg_ptr = self.ioc_base.as_ptr().add(reg_offset) as *const u32;
-            reg_ptr.read_volatile()
-        };
+        let reg_value = self.regs.read(reg_offset);
 
         debug!(
             "get_pull: pin={}, reg_offset={:#x}, bit_offset={}, reg_value={:#x}",
@@ -302,10 +266,7 @@ impl PinctrlReg {
             find_drive_entry(pin).ok_or(PinctrlError::InvalidPinId(pin))?;
 
         // 读取寄存器值
-        let reg_value = unsafe {
-            let reg_ptr = self.ioc_base.as_ptr().add(reg_offset) as *const u32;
-            reg_ptr.read_volatile()
-        };
+        let reg_value = self.regs.read(reg_offset);
 
         debug!(
             "get_drive: pin={}, reg_offset={:#x}, bit_offset={}, reg_value={:#x}",